@@ -8,7 +8,7 @@ use thiserror::Error;
 use tokio::sync::{mpsc, Mutex};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::Status;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 use super::cosmos;
 use crate::asyncutil::future::{with_retry, RetryPolicy};
@@ -17,6 +17,8 @@ use crate::asyncutil::future::{with_retry, RetryPolicy};
 pub enum TxStatus {
     Success,
     Failure,
+    /// The tx could not be found on chain before the confirmation timed out, so it might have been dropped
+    NotFound,
 }
 
 impl From<u32> for TxStatus {
@@ -43,6 +45,18 @@ impl From<cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse> for TxResponse
     }
 }
 
+impl TxResponse {
+    fn not_found(tx_hash: String) -> Self {
+        Self {
+            status: TxStatus::NotFound,
+            response: cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse {
+                txhash: tx_hash,
+                ..Default::default()
+            },
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed confirming tx due to tx not found: {tx_hash}")]
@@ -92,6 +106,7 @@ where
                 // multiple instances of confirm_tx can be spawned due to buffer_unordered,
                 // so we need to clone the client to avoid a deadlock
                 confirm_tx_with_retry(client.clone(), tx_hash, retry_policy)
+                    .or_else(|err| async { report_not_found(err) })
                     .and_then(|tx| async { send_response(&tx_response_sender, tx).await })
                     .await
             })
@@ -150,6 +165,18 @@ fn evaluate_tx_response(
     }
 }
 
+/// A tx that is still not found after all retries is reported back instead of failing the confirmer,
+/// so the broadcaster can decide whether to resubmit it
+fn report_not_found(err: Report<Error>) -> Result<TxResponse, Error> {
+    match err.current_context() {
+        Error::Confirmation { tx_hash } => {
+            warn!(tx_hash, "tx not found after confirmation timeout");
+            Ok(TxResponse::not_found(tx_hash.clone()))
+        }
+        _ => Err(err),
+    }
+}
+
 async fn send_response(
     tx_res_sender: &mpsc::Sender<TxResponse>,
     tx: TxResponse,
@@ -270,7 +297,7 @@ mod test {
     }
 
     #[test]
-    async fn should_retry_when_tx_is_not_found() {
+    async fn should_report_not_found_when_tx_is_not_found_after_retries() {
         let tx_hash = "tx_hash".to_string();
 
         let mut client = MockBroadcastClient::new();
@@ -285,7 +312,7 @@ mod test {
         let sleep = Duration::from_millis(100);
         let max_attempts = 3;
         let (tx_confirmer_sender, tx_confirmer_receiver) = mpsc::channel(100);
-        let (tx_res_sender, _tx_res_receiver) = mpsc::channel(100);
+        let (tx_res_sender, mut tx_res_receiver) = mpsc::channel(100);
 
         let tx_confirmer = TxConfirmer::new(
             client,
@@ -297,10 +324,18 @@ mod test {
         let handle = tokio::spawn(tx_confirmer.run(tx_confirmer_receiver, tx_res_sender));
 
         tx_confirmer_sender.send(tx_hash.clone()).await.unwrap();
-        assert!(matches!(
-            handle.await.unwrap().unwrap_err().current_context(),
-            Error::Confirmation { tx_hash: actual } if *actual == tx_hash
-        ));
+        assert_eq!(
+            tx_res_receiver.recv().await.unwrap(),
+            TxResponse {
+                status: TxStatus::NotFound,
+                response: cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse {
+                    txhash: tx_hash,
+                    ..Default::default()
+                }
+            }
+        );
+        drop(tx_confirmer_sender);
+        assert!(handle.await.unwrap().is_ok());
     }
 
     #[test]
@@ -11,6 +11,8 @@ use cosmrs::proto::cosmos::auth::v1beta1::{QueryAccountRequest, QueryAccountResp
 use cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
 use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::node::v1beta1::service_client::ServiceClient as NodeServiceClient;
+use cosmrs::proto::cosmos::base::node::v1beta1::{ConfigRequest, ConfigResponse};
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use cosmrs::proto::cosmos::tx::v1beta1::{
     BroadcastTxRequest, GetTxRequest, GetTxResponse, SimulateRequest, SimulateResponse,
@@ -84,3 +86,16 @@ impl BalanceQueryClient for BankQueryClient<Channel> {
         self.balance(request).await.map(Response::into_inner)
     }
 }
+
+#[automock]
+#[async_trait]
+pub trait NodeQueryClient {
+    async fn config(&mut self, request: ConfigRequest) -> Result<ConfigResponse, Status>;
+}
+
+#[async_trait]
+impl NodeQueryClient for NodeServiceClient<Channel> {
+    async fn config(&mut self, request: ConfigRequest) -> Result<ConfigResponse, Status> {
+        self.config(request).await.map(Response::into_inner)
+    }
+}
@@ -10,6 +10,7 @@ use cosmrs::proto::cosmos::auth::v1beta1::{
 };
 use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::node::v1beta1::ConfigRequest;
 use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastMode, BroadcastTxRequest, SimulateRequest};
 use cosmrs::proto::traits::MessageExt;
 use cosmrs::tendermint::chain::Id;
@@ -24,13 +25,15 @@ use mockall::automock;
 use num_traits::{cast, Zero};
 use prost::Message;
 use prost_types::Any;
-use report::ResultCompatExt;
+use report::{LoggableError, ResultCompatExt};
+use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tonic::{Code, Status};
-use tracing::info;
+use tracing::{debug, info, warn};
 use tx::Tx;
 use typed_builder::TypedBuilder;
+use valuable::Valuable;
 
 use crate::tofnd;
 use crate::tofnd::grpc::Multisig;
@@ -41,6 +44,13 @@ mod cosmos;
 mod dec_coin;
 mod tx;
 
+/// Codespace of the errors raised by the cosmos sdk itself
+const SDK_CODESPACE: &str = "sdk";
+/// Error code the cosmos sdk uses when a tx is signed with the wrong account sequence
+const SEQUENCE_MISMATCH_CODE: u32 = 32;
+/// Error code the cosmos sdk uses when the fee of a tx is below the minimum gas price of the node
+const INSUFFICIENT_FEE_CODE: u32 = 13;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed building tx")]
@@ -65,9 +75,12 @@ pub enum Error {
     AccountNotFound { address: TMAddress },
     #[error("transaction not accepted by the node")]
     TxNotAccepted,
+    #[error("failed to query the minimum gas price of the node")]
+    QueryGasPrice,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
 pub struct Config {
     pub chain_id: Id,
     #[serde(with = "humantime_serde")]
@@ -79,6 +92,10 @@ pub struct Config {
     pub queue_cap: usize,
     #[serde(with = "humantime_serde")]
     pub broadcast_interval: Duration,
+    pub sequence_mismatch_max_retries: u32,
+    pub tx_resubmission_max_retries: u32,
+    pub dynamic_gas_price: Option<DynamicGasPrice>,
+    pub priority_lanes: Vec<PriorityLane>,
 }
 
 impl Default for Config {
@@ -92,10 +109,78 @@ impl Default for Config {
             batch_gas_limit: 1000000,
             queue_cap: 1000,
             broadcast_interval: Duration::from_secs(5),
+            sequence_mismatch_max_retries: 3,
+            tx_resubmission_max_retries: 3,
+            dynamic_gas_price: None,
+            priority_lanes: vec![
+                PriorityLane {
+                    msgs: vec!["submit_signature".to_string()],
+                    gas_budget: 400000,
+                },
+                PriorityLane {
+                    msgs: vec!["vote".to_string()],
+                    gas_budget: 400000,
+                },
+            ],
         }
     }
 }
 
+/// If set, the gas price follows the minimum gas price of the connected node within the given bounds.
+/// Amounts are in the denomination of `gas_price`, which is used as long as the node reports no price for that denomination.
+/// When a tx is rejected because of an insufficient fee, it is rebuilt with the gas price multiplied by
+/// `insufficient_fee_multiplier` until `max` is reached.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct DynamicGasPrice {
+    pub min: f64,
+    pub max: f64,
+    pub insufficient_fee_multiplier: f64,
+}
+
+/// Messages of the given types are broadcast before the messages of all following lanes,
+/// using at most `gas_budget` of each batch. Types are either contract execute message names
+/// (e.g. "vote") or proto type urls. All other messages are broadcast last.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PriorityLane {
+    pub msgs: Vec<String>,
+    pub gas_budget: Gas,
+}
+
+/// An additional account that broadcasts on behalf of the handlers with the given labels,
+/// so they don't share the message queue and account sequence with all other handlers
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct AccountConfig {
+    pub key_uid: String,
+    pub handlers: Vec<String>,
+}
+
+pub fn deserialize_account_configs<'de, D>(
+    deserializer: D,
+) -> core::result::Result<Vec<AccountConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let configs: Vec<AccountConfig> = Deserialize::deserialize(deserializer)?;
+
+    if !configs.iter().map(|config| &config.key_uid).all_unique() {
+        return Err(de::Error::custom(
+            "the key uids of broadcaster accounts must be unique",
+        ));
+    }
+
+    if !configs
+        .iter()
+        .flat_map(|config| &config.handlers)
+        .all_unique()
+    {
+        return Err(de::Error::custom(
+            "a handler can only be assigned to one broadcaster account",
+        ));
+    }
+
+    Ok(configs)
+}
+
 #[automock]
 #[async_trait]
 pub trait Broadcaster {
@@ -105,17 +190,19 @@ pub trait Broadcaster {
 }
 
 #[derive(TypedBuilder)]
-pub struct UnvalidatedBasicBroadcaster<T, S, A, B>
+pub struct UnvalidatedBasicBroadcaster<T, S, A, B, N>
 where
     T: cosmos::BroadcastClient + Send,
     S: Multisig + Send + Sync,
     A: cosmos::AccountQueryClient + Send,
     B: cosmos::BalanceQueryClient,
+    N: cosmos::NodeQueryClient + Send,
 {
     client: T,
     signer: S,
     auth_query_client: A,
     bank_query_client: B,
+    node_query_client: N,
     address_prefix: String,
     #[builder(default, setter(skip))]
     acc_sequence: Option<u64>,
@@ -123,14 +210,17 @@ where
     config: Config,
 }
 
-impl<T, S, A, B> UnvalidatedBasicBroadcaster<T, S, A, B>
+impl<T, S, A, B, N> UnvalidatedBasicBroadcaster<T, S, A, B, N>
 where
     T: cosmos::BroadcastClient + Send,
     S: Multisig + Send + Sync,
     A: cosmos::AccountQueryClient + Send,
     B: cosmos::BalanceQueryClient,
+    N: cosmos::NodeQueryClient + Send,
 {
-    pub async fn validate_fee_denomination(mut self) -> Result<BasicBroadcaster<T, S, A>, Error> {
+    pub async fn validate_fee_denomination(
+        mut self,
+    ) -> Result<BasicBroadcaster<T, S, A, N>, Error> {
         let denom: Denom = self.config.gas_price.denom.clone().into();
         let address: TMAddress = self.derive_address()?;
 
@@ -146,9 +236,11 @@ where
             client: self.client,
             signer: self.signer,
             auth_query_client: self.auth_query_client,
+            node_query_client: self.node_query_client,
             address: address.clone(),
             acc_sequence: self.acc_sequence,
             pub_key: self.pub_key,
+            gas_price: self.config.gas_price.clone(),
             config: self.config,
         })
     }
@@ -191,33 +283,97 @@ fn extract_non_zero_amount(coin: Coin) -> Option<Amount> {
 }
 
 #[derive(Debug)]
-pub struct BasicBroadcaster<T, S, Q>
+pub struct BasicBroadcaster<T, S, Q, N>
 where
     T: cosmos::BroadcastClient + Send,
     S: Multisig + Send + Sync,
     Q: cosmos::AccountQueryClient + Send,
+    N: cosmos::NodeQueryClient + Send,
 {
     client: T,
     signer: S,
     auth_query_client: Q,
+    node_query_client: N,
     address: TMAddress,
     acc_sequence: Option<u64>,
     pub_key: (String, CosmosPublicKey),
+    gas_price: DecCoin,
     config: Config,
 }
 
 #[async_trait]
-impl<T, S, Q> Broadcaster for BasicBroadcaster<T, S, Q>
+impl<T, S, Q, N> Broadcaster for BasicBroadcaster<T, S, Q, N>
 where
     T: cosmos::BroadcastClient + Send,
     S: Multisig + Send + Sync,
     Q: cosmos::AccountQueryClient + Send,
+    N: cosmos::NodeQueryClient + Send,
 {
     fn sender_address(&self) -> TMAddress {
         self.address.clone()
     }
 
     async fn broadcast(&mut self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        self.update_gas_price().await;
+
+        let mut retries = 0u32;
+
+        loop {
+            let (response, acc_sequence) = self.sign_and_broadcast(msgs.clone()).await?;
+
+            if is_insufficient_fee(&response) && self.raise_gas_price() {
+                warn!(
+                    tx_hash = response.txhash,
+                    log = response.raw_log,
+                    gas_price = self.gas_price.to_string(),
+                    "insufficient fee, rebuilding tx with a higher gas price"
+                );
+
+                continue;
+            }
+
+            if is_sequence_mismatch(&response)
+                && retries < self.config.sequence_mismatch_max_retries
+            {
+                warn!(
+                    tx_hash = response.txhash,
+                    log = response.raw_log,
+                    acc_sequence,
+                    "account sequence mismatch, rebuilding tx with the sequence from the chain"
+                );
+
+                // drop the locally tracked sequence so the next attempt uses the on-chain value
+                self.acc_sequence = None;
+                retries = retries.saturating_add(1);
+                continue;
+            }
+
+            ensure!(response.code == 0, Error::TxNotAccepted);
+
+            self.acc_sequence.replace(
+                acc_sequence
+                    .checked_add(1)
+                    .expect("account sequence must be less than u64::MAX"),
+            );
+            return Ok(response);
+        }
+    }
+
+    async fn estimate_fee(&mut self, msgs: Vec<Any>) -> Result<Fee, Error> {
+        let (_, acc_sequence) = self.acc_number_and_sequence().await?;
+
+        self.estimate_fee(msgs, acc_sequence).await
+    }
+}
+
+impl<T, S, Q, N> BasicBroadcaster<T, S, Q, N>
+where
+    T: cosmos::BroadcastClient + Send,
+    S: Multisig + Send + Sync,
+    Q: cosmos::AccountQueryClient + Send,
+    N: cosmos::NodeQueryClient + Send,
+{
+    async fn sign_and_broadcast(&mut self, msgs: Vec<Any>) -> Result<(TxResponse, u64), Error> {
         let (acc_number, acc_sequence) = self.acc_number_and_sequence().await?;
         let fee = self.estimate_fee(msgs.clone(), acc_sequence).await?;
         let tx = Tx::builder()
@@ -260,36 +416,16 @@ where
         info!(
             tx_hash = response.txhash,
             acc_number,
-            acc_sequence = self.acc_sequence,
+            acc_sequence,
             fee.amount = fee.amount.iter().map(Coin::to_string).join(", "),
             ?fee.gas_limit,
             ?response,
             "transaction was broadcast"
         );
 
-        ensure!(response.code == 0, Error::TxNotAccepted);
-
-        self.acc_sequence.replace(
-            acc_sequence
-                .checked_add(1)
-                .expect("account sequence must be less than u64::MAX"),
-        );
-        Ok(response)
-    }
-
-    async fn estimate_fee(&mut self, msgs: Vec<Any>) -> Result<Fee, Error> {
-        let (_, acc_sequence) = self.acc_number_and_sequence().await?;
-
-        self.estimate_fee(msgs, acc_sequence).await
+        Ok((response, acc_sequence))
     }
-}
 
-impl<T, S, Q> BasicBroadcaster<T, S, Q>
-where
-    T: cosmos::BroadcastClient + Send,
-    S: Multisig + Send + Sync,
-    Q: cosmos::AccountQueryClient + Send,
-{
     async fn acc_number_and_sequence(&mut self) -> Result<(u64, u64), Error> {
         let request = QueryAccountRequest {
             address: self.address.to_string(),
@@ -336,15 +472,95 @@ where
 
             Ok(Fee::from_amount_and_gas(
                 Coin {
-                    amount: cast(gas_adj.mul(self.config.gas_price.amount).ceil())
+                    amount: cast(gas_adj.mul(self.gas_price.amount).ceil())
                         .ok_or(Error::FeeEstimation)?,
-                    denom: self.config.gas_price.denom.clone().into(),
+                    denom: self.gas_price.denom.clone().into(),
                 },
                 cast::<f64, u64>(gas_adj).ok_or(Error::FeeEstimation)?,
             ))
         })?
     }
 
+    /// Sets the gas price to the minimum gas price of the node within the configured bounds.
+    /// The current gas price is kept if the node can't be queried.
+    async fn update_gas_price(&mut self) {
+        let Some(DynamicGasPrice { min, max, .. }) = self.config.dynamic_gas_price.clone() else {
+            return;
+        };
+
+        match self.node_gas_price().await {
+            Ok(Some(amount)) => {
+                self.gas_price = self.gas_price_with_amount(amount.max(min).min(max));
+            }
+            Ok(None) => {
+                debug!(
+                    denom = self.gas_price.denom.to_string(),
+                    "node reports no minimum gas price for the fee denomination"
+                );
+            }
+            Err(err) => {
+                warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    gas_price = self.gas_price.to_string(),
+                    "failed to query the minimum gas price of the node, keeping the current gas price"
+                );
+            }
+        }
+    }
+
+    async fn node_gas_price(&mut self) -> Result<Option<f64>, Error> {
+        let response = self
+            .node_query_client
+            .config(ConfigRequest {})
+            .await
+            .change_context(Error::QueryGasPrice)?;
+
+        let gas_prices: Vec<DecCoin> = response
+            .minimum_gas_price
+            .split(',')
+            .map(str::trim)
+            .filter(|gas_price| !gas_price.is_empty())
+            .map(DecCoin::try_from)
+            .collect::<core::result::Result<_, _>>()
+            .change_context(Error::MalformedResponse {
+                query: "config".to_string(),
+            })?;
+
+        Ok(gas_prices
+            .into_iter()
+            .find(|gas_price| gas_price.denom == self.gas_price.denom)
+            .map(|gas_price| gas_price.amount.into()))
+    }
+
+    /// Multiplies the gas price after a tx was rejected because of an insufficient fee.
+    /// Returns false if the gas price can't be raised any further, so the tx should not be retried.
+    fn raise_gas_price(&mut self) -> bool {
+        let Some(DynamicGasPrice {
+            max,
+            insufficient_fee_multiplier,
+            ..
+        }) = self.config.dynamic_gas_price.clone()
+        else {
+            return false;
+        };
+
+        let current: f64 = self.gas_price.amount.into();
+        let raised = (current * insufficient_fee_multiplier).min(max);
+        if raised.is_nan() || raised <= current {
+            return false;
+        }
+
+        self.gas_price = self.gas_price_with_amount(raised);
+        true
+    }
+
+    fn gas_price_with_amount(&self, amount: f64) -> DecCoin {
+        DecCoin {
+            denom: self.gas_price.denom.clone(),
+            amount: amount.try_into().unwrap_or(self.gas_price.amount),
+        }
+    }
+
     async fn estimate_gas(&mut self, tx_bytes: Vec<u8>) -> Result<u64, Error> {
         #[allow(deprecated)]
         self.client
@@ -360,6 +576,14 @@ where
     }
 }
 
+fn is_sequence_mismatch(response: &TxResponse) -> bool {
+    response.codespace == SDK_CODESPACE && response.code == SEQUENCE_MISMATCH_CODE
+}
+
+fn is_insufficient_fee(response: &TxResponse) -> bool {
+    response.codespace == SDK_CODESPACE && response.code == INSUFFICIENT_FEE_CODE
+}
+
 fn decode_base_account(account: Any) -> Result<BaseAccount, Error> {
     BaseAccount::decode(&account.value[..])
         .change_context(Error::MalformedResponse {
@@ -384,6 +608,7 @@ mod tests {
     use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountResponse};
     use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceResponse;
     use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxResponse};
+    use cosmrs::proto::cosmos::base::node::v1beta1::ConfigResponse;
     use cosmrs::proto::cosmos::tx::v1beta1::{GetTxResponse, SimulateResponse};
     use cosmrs::proto::traits::MessageExt;
     use cosmrs::proto::Any;
@@ -394,10 +619,11 @@ mod tests {
     use tonic::Status;
 
     use crate::broadcaster::cosmos::{
-        MockAccountQueryClient, MockBalanceQueryClient, MockBroadcastClient,
+        MockAccountQueryClient, MockBalanceQueryClient, MockBroadcastClient, MockNodeQueryClient,
     };
+    use crate::broadcaster::dec_coin::DecCoin;
     use crate::broadcaster::{
-        BasicBroadcaster, Broadcaster, Config, Error, UnvalidatedBasicBroadcaster,
+        BasicBroadcaster, Broadcaster, Config, DynamicGasPrice, Error, UnvalidatedBasicBroadcaster,
     };
     use crate::tofnd::grpc::MockMultisig;
     use crate::types::{CosmosPublicKey, PublicKey, TMAddress};
@@ -546,6 +772,189 @@ mod tests {
         assert_eq!(broadcaster.acc_sequence, Some(12));
     }
 
+    #[test]
+    async fn broadcast_rebuilds_tx_with_on_chain_acc_sequence_after_sequence_mismatch() {
+        let mut client = MockBroadcastClient::new();
+        client.expect_simulate().returning(|_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: 1000,
+                    gas_used: 500,
+                }),
+                result: None,
+            })
+        });
+        let mut call_count = 0;
+        client.expect_broadcast_tx().times(2).returning(move |_| {
+            call_count += 1;
+
+            match call_count {
+                1 => Ok(sequence_mismatch_response()),
+                _ => Ok(TxResponse::default()),
+            }
+        });
+
+        let mut broadcaster = init_validated_broadcaster(None, None, Some(client)).await;
+        // the in-memory sequence is ahead of the chain, e.g. because a previous tx was dropped from the mempool
+        broadcaster.acc_sequence = Some(5);
+
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.acc_sequence, Some(1));
+    }
+
+    #[test]
+    async fn broadcast_fails_after_max_sequence_mismatch_retries() {
+        let mut client = MockBroadcastClient::new();
+        client.expect_simulate().returning(|_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: 1000,
+                    gas_used: 500,
+                }),
+                result: None,
+            })
+        });
+        client
+            .expect_broadcast_tx()
+            .times(Config::default().sequence_mismatch_max_retries as usize + 1)
+            .returning(|_| Ok(sequence_mismatch_response()));
+
+        let mut broadcaster = init_validated_broadcaster(None, None, Some(client)).await;
+
+        assert!(matches!(
+            broadcaster
+                .broadcast(vec![dummy_msg()])
+                .await
+                .unwrap_err()
+                .current_context(),
+            Error::TxNotAccepted
+        ));
+        assert_eq!(broadcaster.acc_sequence, None);
+    }
+
+    #[test]
+    async fn broadcast_uses_node_gas_price_and_raises_it_on_insufficient_fee() {
+        let mut client = MockBroadcastClient::new();
+        client.expect_simulate().returning(|_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: 1000,
+                    gas_used: 500,
+                }),
+                result: None,
+            })
+        });
+        let mut call_count = 0;
+        client
+            .expect_broadcast_tx()
+            .times(2)
+            .returning(move |request| {
+                call_count += 1;
+
+                // gas used of 500 with the default gas adjustment of 1.2 results in a gas limit of 600
+                let fee = cosmrs::Tx::from_bytes(&request.tx_bytes)
+                    .unwrap()
+                    .auth_info
+                    .fee;
+                assert_eq!(fee.gas_limit, 600);
+
+                match call_count {
+                    1 => {
+                        assert_eq!(fee.amount[0].amount, 150);
+                        Ok(insufficient_fee_response())
+                    }
+                    _ => {
+                        assert_eq!(fee.amount[0].amount, 300);
+                        Ok(TxResponse::default())
+                    }
+                }
+            });
+
+        let mut broadcaster = init_validated_broadcaster(None, None, Some(client)).await;
+        broadcaster.node_query_client = init_mock_node_client("0.25uaxl,0.1uusdc");
+        broadcaster.config.dynamic_gas_price = Some(DynamicGasPrice {
+            min: 0.1,
+            max: 1.0,
+            insufficient_fee_multiplier: 2.0,
+        });
+
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.gas_price, DecCoin::new(0.5, "uaxl").unwrap());
+        assert_eq!(broadcaster.acc_sequence, Some(1));
+    }
+
+    #[test]
+    async fn broadcast_bounds_node_gas_price() {
+        let mut broadcaster = init_validated_broadcaster(None, None, None).await;
+        broadcaster.config.dynamic_gas_price = Some(DynamicGasPrice {
+            min: 0.1,
+            max: 1.0,
+            insufficient_fee_multiplier: 2.0,
+        });
+
+        broadcaster.node_query_client = init_mock_node_client("10uaxl");
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.gas_price, DecCoin::new(1.0, "uaxl").unwrap());
+
+        broadcaster.node_query_client = init_mock_node_client("0.001uaxl");
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.gas_price, DecCoin::new(0.1, "uaxl").unwrap());
+    }
+
+    #[test]
+    async fn broadcast_keeps_gas_price_when_node_query_fails() {
+        let mut broadcaster = init_validated_broadcaster(None, None, None).await;
+        broadcaster.config.dynamic_gas_price = Some(DynamicGasPrice {
+            min: 0.1,
+            max: 1.0,
+            insufficient_fee_multiplier: 2.0,
+        });
+        broadcaster.node_query_client = MockNodeQueryClient::new();
+        broadcaster
+            .node_query_client
+            .expect_config()
+            .returning(|_| Err(Status::unavailable("unavailable")));
+
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.gas_price, Config::default().gas_price);
+    }
+
+    #[test]
+    async fn broadcast_fails_on_insufficient_fee_when_gas_price_is_at_max() {
+        let mut client = MockBroadcastClient::new();
+        client.expect_simulate().returning(|_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: 1000,
+                    gas_used: 500,
+                }),
+                result: None,
+            })
+        });
+        client
+            .expect_broadcast_tx()
+            .times(2)
+            .returning(|_| Ok(insufficient_fee_response()));
+
+        let mut broadcaster = init_validated_broadcaster(None, None, Some(client)).await;
+        broadcaster.node_query_client = init_mock_node_client("0.5uaxl");
+        broadcaster.config.dynamic_gas_price = Some(DynamicGasPrice {
+            min: 0.1,
+            max: 1.0,
+            insufficient_fee_multiplier: 2.0,
+        });
+
+        assert!(matches!(
+            broadcaster
+                .broadcast(vec![dummy_msg()])
+                .await
+                .unwrap_err()
+                .current_context(),
+            Error::TxNotAccepted
+        ));
+        assert_eq!(broadcaster.gas_price, DecCoin::new(1.0, "uaxl").unwrap());
+    }
+
     #[test]
     async fn account_query_failed_return_error() {
         let mut client = MockAccountQueryClient::new();
@@ -626,6 +1035,7 @@ mod tests {
         MockMultisig,
         MockAccountQueryClient,
         MockBalanceQueryClient,
+        MockNodeQueryClient,
     > {
         let key_id = "key_uid".to_string();
         let priv_key = k256::ecdsa::SigningKey::random(&mut OsRng);
@@ -644,6 +1054,7 @@ mod tests {
             .bank_query_client(
                 balance_client_override.unwrap_or(init_mock_balance_client(known_denom)),
             )
+            .node_query_client(MockNodeQueryClient::new())
             .address_prefix(PREFIX.to_string())
             .pub_key((key_id, pub_key))
             .config(Config::default())
@@ -654,7 +1065,12 @@ mod tests {
         balance_client_override: Option<MockBalanceQueryClient>,
         auth_client_override: Option<MockAccountQueryClient>,
         broadcast_client_override: Option<MockBroadcastClient>,
-    ) -> BasicBroadcaster<MockBroadcastClient, MockMultisig, MockAccountQueryClient> {
+    ) -> BasicBroadcaster<
+        MockBroadcastClient,
+        MockMultisig,
+        MockAccountQueryClient,
+        MockNodeQueryClient,
+    > {
         init_unvalidated_broadcaster(
             balance_client_override,
             auth_client_override,
@@ -692,6 +1108,37 @@ mod tests {
         client
     }
 
+    fn init_mock_node_client(minimum_gas_price: &'static str) -> MockNodeQueryClient {
+        let mut node_query_client = MockNodeQueryClient::new();
+        node_query_client.expect_config().returning(move |_| {
+            Ok(ConfigResponse {
+                minimum_gas_price: minimum_gas_price.to_string(),
+            })
+        });
+
+        node_query_client
+    }
+
+    fn insufficient_fee_response() -> TxResponse {
+        TxResponse {
+            code: 13,
+            codespace: "sdk".to_string(),
+            raw_log: "insufficient fees; got: 150uaxl required: 300uaxl: insufficient fee"
+                .to_string(),
+            ..TxResponse::default()
+        }
+    }
+
+    fn sequence_mismatch_response() -> TxResponse {
+        TxResponse {
+            code: 32,
+            codespace: "sdk".to_string(),
+            raw_log: "account sequence mismatch, expected 0, got 5: incorrect account sequence"
+                .to_string(),
+            ..TxResponse::default()
+        }
+    }
+
     // returns a non-zero balance if the denom in the request is known, a zero balance otherwise
     fn init_mock_balance_client(known_denom: Denom) -> MockBalanceQueryClient {
         let mut bank_query_client = MockBalanceQueryClient::new();
@@ -0,0 +1,181 @@
+use axelar_wasm_std::nonempty;
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest;
+use cosmrs::AccountId;
+use error_stack::{Result, ResultExt};
+use interchain_token_service::msg::{
+    InvariantCheckResponse, InvariantViolation, QueryMsg, TokenConfigResponse,
+};
+use report::ResultCompatExt;
+use serde::de::DeserializeOwned;
+use tonic::transport::Channel;
+use valuable::Valuable;
+
+use crate::config::Config;
+use crate::Error;
+
+const PAGE_SIZE: u32 = 30;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    pub its_hub_address: nonempty::String,
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let its_hub = args
+        .its_hub_address
+        .parse::<AccountId>()
+        .change_context(Error::InvalidInput)?;
+    let mut client = QueryClient::connect(config.tm_grpc.to_string())
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_grpc.clone())?;
+
+    let mut checks = vec![];
+    let mut start_after = None;
+    loop {
+        let tokens: Vec<TokenConfigResponse> = query(
+            &mut client,
+            &its_hub,
+            &QueryMsg::Tokens {
+                start_after,
+                limit: Some(PAGE_SIZE),
+            },
+        )
+        .await?;
+
+        for TokenConfigResponse { token_id, .. } in &tokens {
+            let check: Option<InvariantCheckResponse> = query(
+                &mut client,
+                &its_hub,
+                &QueryMsg::InvariantCheck {
+                    token_id: *token_id,
+                },
+            )
+            .await?;
+            checks.extend(check);
+        }
+
+        match tokens.last() {
+            Some(last) if tokens.len() == PAGE_SIZE as usize => start_after = Some(last.token_id),
+            _ => break,
+        }
+    }
+
+    Ok(Some(report(&checks)))
+}
+
+async fn query<T: DeserializeOwned>(
+    client: &mut QueryClient<Channel>,
+    contract: &AccountId,
+    msg: &QueryMsg,
+) -> Result<T, Error> {
+    let response = client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: contract.to_string(),
+            query_data: serde_json::to_vec(msg).expect("query msg should serialize"),
+        })
+        .await
+        .change_context(Error::ContractQuery)?
+        .into_inner();
+
+    serde_json::from_slice(&response.data).change_context(Error::ContractQuery)
+}
+
+fn report(checks: &[InvariantCheckResponse]) -> String {
+    let violations: Vec<_> = checks
+        .iter()
+        .flat_map(|check| {
+            check.violations.iter().map(|violation| {
+                format!("token {}: {}", check.token_id, describe(check, violation))
+            })
+        })
+        .collect();
+
+    if violations.is_empty() {
+        return format!("checked {} tokens, no violations found", checks.len());
+    }
+
+    format!(
+        "checked {} tokens, found {} violations:\n{}",
+        checks.len(),
+        violations.len(),
+        violations.join("\n")
+    )
+}
+
+fn describe(check: &InvariantCheckResponse, violation: &InvariantViolation) -> String {
+    match violation {
+        InvariantViolation::OriginInflowExceedsOutflow { outflow, inflow } => format!(
+            "{} returned to origin chain {}, but only {} left it",
+            inflow, check.origin_chain, outflow
+        ),
+        InvariantViolation::UnbackedSupply { claimable, locked } => format!(
+            "{} can be bridged back to origin chain {}, but only {} is locked on it",
+            claimable, check.origin_chain, locked
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint256;
+    use interchain_token_service::msg::{InvariantCheckResponse, InvariantViolation};
+    use interchain_token_service::TokenId;
+
+    use super::report;
+
+    fn check(token_id: TokenId, violations: Vec<InvariantViolation>) -> InvariantCheckResponse {
+        InvariantCheckResponse {
+            token_id,
+            origin_chain: "ethereum".parse().unwrap(),
+            origin_decimals: 18,
+            chains: vec![],
+            total_supply_on_origin: Uint256::from(100u128),
+            total_rounding_loss_on_origin: Uint256::one(),
+            total_accrued_fees_on_origin: Uint256::zero(),
+            locked_on_origin: Uint256::from(101u128),
+            violations,
+        }
+    }
+
+    #[test]
+    fn report_should_not_list_consistent_tokens() {
+        let checks = vec![
+            check(TokenId::new([1; 32]), vec![]),
+            check(TokenId::new([2; 32]), vec![]),
+        ];
+
+        assert_eq!(report(&checks), "checked 2 tokens, no violations found");
+    }
+
+    #[test]
+    fn report_should_list_violations() {
+        let token_id = TokenId::new([2; 32]);
+        let checks = vec![
+            check(TokenId::new([1; 32]), vec![]),
+            check(
+                token_id,
+                vec![
+                    InvariantViolation::OriginInflowExceedsOutflow {
+                        outflow: Uint256::from(5u128),
+                        inflow: Uint256::from(6u128),
+                    },
+                    InvariantViolation::UnbackedSupply {
+                        claimable: Uint256::from(10u128),
+                        locked: Uint256::zero(),
+                    },
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            report(&checks),
+            format!(
+                "checked 2 tokens, found 2 violations:\n\
+                 token {token_id}: 6 returned to origin chain ethereum, but only 5 left it\n\
+                 token {token_id}: 10 can be bridged back to origin chain ethereum, but only 0 is locked on it"
+            )
+        );
+    }
+}
@@ -2,6 +2,7 @@ use clap::Subcommand;
 use cosmrs::proto::cosmos::auth::v1beta1::query_client::QueryClient as AuthQueryClient;
 use cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::node::v1beta1::service_client::ServiceClient as NodeServiceClient;
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use cosmrs::proto::Any;
 use cosmrs::AccountId;
@@ -12,7 +13,7 @@ use tonic::transport::Channel;
 use valuable::Valuable;
 
 use crate::asyncutil::future::RetryPolicy;
-use crate::broadcaster::confirm_tx::TxConfirmer;
+use crate::broadcaster::confirm_tx::{TxConfirmer, TxStatus};
 use crate::broadcaster::Broadcaster;
 use crate::config::{Config as AmpdConfig, Config};
 use crate::tofnd::grpc::{Multisig, MultisigClient};
@@ -20,6 +21,7 @@ use crate::types::{CosmosPublicKey, TMAddress};
 use crate::{broadcaster, tofnd, Error, PREFIX};
 
 pub mod bond_verifier;
+pub mod check_its_supply;
 pub mod claim_stake;
 pub mod daemon;
 pub mod deregister_chain_support;
@@ -52,6 +54,8 @@ pub enum SubCommand {
     SendTokens(send_tokens::Args),
     /// Set a proxy address to receive rewards, instead of receiving rewards at the verifier address
     SetRewardsProxy(set_rewards_proxy::Args),
+    /// Check the supply of all tokens registered on the ITS hub and report violated supply invariants
+    CheckItsSupply(check_its_supply::Args),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -123,6 +127,7 @@ async fn broadcast_tx(
     confirmation_receiver
         .recv()
         .await
+        .filter(|tx| tx.status != TxStatus::NotFound)
         .ok_or(report!(Error::TxConfirmation))
         .map(|tx| tx.response)
 }
@@ -146,6 +151,10 @@ async fn instantiate_broadcaster(
         .change_context(Error::Connection)
         .attach_printable(tm_grpc.clone())?;
     let bank_query_client = BankQueryClient::connect(tm_grpc.to_string())
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(tm_grpc.clone())?;
+    let node_query_client = NodeServiceClient::connect(tm_grpc.to_string())
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_grpc)?;
@@ -167,6 +176,7 @@ async fn instantiate_broadcaster(
         .signer(multisig_client)
         .auth_query_client(auth_query_client)
         .bank_query_client(bank_query_client)
+        .node_query_client(node_query_client)
         .pub_key((tofnd_config.key_uid, pub_key))
         .config(broadcast)
         .address_prefix(PREFIX.to_string())
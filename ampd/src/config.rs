@@ -2,6 +2,7 @@ use std::net::{Ipv4Addr, SocketAddrV4};
 
 use serde::{Deserialize, Serialize};
 
+use crate::broadcaster::deserialize_account_configs;
 use crate::commands::{RewardsConfig, ServiceRegistryConfig};
 use crate::handlers::config::deserialize_handler_configs;
 use crate::handlers::{self};
@@ -17,6 +18,11 @@ pub struct Config {
     pub tm_grpc: Url,
     pub event_processor: event_processor::Config,
     pub broadcast: broadcaster::Config,
+    #[serde(
+        deserialize_with = "deserialize_account_configs",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub broadcast_accounts: Vec<broadcaster::AccountConfig>,
     #[serde(deserialize_with = "deserialize_handler_configs")]
     pub handlers: Vec<handlers::config::Config>,
     pub tofnd_config: TofndConfig,
@@ -30,6 +36,7 @@ impl Default for Config {
             tm_jsonrpc: "http://localhost:26657".parse().unwrap(),
             tm_grpc: "tcp://localhost:9090".parse().unwrap(),
             broadcast: broadcaster::Config::default(),
+            broadcast_accounts: vec![],
             handlers: vec![],
             tofnd_config: TofndConfig::default(),
             event_processor: event_processor::Config::default(),
@@ -214,6 +221,53 @@ mod tests {
         assert!(toml::from_str::<Config>(config_str.as_str()).is_err());
     }
 
+    #[test]
+    fn deserialize_broadcast_accounts() {
+        let config_str = "
+            [[broadcast_accounts]]
+            key_uid = 'axelar-ethereum'
+            handlers = ['ethereum-msg-verifier', 'ethereum-verifier-set-verifier']
+
+            [[broadcast_accounts]]
+            key_uid = 'axelar-sui'
+            handlers = ['sui-msg-verifier']
+            ";
+
+        let cfg: Config = toml::from_str(config_str).unwrap();
+        assert_eq!(cfg.broadcast_accounts.len(), 2);
+        assert_eq!(cfg.broadcast_accounts[0].handlers.len(), 2);
+    }
+
+    #[test]
+    fn deserialize_broadcast_accounts_with_the_same_handler() {
+        let config_str = "
+            [[broadcast_accounts]]
+            key_uid = 'axelar-ethereum'
+            handlers = ['ethereum-msg-verifier']
+
+            [[broadcast_accounts]]
+            key_uid = 'axelar-sui'
+            handlers = ['ethereum-msg-verifier']
+            ";
+
+        assert!(toml::from_str::<Config>(config_str).is_err());
+    }
+
+    #[test]
+    fn deserialize_broadcast_accounts_with_the_same_key_uid() {
+        let config_str = "
+            [[broadcast_accounts]]
+            key_uid = 'axelar-ethereum'
+            handlers = ['ethereum-msg-verifier']
+
+            [[broadcast_accounts]]
+            key_uid = 'axelar-ethereum'
+            handlers = ['sui-msg-verifier']
+            ";
+
+        assert!(toml::from_str::<Config>(config_str).is_err());
+    }
+
     #[test]
     fn deserialize_url() {
         let expected_url = "tcp://localhost:26657";
@@ -19,6 +19,15 @@ use crate::asyncutil::future::{self, RetryPolicy};
 use crate::asyncutil::task::TaskError;
 use crate::queue::queued_broadcaster::BroadcasterClient;
 
+/// Handlers attach this to an error to signal that the event cannot be handled yet and should be
+/// handed to them again with a later block, up to and including the given block height.
+/// Deferred events are dropped without handing them over again once the poll has expired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deferred {
+    pub until_height: u64,
+    pub expires_at: u64,
+}
+
 #[async_trait]
 pub trait EventHandler {
     type Err: Context;
@@ -37,6 +46,7 @@ pub enum Error {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default)]
 pub struct Config {
     #[serde(with = "humantime_serde")]
     pub retry_delay: Duration,
@@ -44,6 +54,9 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     pub stream_timeout: Duration,
     pub stream_buffer_size: usize,
+    pub vote_deadline_margin: Option<u64>,
+    /// The maximum number of deferred events a handler keeps, further deferred events are dropped
+    pub deferred_events_capacity: usize,
 }
 
 impl Default for Config {
@@ -53,6 +66,8 @@ impl Default for Config {
             retry_max_attempts: 3,
             stream_timeout: Duration::from_secs(15),
             stream_buffer_size: 100000,
+            vote_deadline_margin: None,
+            deferred_events_capacity: 1000,
         }
     }
 }
@@ -75,6 +90,13 @@ where
     E: Context,
 {
     let mut event_stream = Box::pin(event_stream);
+    let mut deferred_events =
+        DeferredEvents::with_capacity(event_processor_config.deferred_events_capacity);
+    let retry_policy = RetryPolicy::RepeatConstant {
+        sleep: event_processor_config.retry_delay,
+        max_attempts: event_processor_config.retry_max_attempts,
+    };
+
     loop {
         let stream_status =
             retrieve_next_event(&mut event_stream, event_processor_config.stream_timeout)
@@ -82,19 +104,18 @@ where
                 .change_context(Error::EventStream)?;
 
         if let StreamStatus::Active(event) = &stream_status {
-            handle_event(
-                &handler,
-                &broadcaster,
-                event,
-                RetryPolicy::RepeatConstant {
-                    sleep: event_processor_config.retry_delay,
-                    max_attempts: event_processor_config.retry_max_attempts,
-                },
-            )
-            .await?;
+            if let Some(deferred) =
+                handle_event(&handler, &broadcaster, event, retry_policy).await?
+            {
+                deferred_events.push(event.clone(), deferred);
+            }
         }
 
         if let StreamStatus::Active(Event::BlockEnd(height)) = &stream_status {
+            deferred_events
+                .retry(&handler, &broadcaster, height.value(), retry_policy)
+                .await?;
+
             info!(
                 handler = handler_label,
                 height = height.value(),
@@ -108,19 +129,34 @@ where
     }
 }
 
+/// Returns the deadline until which the handler asked to defer the event, if it did
 async fn handle_event<H, B>(
     handler: &H,
     broadcaster: &B,
     event: &Event,
     retry_policy: RetryPolicy,
-) -> Result<(), Error>
+) -> Result<Option<Deferred>, Error>
 where
     H: EventHandler,
     B: BroadcasterClient,
 {
+    let outcome = future::with_retry(
+        || async {
+            match handler.handle(event).await {
+                Ok(msgs) => Ok(HandlerOutcome::Msgs(msgs)),
+                Err(err) => match err.downcast_ref::<Deferred>() {
+                    Some(deferred) => Ok(HandlerOutcome::Deferred(*deferred)),
+                    None => Err(err),
+                },
+            }
+        },
+        retry_policy,
+    )
+    .await;
+
     // if handlers run into errors we log them and then move on to the next event
-    match future::with_retry(|| handler.handle(event), retry_policy).await {
-        Ok(msgs) => {
+    match outcome {
+        Ok(HandlerOutcome::Msgs(msgs)) => {
             for msg in msgs {
                 broadcaster
                     .broadcast(msg)
@@ -128,6 +164,7 @@ where
                     .change_context(Error::Broadcaster)?;
             }
         }
+        Ok(HandlerOutcome::Deferred(deferred)) => return Ok(Some(deferred)),
         Err(err) => {
             warn!(
                 err = LoggableError::from(&err).as_value(),
@@ -136,7 +173,78 @@ where
         }
     }
 
-    Ok(())
+    Ok(None)
+}
+
+enum HandlerOutcome {
+    Msgs(Vec<Any>),
+    Deferred(Deferred),
+}
+
+/// Events the handler could not process yet, with the deadline until which they can be retried
+struct DeferredEvents {
+    events: Vec<(Event, Deferred)>,
+    capacity: usize,
+}
+
+impl DeferredEvents {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            events: Vec::new(),
+            capacity,
+        }
+    }
+
+    fn push(&mut self, event: Event, deferred: Deferred) {
+        if self.events.len() >= self.capacity {
+            warn!(
+                capacity = self.capacity,
+                "dropping deferred event {} because too many events are deferred", event
+            );
+            return;
+        }
+
+        self.events.push((event, deferred));
+    }
+
+    /// Hands all deferred events of polls that have not expired yet to the handler again. Events that get
+    /// deferred again are kept for the next block, unless their deadline has already been reached.
+    async fn retry<H, B>(
+        &mut self,
+        handler: &H,
+        broadcaster: &B,
+        height: u64,
+        retry_policy: RetryPolicy,
+    ) -> Result<(), Error>
+    where
+        H: EventHandler,
+        B: BroadcasterClient,
+    {
+        for (event, deferred) in std::mem::take(&mut self.events) {
+            if height >= deferred.expires_at {
+                warn!(
+                    height,
+                    expires_at = deferred.expires_at,
+                    "dropping deferred event {} of an expired poll",
+                    event
+                );
+                continue;
+            }
+
+            match handle_event(handler, broadcaster, &event, retry_policy).await? {
+                Some(deferred) if height < deferred.until_height => self.push(event, deferred),
+                Some(deferred) => warn!(
+                    height,
+                    until_height = deferred.until_height,
+                    "dropping deferred event {} after its deadline",
+                    event
+                ),
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
 }
 
 async fn retrieve_next_event<S, E>(
@@ -189,7 +297,7 @@ mod tests {
     use tokio_util::sync::CancellationToken;
 
     use crate::event_processor;
-    use crate::event_processor::{consume_events, Config, Error, EventHandler};
+    use crate::event_processor::{consume_events, Config, Deferred, Error, EventHandler};
     use crate::queue::queued_broadcaster::MockBroadcasterClient;
 
     pub fn setup_event_config(
@@ -201,6 +309,8 @@ mod tests {
             retry_max_attempts: 3,
             stream_timeout: stream_timeout_value,
             stream_buffer_size: 100000,
+            vote_deadline_margin: None,
+            deferred_events_capacity: 1000,
         }
     }
 
@@ -334,6 +444,153 @@ mod tests {
         assert!(result_with_timeout.unwrap().is_ok());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn retry_deferred_event_at_block_end() {
+        let events: Vec<Result<Event, event_processor::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockEnd(1_u32.into())),
+        ];
+
+        let mut handler = MockEventHandler::new();
+        let mut deferred = false;
+        handler
+            .expect_handle()
+            .withf(|event| matches!(event, Event::BlockBegin(_)))
+            .times(2)
+            .returning(move |_| {
+                if deferred {
+                    Ok(vec![dummy_msg()])
+                } else {
+                    deferred = true;
+                    Err(report!(EventHandlerError::Failed).attach(Deferred {
+                        until_height: 10,
+                        expires_at: 20,
+                    }))
+                }
+            });
+        handler
+            .expect_handle()
+            .withf(|event| matches!(event, Event::BlockEnd(_)))
+            .once()
+            .returning(|_| Ok(vec![]));
+
+        let mut broadcaster = MockBroadcasterClient::new();
+        broadcaster.expect_broadcast().once().returning(|_| Ok(()));
+        let event_config = setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+
+        let result_with_timeout = timeout(
+            Duration::from_secs(1),
+            consume_events(
+                "handler".to_string(),
+                handler,
+                broadcaster,
+                stream::iter(events),
+                event_config,
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+
+        assert!(result_with_timeout.is_ok());
+        assert!(result_with_timeout.unwrap().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drop_deferred_event_after_deadline() {
+        let events: Vec<Result<Event, event_processor::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockEnd(1_u32.into())),
+            Ok(Event::BlockBegin(2_u32.into())),
+            Ok(Event::BlockEnd(2_u32.into())),
+        ];
+
+        let mut handler = MockEventHandler::new();
+        handler
+            .expect_handle()
+            .withf(|event| *event == Event::BlockBegin(1_u32.into()))
+            .times(2)
+            .returning(|_| {
+                Err(report!(EventHandlerError::Failed).attach(Deferred {
+                    until_height: 1,
+                    expires_at: 10,
+                }))
+            });
+        handler
+            .expect_handle()
+            .withf(|event| *event != Event::BlockBegin(1_u32.into()))
+            .times(3)
+            .returning(|_| Ok(vec![]));
+
+        let broadcaster = MockBroadcasterClient::new();
+        let event_config = setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+
+        let result_with_timeout = timeout(
+            Duration::from_secs(1),
+            consume_events(
+                "handler".to_string(),
+                handler,
+                broadcaster,
+                stream::iter(events),
+                event_config,
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+
+        assert!(result_with_timeout.is_ok());
+        assert!(result_with_timeout.unwrap().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drop_deferred_events_beyond_capacity_and_of_expired_polls() {
+        let events: Vec<Result<Event, event_processor::Error>> = vec![
+            Ok(Event::BlockBegin(1_u32.into())),
+            Ok(Event::BlockBegin(2_u32.into())),
+            Ok(Event::BlockEnd(2_u32.into())),
+        ];
+
+        // each event is only handled once, the second deferred event doesn't fit into the queue
+        // and the first one is not handed over again because its poll has expired
+        let mut handler = MockEventHandler::new();
+        handler
+            .expect_handle()
+            .withf(|event| matches!(event, Event::BlockBegin(_)))
+            .times(2)
+            .returning(|_| {
+                Err(report!(EventHandlerError::Failed).attach(Deferred {
+                    until_height: 10,
+                    expires_at: 2,
+                }))
+            });
+        handler
+            .expect_handle()
+            .withf(|event| matches!(event, Event::BlockEnd(_)))
+            .once()
+            .returning(|_| Ok(vec![]));
+
+        let broadcaster = MockBroadcasterClient::new();
+        let event_config = Config {
+            deferred_events_capacity: 1,
+            ..setup_event_config(Duration::from_secs(1), Duration::from_secs(1000))
+        };
+
+        let result_with_timeout = timeout(
+            Duration::from_secs(1),
+            consume_events(
+                "handler".to_string(),
+                handler,
+                broadcaster,
+                stream::iter(events),
+                event_config,
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+
+        assert!(result_with_timeout.is_ok());
+        assert!(result_with_timeout.unwrap().is_ok());
+    }
+
     #[tokio::test]
     async fn react_to_cancellation_at_block_end() {
         let events: Vec<Result<Event, event_processor::Error>> = vec![
@@ -24,6 +24,7 @@ use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
 use crate::evm::verifier::verify_message;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
 use crate::types::{EVMAddress, Hash, TMAddress};
@@ -61,6 +62,7 @@ where
     finalizer_type: Finalization,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<C> Handler<C>
@@ -82,14 +84,24 @@ where
             finalizer_type,
             rpc_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on transactions that exist but are not final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
+    /// Returns the receipts of all finalized transactions,
+    /// and whether any of the transactions exist but are not final yet
     async fn finalized_tx_receipts<T>(
         &self,
         tx_hashes: T,
         confirmation_height: u64,
-    ) -> Result<HashMap<Hash, TransactionReceipt>>
+    ) -> Result<(HashMap<Hash, TransactionReceipt>, bool)>
     where
         T: IntoIterator<Item = Hash>,
     {
@@ -99,7 +111,7 @@ where
                 .await
                 .change_context(Error::Finalizer)?;
 
-        Ok(join_all(
+        let (finalized_tx_receipts, unfinalized_tx_receipts): (Vec<_>, Vec<_>) = join_all(
             tx_hashes
                 .into_iter()
                 .map(|tx_hash| self.rpc_client.transaction_receipt(tx_hash)),
@@ -107,19 +119,22 @@ where
         .await
         .into_iter()
         .filter_map(std::result::Result::unwrap_or_default)
-        .filter_map(|tx_receipt| {
-            if tx_receipt
+        .partition(|tx_receipt| {
+            tx_receipt
                 .block_number
                 .unwrap_or(U64::MAX)
                 .le(&latest_finalized_block_height)
-            {
-                Some((tx_receipt.transaction_hash, tx_receipt))
-            } else {
-                None
-            }
-        })
-        .collect())
+        });
+
+        Ok((
+            finalized_tx_receipts
+                .into_iter()
+                .map(|tx_receipt| (tx_receipt.transaction_hash, tx_receipt))
+                .collect(),
+            !unfinalized_tx_receipts.is_empty(),
+        ))
     }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -176,10 +191,14 @@ where
             .iter()
             .map(|msg| msg.message_id.tx_hash.into())
             .collect();
-        let finalized_tx_receipts = self
+        let (finalized_tx_receipts, has_unfinalized_txs) = self
             .finalized_tx_receipts(tx_hashes, confirmation_height)
             .await?;
 
+        if has_unfinalized_txs {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
+
         let poll_id_str: String = poll_id.into();
         let source_chain_str: String = source_chain.into();
         let votes = info_span!(
@@ -228,7 +247,7 @@ mod tests {
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
     use cosmwasm_std;
     use error_stack::{Report, Result};
-    use ethers_core::types::{H160, H256};
+    use ethers_core::types::{Block, TransactionReceipt, H160, H256, U64};
     use ethers_providers::ProviderError;
     use events::Error::{DeserializationFailed, EventTypeMismatch};
     use events::Event;
@@ -238,11 +257,11 @@ mod tests {
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
     use super::PollStartedEvent;
-    use crate::event_processor::EventHandler;
+    use crate::event_processor::{Deferred, EventHandler};
     use crate::evm::finalizer::Finalization;
     use crate::evm::json_rpc::MockEthereumClient;
     use crate::handlers::tests::{into_structured_event, participants};
-    use crate::types::TMAddress;
+    use crate::types::{Hash, TMAddress};
     use crate::PREFIX;
 
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
@@ -390,4 +409,57 @@ mod tests {
         // poll is expired, should not hit rpc error now
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
+
+    #[async_test]
+    async fn should_defer_vote_for_unfinalized_txs_until_deadline() {
+        let mut rpc_client = MockEthereumClient::new();
+        let mut block = Block::<Hash>::default();
+        block.number = Some(U64::from(10));
+        rpc_client
+            .expect_finalized_block()
+            .returning(move || Ok(block.clone()));
+        rpc_client.expect_transaction_receipt().returning(|hash| {
+            Ok(Some(TransactionReceipt {
+                transaction_hash: hash,
+                block_number: Some(U64::from(11)),
+                ..TransactionReceipt::default()
+            }))
+        });
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let vote_deadline_margin = 10u64;
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier_contract,
+        );
+
+        let (tx, rx) = watch::channel(expiration - vote_deadline_margin - 1);
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier_contract,
+            ChainName::from_str("ethereum").unwrap(),
+            Finalization::RPCFinalizedBlock,
+            rpc_client,
+            rx,
+        )
+        .vote_deadline_margin(Some(vote_deadline_margin));
+
+        // vote deadline is not reached yet, so the vote is deferred
+        let err = handler.handle(&event).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Deferred>(),
+            Some(&Deferred {
+                until_height: expiration - vote_deadline_margin,
+                expires_at: expiration,
+            })
+        );
+
+        let _ = tx.send(expiration - vote_deadline_margin);
+
+        // vote deadline is reached, so the handler votes with the unfinalized txs treated as not found
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
 }
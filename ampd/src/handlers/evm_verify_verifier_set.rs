@@ -23,6 +23,7 @@ use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
 use crate::evm::verifier::verify_verifier_set;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::types::{EVMAddress, Hash, TMAddress};
 
@@ -56,6 +57,7 @@ where
     finalizer_type: Finalization,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<C> Handler<C>
@@ -77,14 +79,24 @@ where
             finalizer_type,
             rpc_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on a transaction that exists but is not final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
+    /// Returns the receipt if the transaction is finalized,
+    /// and whether the transaction exists but is not final yet
     async fn finalized_tx_receipt(
         &self,
         tx_hash: Hash,
         confirmation_height: u64,
-    ) -> Result<Option<TransactionReceipt>> {
+    ) -> Result<(Option<TransactionReceipt>, bool)> {
         let latest_finalized_block_height =
             finalizer::pick(&self.finalizer_type, &self.rpc_client, confirmation_height)
                 .latest_finalized_block_height()
@@ -96,17 +108,18 @@ where
             .await
             .change_context(Error::Finalizer)?;
 
-        Ok(tx_receipt.and_then(|tx_receipt| {
-            if tx_receipt
-                .block_number
-                .unwrap_or(U64::MAX)
-                .le(&latest_finalized_block_height)
+        match tx_receipt {
+            Some(tx_receipt)
+                if tx_receipt
+                    .block_number
+                    .unwrap_or(U64::MAX)
+                    .le(&latest_finalized_block_height) =>
             {
-                Some(tx_receipt)
-            } else {
-                None
+                Ok((Some(tx_receipt), false))
             }
-        }))
+            Some(_) => Ok((None, true)),
+            None => Ok((None, false)),
+        }
     }
 
     fn vote_msg(&self, poll_id: PollId, vote: Vote) -> MsgExecuteContract {
@@ -164,9 +177,13 @@ where
             return Ok(vec![]);
         }
 
-        let tx_receipt = self
+        let (tx_receipt, is_unfinalized) = self
             .finalized_tx_receipt(verifier_set.message_id.tx_hash.into(), confirmation_height)
             .await?;
+
+        if is_unfinalized {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
         let vote = info_span!(
             "verify a new verifier set for an EVM chain",
             poll_id = poll_id.to_string(),
@@ -1,3 +1,7 @@
+use error_stack::report;
+
+use crate::event_processor::Deferred;
+
 pub mod config;
 mod errors;
 pub mod evm_verify_msg;
@@ -10,6 +14,31 @@ pub(crate) mod stellar_verify_verifier_set;
 pub mod sui_verify_msg;
 pub mod sui_verify_verifier_set;
 
+/// Asks the event processor to hand the event over again in a later block if the vote can still wait
+/// for transactions to become final, i.e. the deadline `vote_deadline_margin` blocks before
+/// the poll expiry has not been reached yet. Without a margin votes are never deferred.
+/// Handlers of chains whose RPC only returns finalized transactions call this whenever a transaction is missing,
+/// because it can't be told apart from a transaction that is not final yet.
+fn defer_vote(
+    latest_block_height: u64,
+    expires_at: u64,
+    vote_deadline_margin: Option<u64>,
+) -> error_stack::Result<(), errors::Error> {
+    let Some(vote_deadline_margin) = vote_deadline_margin else {
+        return Ok(());
+    };
+
+    let deadline = expires_at.saturating_sub(vote_deadline_margin);
+    if latest_block_height < deadline {
+        return Err(report!(errors::Error::TxNotFinalized).attach(Deferred {
+            until_height: deadline,
+            expires_at,
+        }));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryInto;
@@ -17,6 +17,7 @@ use tracing::info;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::mvx::proxy::MvxProxy;
 use crate::mvx::verifier::verify_message;
@@ -52,6 +53,7 @@ where
     voting_verifier_contract: TMAddress,
     blockchain: P,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<P> Handler<P>
@@ -69,9 +71,17 @@ where
             voting_verifier_contract,
             blockchain,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on transactions that are not found yet, as they might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -126,6 +136,13 @@ where
             .transactions_info_with_results(tx_hashes)
             .await;
 
+        if messages
+            .iter()
+            .any(|msg| !transactions_info.contains_key(&msg.tx_id))
+        {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
+
         let votes: Vec<Vote> = messages
             .iter()
             .map(|msg| {
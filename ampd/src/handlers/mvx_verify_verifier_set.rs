@@ -18,6 +18,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::mvx::proxy::MvxProxy;
 use crate::mvx::verifier::verify_verifier_set;
@@ -48,6 +49,7 @@ where
     voting_verifier_contract: TMAddress,
     blockchain: P,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<P> Handler<P>
@@ -65,9 +67,17 @@ where
             voting_verifier_contract,
             blockchain,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on a transaction that is not found yet, as it might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, vote: Vote) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -123,6 +133,10 @@ where
             .transaction_info_with_results(&verifier_set.tx_id)
             .await;
 
+        if transaction_info.is_none() {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
+
         let vote = info_span!(
             "verify a new verifier set for MultiversX",
             poll_id = poll_id.to_string(),
@@ -21,6 +21,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
 use crate::stellar::rpc_client::Client;
@@ -56,6 +57,7 @@ pub struct Handler {
     voting_verifier_contract: TMAddress,
     http_client: Client,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl Handler {
@@ -70,9 +72,17 @@ impl Handler {
             voting_verifier_contract,
             http_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on transactions that are not found yet, as they might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -127,6 +137,17 @@ impl EventHandler for Handler {
             .await
             .change_context(Error::TxReceipts)?;
 
+        if messages.iter().any(|msg| {
+            !transaction_responses
+                .contains_key(&msg.message_id.tx_hash_as_hex_no_prefix().to_string())
+        }) {
+            defer_vote(
+                *self.latest_block_height.borrow(),
+                expires_at,
+                self.vote_deadline_margin,
+            )?;
+        }
+
         let message_ids = messages
             .iter()
             .map(|message| message.message_id.to_string())
@@ -20,6 +20,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
 use crate::stellar::rpc_client::Client;
@@ -49,6 +50,7 @@ pub struct Handler {
     voting_verifier_contract: TMAddress,
     http_client: Client,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl Handler {
@@ -63,9 +65,17 @@ impl Handler {
             voting_verifier_contract,
             http_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on a transaction that is not found yet, as it might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -119,6 +129,14 @@ impl EventHandler for Handler {
             .await
             .change_context(Error::TxReceipts)?;
 
+        if transaction_response.is_none() {
+            defer_vote(
+                *self.latest_block_height.borrow(),
+                expires_at,
+                self.vote_deadline_margin,
+            )?;
+        }
+
         let vote = info_span!(
             "verify a new verifier set",
             poll_id = poll_id.to_string(),
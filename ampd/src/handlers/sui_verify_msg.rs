@@ -18,6 +18,7 @@ use tracing::info;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::sui::json_rpc::SuiClient;
 use crate::sui::verifier::verify_message;
@@ -52,6 +53,7 @@ where
     voting_verifier_contract: TMAddress,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<C> Handler<C>
@@ -69,9 +71,17 @@ where
             voting_verifier_contract,
             rpc_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on transactions that are not found yet, as they might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -132,6 +142,13 @@ where
             .await
             .change_context(Error::TxReceipts)?;
 
+        if messages
+            .iter()
+            .any(|msg| !transaction_blocks.contains_key(&msg.message_id.tx_digest.into()))
+        {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
+
         let votes = messages
             .iter()
             .map(|msg| {
@@ -169,7 +186,7 @@ mod tests {
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
     use super::PollStartedEvent;
-    use crate::event_processor::EventHandler;
+    use crate::event_processor::{Deferred, EventHandler};
     use crate::handlers::errors::Error;
     use crate::handlers::tests::{into_structured_event, participants};
     use crate::sui::json_rpc::MockSuiClient;
@@ -327,6 +344,43 @@ mod tests {
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
 
+    #[async_test]
+    async fn should_defer_vote_for_missing_txs_until_deadline() {
+        let mut rpc_client = MockSuiClient::new();
+        rpc_client
+            .expect_finalized_transaction_blocks()
+            .returning(|_| Ok(HashMap::new()));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let vote_deadline_margin = 10u64;
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier,
+        );
+
+        let (tx, rx) = watch::channel(expiration - vote_deadline_margin - 1);
+
+        let handler = super::Handler::new(verifier, voting_verifier, rpc_client, rx)
+            .vote_deadline_margin(Some(vote_deadline_margin));
+
+        // vote deadline is not reached yet, so the vote is deferred
+        let err = handler.handle(&event).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Deferred>(),
+            Some(&Deferred {
+                until_height: expiration - vote_deadline_margin,
+                expires_at: expiration,
+            })
+        );
+
+        let _ = tx.send(expiration - vote_deadline_margin);
+
+        // vote deadline is reached, so the handler votes with the missing txs treated as not found
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         let msg_id = Base58TxDigestAndEventIndex::new([1; 32], 0u64);
         PollStarted::Messages {
@@ -19,6 +19,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::handlers::defer_vote;
 use crate::handlers::errors::Error;
 use crate::sui::json_rpc::SuiClient;
 use crate::sui::verifier::verify_verifier_set;
@@ -48,6 +49,7 @@ where
     voting_verifier_contract: TMAddress,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
+    vote_deadline_margin: Option<u64>,
 }
 
 impl<C> Handler<C>
@@ -65,9 +67,17 @@ where
             voting_verifier_contract,
             rpc_client,
             latest_block_height,
+            vote_deadline_margin: None,
         }
     }
 
+    /// Defer voting on a transaction that is not found yet, as it might not be final yet,
+    /// until `vote_deadline_margin` blocks before the poll expires
+    pub fn vote_deadline_margin(mut self, vote_deadline_margin: Option<u64>) -> Self {
+        self.vote_deadline_margin = vote_deadline_margin;
+        self
+    }
+
     fn vote_msg(&self, poll_id: PollId, vote: Vote) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -124,6 +134,10 @@ where
             .await
             .change_context(Error::TxReceipts)?;
 
+        if transaction_block.is_none() {
+            defer_vote(latest_block_height, expires_at, self.vote_deadline_margin)?;
+        }
+
         let vote = info_span!(
             "verify a new verifier set for Sui",
             poll_id = poll_id.to_string(),
@@ -5,13 +5,15 @@ use block_height_monitor::BlockHeightMonitor;
 use broadcaster::Broadcaster;
 use cosmrs::proto::cosmos::auth::v1beta1::query_client::QueryClient as AuthQueryClient;
 use cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
+use cosmrs::proto::cosmos::base::node::v1beta1::service_client::ServiceClient as NodeServiceClient;
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
-use error_stack::{FutureExt, Result, ResultExt};
+use error_stack::{report, FutureExt, Result, ResultExt};
 use event_processor::EventHandler;
 use event_sub::EventSub;
 use evm::finalizer::{pick, Finalization};
 use evm::json_rpc::EthereumClient;
 use multiversx_sdk::gateway::GatewayProxy;
+use queue::authz::AuthzBroadcasterClient;
 use queue::queued_broadcaster::QueuedBroadcaster;
 use router_api::ChainName;
 use thiserror::Error;
@@ -64,6 +66,7 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         tm_jsonrpc,
         tm_grpc,
         broadcast,
+        broadcast_accounts,
         handlers,
         tofnd_config,
         event_processor,
@@ -87,6 +90,10 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_grpc.clone())?;
+    let node_query_client = NodeServiceClient::connect(tm_grpc.to_string())
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(tm_grpc.clone())?;
     let multisig_client = MultisigClient::new(tofnd_config.party_uid, tofnd_config.url.clone())
         .await
         .change_context(Error::Connection)
@@ -97,19 +104,72 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         .change_context(Error::Connection)
         .attach_printable(tm_jsonrpc)?;
 
+    let new_broadcaster_account = |key_uid: String, handlers: Vec<String>| {
+        create_broadcaster_account(
+            key_uid,
+            handlers,
+            service_client.clone(),
+            auth_query_client.clone(),
+            bank_query_client.clone(),
+            node_query_client.clone(),
+            multisig_client.clone(),
+            broadcast.clone(),
+        )
+    };
+
+    let default_broadcaster_account = new_broadcaster_account(tofnd_config.key_uid, vec![]).await?;
+    let mut broadcaster_accounts = vec![];
+    for broadcaster::AccountConfig { key_uid, handlers } in broadcast_accounts {
+        broadcaster_accounts.push(new_broadcaster_account(key_uid, handlers).await?);
+    }
+
+    let health_check_server = health_check::Server::new(health_check_bind_addr);
+
+    App::new(
+        tm_client,
+        default_broadcaster_account,
+        broadcaster_accounts,
+        multisig_client,
+        event_processor.stream_buffer_size,
+        block_height_monitor,
+        health_check_server,
+    )
+    .configure_handlers(handlers, event_processor)
+    .await
+}
+
+/// Creates a broadcaster for the tofnd key `key_uid` with its own message queue and account sequence tracking.
+/// The account broadcasts on behalf of the handlers with the given labels.
+async fn create_broadcaster_account(
+    key_uid: String,
+    handlers: Vec<String>,
+    service_client: ServiceClient<Channel>,
+    auth_query_client: AuthQueryClient<Channel>,
+    bank_query_client: BankQueryClient<Channel>,
+    node_query_client: NodeServiceClient<Channel>,
+    multisig_client: MultisigClient,
+    broadcast: broadcaster::Config,
+) -> Result<BroadcasterAccount<impl Broadcaster>, Error> {
     let pub_key = multisig_client
-        .keygen(&tofnd_config.key_uid, tofnd::Algorithm::Ecdsa)
+        .keygen(&key_uid, tofnd::Algorithm::Ecdsa)
         .await
-        .change_context(Error::Tofnd)?;
+        .change_context(Error::Tofnd)
+        .attach_printable(key_uid.clone())?;
     let pub_key = CosmosPublicKey::try_from(pub_key).change_context(Error::Tofnd)?;
 
+    let verifier: TMAddress = pub_key
+        .account_id(PREFIX)
+        .expect("failed to convert to account identifier")
+        .into();
+
     let broadcaster = broadcaster::UnvalidatedBasicBroadcaster::builder()
         .auth_query_client(auth_query_client)
         .bank_query_client(bank_query_client)
+        .node_query_client(node_query_client)
         .address_prefix(PREFIX.to_string())
         .client(service_client.clone())
-        .signer(multisig_client.clone())
-        .pub_key((tofnd_config.key_uid, pub_key))
+        .signer(multisig_client)
+        .pub_key((key_uid, pub_key))
         .config(broadcast.clone())
         .build()
         .validate_fee_denomination()
@@ -121,7 +181,9 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         broadcast.batch_gas_limit,
         broadcast.queue_cap,
         interval(broadcast.broadcast_interval),
-    );
+    )
+    .priority_lanes(broadcast.priority_lanes.clone())
+    .tx_resubmission_max_retries(broadcast.tx_resubmission_max_retries);
 
     let tx_confirmer = TxConfirmer::new(
         service_client,
@@ -131,24 +193,12 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         },
     );
 
-    let health_check_server = health_check::Server::new(health_check_bind_addr);
-
-    let verifier: TMAddress = pub_key
-        .account_id(PREFIX)
-        .expect("failed to convert to account identifier")
-        .into();
-
-    App::new(
-        tm_client,
+    Ok(BroadcasterAccount {
+        verifier,
+        handlers,
         broadcaster,
         tx_confirmer,
-        multisig_client,
-        event_processor.stream_buffer_size,
-        block_height_monitor,
-        health_check_server,
-    )
-    .configure_handlers(verifier, handlers, event_processor)
-    .await
+    })
 }
 
 async fn check_finalizer<'a, C>(
@@ -167,6 +217,16 @@ where
     Ok(())
 }
 
+struct BroadcasterAccount<T>
+where
+    T: Broadcaster,
+{
+    verifier: TMAddress,
+    handlers: Vec<String>,
+    broadcaster: QueuedBroadcaster<T>,
+    tx_confirmer: TxConfirmer<ServiceClient<Channel>>,
+}
+
 struct App<T>
 where
     T: Broadcaster,
@@ -174,8 +234,9 @@ where
     event_publisher: event_sub::EventPublisher<tendermint_rpc::HttpClient>,
     event_subscriber: event_sub::EventSubscriber,
     event_processor: TaskGroup<event_processor::Error>,
-    broadcaster: QueuedBroadcaster<T>,
-    tx_confirmer: TxConfirmer<ServiceClient<Channel>>,
+    default_broadcaster_account: BroadcasterAccount<T>,
+    broadcaster_accounts: Vec<BroadcasterAccount<T>>,
+    handler_labels: Vec<String>,
     multisig_client: MultisigClient,
     block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
     health_check_server: health_check::Server,
@@ -188,8 +249,8 @@ where
     #[allow(clippy::too_many_arguments)]
     fn new(
         tm_client: tendermint_rpc::HttpClient,
-        broadcaster: QueuedBroadcaster<T>,
-        tx_confirmer: TxConfirmer<ServiceClient<Channel>>,
+        default_broadcaster_account: BroadcasterAccount<T>,
+        broadcaster_accounts: Vec<BroadcasterAccount<T>>,
         multisig_client: MultisigClient,
         event_buffer_cap: usize,
         block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
@@ -204,17 +265,44 @@ where
             event_publisher,
             event_subscriber,
             event_processor,
-            broadcaster,
-            tx_confirmer,
+            default_broadcaster_account,
+            broadcaster_accounts,
+            handler_labels: vec![],
             multisig_client,
             block_height_monitor,
             health_check_server,
         }
     }
 
+    /// Handlers that are not explicitly assigned to a broadcaster account use the default account
+    fn broadcaster_account(&mut self, handler_label: &str) -> &mut BroadcasterAccount<T> {
+        self.broadcaster_accounts
+            .iter_mut()
+            .find(|account| account.handlers.iter().any(|label| label == handler_label))
+            .unwrap_or(&mut self.default_broadcaster_account)
+    }
+
+    /// Handlers always vote and sign as the verifier. Handlers assigned to another broadcaster account
+    /// broadcast through that account on behalf of the verifier via authz
+    fn verifier(&self) -> TMAddress {
+        self.default_broadcaster_account.verifier.clone()
+    }
+
+    /// Every handler assigned to a broadcaster account must be one of the configured handlers
+    fn validate_broadcaster_accounts(&self) -> Result<(), Error> {
+        match self
+            .broadcaster_accounts
+            .iter()
+            .flat_map(|account| &account.handlers)
+            .find(|label| !self.handler_labels.contains(label))
+        {
+            Some(label) => Err(report!(Error::UnknownHandler(label.clone()))),
+            None => Ok(()),
+        }
+    }
+
     async fn configure_handlers(
         mut self,
-        verifier: TMAddress,
         handler_configs: Vec<handlers::config::Config>,
         event_processor_config: event_processor::Config,
     ) -> Result<App<T>, Error> {
@@ -236,16 +324,20 @@ where
 
                     check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
 
+                    let label = format!("{}-msg-verifier", chain.name);
+                    let verifier = self.verifier();
+
                     self.create_handler_task(
-                        format!("{}-msg-verifier", chain.name),
+                        label,
                         handlers::evm_verify_msg::Handler::new(
-                            verifier.clone(),
+                            verifier,
                             cosmwasm_contract,
                             chain.name,
                             chain.finalization,
                             rpc_client,
                             self.block_height_monitor.latest_block_height(),
-                        ),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
                         event_processor_config.clone(),
                     )
                 }
@@ -265,134 +357,180 @@ where
 
                     check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
 
+                    let label = format!("{}-verifier-set-verifier", chain.name);
+                    let verifier = self.verifier();
+
                     self.create_handler_task(
-                        format!("{}-verifier-set-verifier", chain.name),
+                        label,
                         handlers::evm_verify_verifier_set::Handler::new(
-                            verifier.clone(),
+                            verifier,
                             cosmwasm_contract,
                             chain.name,
                             chain.finalization,
                             rpc_client,
                             self.block_height_monitor.latest_block_height(),
-                        ),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
                         event_processor_config.clone(),
                     )
                 }
-                handlers::config::Config::MultisigSigner { cosmwasm_contract } => self
-                    .create_handler_task(
-                        "multisig-signer",
+                handlers::config::Config::MultisigSigner { cosmwasm_contract } => {
+                    let label = "multisig-signer";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
                         handlers::multisig::Handler::new(
-                            verifier.clone(),
+                            verifier,
                             cosmwasm_contract,
                             self.multisig_client.clone(),
                             self.block_height_monitor.latest_block_height(),
                         ),
                         event_processor_config.clone(),
-                    ),
+                    )
+                }
                 handlers::config::Config::SuiMsgVerifier {
                     cosmwasm_contract,
                     rpc_url,
                     rpc_timeout,
-                } => self.create_handler_task(
-                    "sui-msg-verifier",
-                    handlers::sui_verify_msg::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        json_rpc::Client::new_http(
-                            &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                } => {
+                    let label = "sui-msg-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::sui_verify_msg::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            json_rpc::Client::new_http(
+                                &rpc_url,
+                                reqwest::ClientBuilder::new()
+                                    .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                                    .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                                    .build()
+                                    .change_context(Error::Connection)?,
+                            ),
+                            self.block_height_monitor.latest_block_height(),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
                 handlers::config::Config::SuiVerifierSetVerifier {
                     cosmwasm_contract,
                     rpc_url,
                     rpc_timeout,
-                } => self.create_handler_task(
-                    "sui-verifier-set-verifier",
-                    handlers::sui_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        json_rpc::Client::new_http(
-                            &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                } => {
+                    let label = "sui-verifier-set-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::sui_verify_verifier_set::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            json_rpc::Client::new_http(
+                                &rpc_url,
+                                reqwest::ClientBuilder::new()
+                                    .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                                    .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                                    .build()
+                                    .change_context(Error::Connection)?,
+                            ),
+                            self.block_height_monitor.latest_block_height(),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
                 handlers::config::Config::MvxMsgVerifier {
                     cosmwasm_contract,
                     proxy_url,
-                } => self.create_handler_task(
-                    "mvx-msg-verifier",
-                    handlers::mvx_verify_msg::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                } => {
+                    let label = "mvx-msg-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::mvx_verify_msg::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                            self.block_height_monitor.latest_block_height(),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
                 handlers::config::Config::MvxVerifierSetVerifier {
                     cosmwasm_contract,
                     proxy_url,
-                } => self.create_handler_task(
-                    "mvx-worker-set-verifier",
-                    handlers::mvx_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                } => {
+                    let label = "mvx-worker-set-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::mvx_verify_verifier_set::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                            self.block_height_monitor.latest_block_height(),
+                        )
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
                 handlers::config::Config::StellarMsgVerifier {
                     cosmwasm_contract,
                     rpc_url,
-                } => self.create_handler_task(
-                    "stellar-msg-verifier",
-                    handlers::stellar_verify_msg::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        stellar::rpc_client::Client::new(
-                            rpc_url.to_string().trim_end_matches('/').into(),
+                } => {
+                    let label = "stellar-msg-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::stellar_verify_msg::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            stellar::rpc_client::Client::new(
+                                rpc_url.to_string().trim_end_matches('/').into(),
+                            )
+                            .change_context(Error::Connection)?,
+                            self.block_height_monitor.latest_block_height(),
                         )
-                        .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
                 handlers::config::Config::StellarVerifierSetVerifier {
                     cosmwasm_contract,
                     rpc_url,
-                } => self.create_handler_task(
-                    "stellar-verifier-set-verifier",
-                    handlers::stellar_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        stellar::rpc_client::Client::new(
-                            rpc_url.to_string().trim_end_matches('/').into(),
+                } => {
+                    let label = "stellar-verifier-set-verifier";
+                    let verifier = self.verifier();
+
+                    self.create_handler_task(
+                        label,
+                        handlers::stellar_verify_verifier_set::Handler::new(
+                            verifier,
+                            cosmwasm_contract,
+                            stellar::rpc_client::Client::new(
+                                rpc_url.to_string().trim_end_matches('/').into(),
+                            )
+                            .change_context(Error::Connection)?,
+                            self.block_height_monitor.latest_block_height(),
                         )
-                        .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
+                        .vote_deadline_margin(event_processor_config.vote_deadline_margin),
+                        event_processor_config.clone(),
+                    )
+                }
             };
             self.event_processor = self.event_processor.add_task(task);
         }
 
+        self.validate_broadcaster_accounts()?;
+
         Ok(self)
     }
 
@@ -407,29 +545,53 @@ where
         H: EventHandler + Send + Sync + 'static,
     {
         let label = label.as_ref().to_string();
-        let broadcaster = self.broadcaster.client();
-        let sub = self.event_subscriber.subscribe();
+        self.handler_labels.push(label.clone());
 
-        CancellableTask::create(move |token| {
-            event_processor::consume_events(
-                label,
-                handler,
-                broadcaster,
-                sub,
-                event_processor_config,
-                token,
-            )
-        })
+        let sub = self.event_subscriber.subscribe();
+        let verifier = self.verifier();
+        let account = self.broadcaster_account(&label);
+        let grantee = account.verifier.clone();
+        let broadcaster = account.broadcaster.client();
+
+        if grantee == verifier {
+            CancellableTask::create(move |token| {
+                event_processor::consume_events(
+                    label,
+                    handler,
+                    broadcaster,
+                    sub,
+                    event_processor_config,
+                    token,
+                )
+            })
+        } else {
+            let broadcaster = AuthzBroadcasterClient::new(broadcaster, grantee);
+
+            CancellableTask::create(move |token| {
+                event_processor::consume_events(
+                    label,
+                    handler,
+                    broadcaster,
+                    sub,
+                    event_processor_config,
+                    token,
+                )
+            })
+        }
     }
 
     fn create_broadcaster_task(
-        broadcaster: QueuedBroadcaster<T>,
-        confirmer: TxConfirmer<ServiceClient<Channel>>,
+        BroadcasterAccount {
+            verifier,
+            broadcaster,
+            tx_confirmer: confirmer,
+            ..
+        }: BroadcasterAccount<T>,
     ) -> TaskGroup<Error> {
         let (tx_hash_sender, tx_hash_receiver) = mpsc::channel(1000);
         let (tx_response_sender, tx_response_receiver) = mpsc::channel(1000);
 
-        TaskGroup::new("broadcaster")
+        TaskGroup::new(format!("broadcaster {}", verifier))
             .add_task(CancellableTask::create(|_| {
                 confirmer
                     .run(tx_hash_receiver, tx_response_sender)
@@ -446,8 +608,8 @@ where
         let Self {
             event_publisher,
             event_processor,
-            broadcaster,
-            tx_confirmer,
+            default_broadcaster_account,
+            broadcaster_accounts,
             block_height_monitor,
             health_check_server,
             ..
@@ -491,7 +653,13 @@ where
                     .change_context(Error::EventProcessor)
             }))
             .add_task(CancellableTask::create(|token| {
-                App::create_broadcaster_task(broadcaster, tx_confirmer).run(token)
+                std::iter::once(default_broadcaster_account)
+                    .chain(broadcaster_accounts)
+                    .map(App::create_broadcaster_task)
+                    .fold(TaskGroup::new("broadcasters"), |group, broadcaster_task| {
+                        group.add_task(CancellableTask::create(|token| broadcaster_task.run(token)))
+                    })
+                    .run(token)
             }))
             .run(main_token)
             .await
@@ -526,4 +694,8 @@ pub enum Error {
     InvalidFinalizerType(ChainName),
     #[error("health check is not working")]
     HealthCheck,
+    #[error("failed to query contract")]
+    ContractQuery,
+    #[error("broadcaster account is assigned to unknown handler {0}")]
+    UnknownHandler(String),
 }
@@ -5,9 +5,9 @@ use std::process::ExitCode;
 
 use ::config::{Config as cfg, Environment, File, FileFormat, FileSourceFile};
 use ampd::commands::{
-    bond_verifier, claim_stake, daemon, deregister_chain_support, register_chain_support,
-    register_public_key, send_tokens, set_rewards_proxy, unbond_verifier, verifier_address,
-    SubCommand,
+    bond_verifier, check_its_supply, claim_stake, daemon, deregister_chain_support,
+    register_chain_support, register_public_key, send_tokens, set_rewards_proxy, unbond_verifier,
+    verifier_address, SubCommand,
 };
 use ampd::config::Config;
 use ampd::Error;
@@ -71,6 +71,7 @@ async fn main() -> ExitCode {
         Some(SubCommand::ClaimStake(args)) => claim_stake::run(cfg, args).await,
         Some(SubCommand::SendTokens(args)) => send_tokens::run(cfg, args).await,
         Some(SubCommand::SetRewardsProxy(args)) => set_rewards_proxy::run(cfg, args).await,
+        Some(SubCommand::CheckItsSupply(args)) => check_its_supply::run(cfg, args).await,
     };
 
     match result {
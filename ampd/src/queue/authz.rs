@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use cosmrs::proto::cosmos::authz::v1beta1::MsgExec;
+use cosmrs::Any;
+use prost::Message;
+
+use super::queued_broadcaster::{BroadcasterClient, Error};
+use crate::types::TMAddress;
+
+const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+
+/// Broadcasts messages of the verifier through a grantee account that the verifier has authorized via authz,
+/// so the messages are still executed with the verifier as their sender
+pub struct AuthzBroadcasterClient<B> {
+    client: B,
+    grantee: TMAddress,
+}
+
+impl<B> AuthzBroadcasterClient<B> {
+    pub fn new(client: B, grantee: TMAddress) -> Self {
+        Self { client, grantee }
+    }
+}
+
+#[async_trait]
+impl<B> BroadcasterClient for AuthzBroadcasterClient<B>
+where
+    B: BroadcasterClient + Send + Sync,
+{
+    async fn broadcast(&self, msg: Any) -> error_stack::Result<(), Error> {
+        self.client.broadcast(exec(&self.grantee, msg)).await
+    }
+}
+
+/// Wraps `msg` in an authz exec message broadcast by `grantee`
+pub fn exec(grantee: &TMAddress, msg: Any) -> Any {
+    Any {
+        type_url: MSG_EXEC_TYPE_URL.to_string(),
+        value: MsgExec {
+            grantee: grantee.to_string(),
+            msgs: vec![msg],
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// The message executed by an authz exec message that wraps a single message, `None` for any other message
+pub fn executed_msg(msg: &Any) -> Option<Any> {
+    if msg.type_url != MSG_EXEC_TYPE_URL {
+        return None;
+    }
+
+    MsgExec::decode(msg.value.as_slice())
+        .ok()
+        .filter(|exec| exec.msgs.len() == 1)
+        .and_then(|exec| exec.msgs.into_iter().next())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::bank::MsgSend;
+    use cosmrs::proto::cosmos::authz::v1beta1::MsgExec;
+    use cosmrs::tx::Msg;
+    use cosmrs::{AccountId, Any};
+    use prost::Message;
+    use tokio::sync::Mutex;
+
+    use super::{executed_msg, AuthzBroadcasterClient};
+    use crate::queue::queued_broadcaster::{BroadcasterClient, Error};
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    struct RecordingClient {
+        msgs: Mutex<Vec<Any>>,
+    }
+
+    #[async_trait::async_trait]
+    impl BroadcasterClient for RecordingClient {
+        async fn broadcast(&self, msg: Any) -> error_stack::Result<(), Error> {
+            self.msgs.lock().await.push(msg);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn authz_broadcaster_client_should_wrap_msgs_in_exec_for_grantee() {
+        let grantee = TMAddress::random(PREFIX);
+        let client = AuthzBroadcasterClient::new(
+            RecordingClient {
+                msgs: Mutex::new(vec![]),
+            },
+            grantee.clone(),
+        );
+
+        client.broadcast(dummy_msg()).await.unwrap();
+
+        let msgs = client.client.msgs.into_inner();
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(msgs[0].type_url, "/cosmos.authz.v1beta1.MsgExec");
+
+        let exec = MsgExec::decode(msgs[0].value.as_slice()).unwrap();
+        assert_eq!(exec.grantee, grantee.to_string());
+        assert_eq!(exec.msgs, vec![dummy_msg()]);
+        assert_eq!(executed_msg(&msgs[0]), Some(dummy_msg()));
+    }
+
+    #[test]
+    fn executed_msg_should_ignore_other_msgs() {
+        assert_eq!(executed_msg(&dummy_msg()), None);
+    }
+
+    fn dummy_msg() -> Any {
+        MsgSend {
+            from_address: AccountId::new("", &[1, 2, 3]).unwrap(),
+            to_address: AccountId::new("", &[4, 5, 6]).unwrap(),
+            amount: vec![],
+        }
+        .to_any()
+        .unwrap()
+    }
+}
@@ -1,3 +1,4 @@
+pub mod authz;
 mod msg_queue;
 mod proto;
 pub mod queued_broadcaster;
@@ -1,64 +1,221 @@
+use std::collections::VecDeque;
+
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
 use cosmrs::{Any, Gas};
 use error_stack::Result;
+use serde_json::{Map, Value};
 use thiserror::Error;
 use tracing::info;
 
+use super::authz;
+use crate::broadcaster::PriorityLane;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("overflow in gas cost calculation")]
     GasCostOverflow,
 }
 
-#[derive(Default)]
-pub struct MsgQueue {
-    msgs: Vec<Any>,
+struct Lane {
+    msg_types: Vec<String>,
+    gas_budget: Gas,
+    msgs: VecDeque<(Any, Gas)>,
     gas_cost: Gas,
 }
 
+impl Lane {
+    fn new(msg_types: Vec<String>, gas_budget: Gas) -> Self {
+        Self {
+            msg_types,
+            gas_budget,
+            msgs: VecDeque::new(),
+            gas_cost: 0,
+        }
+    }
+
+    fn accepts(&self, msg_type: &str) -> bool {
+        self.msg_types
+            .iter()
+            .any(|lane_msg_type| lane_msg_type == msg_type)
+    }
+}
+
+/// Share of the batch gas limit (in percent) that priority lanes leave to the catch-all lane while it holds messages
+const CATCH_ALL_LANE_RESERVED_GAS_PERCENT: Gas = 10;
+
+/// Queue that sorts messages into priority lanes. Each lane holds the messages of the configured types
+/// and contributes at most its gas budget to a batch. Messages that don't belong to any configured lane
+/// are put into a last lane that is only bounded by the batch gas limit. While that lane holds messages,
+/// the priority lanes leave part of each batch to it, so it can't be starved.
+pub struct MsgQueue {
+    lanes: Vec<Lane>,
+    batch_gas_limit: Gas,
+}
+
+impl Default for MsgQueue {
+    fn default() -> Self {
+        Self::new(vec![], Gas::MAX)
+    }
+}
+
 impl MsgQueue {
+    pub fn new(priority_lanes: Vec<PriorityLane>, batch_gas_limit: Gas) -> Self {
+        let lanes = priority_lanes
+            .into_iter()
+            .map(|PriorityLane { msgs, gas_budget }| Lane::new(msgs, gas_budget))
+            .chain(std::iter::once(Lane::new(vec![], batch_gas_limit)))
+            .collect();
+
+        Self {
+            lanes,
+            batch_gas_limit,
+        }
+    }
+
     pub fn push(&mut self, msg: Any, gas_cost: Gas) -> Result<(), Error> {
         let message_type = msg.type_url.clone();
+        let queue_gas_cost = self
+            .gas_cost()
+            .checked_add(gas_cost)
+            .ok_or(Error::GasCostOverflow)?;
 
-        self.msgs.push(msg);
-        self.gas_cost = self
+        let lane = self.lane_mut(&msg);
+        lane.gas_cost = lane
             .gas_cost
             .checked_add(gas_cost)
             .ok_or(Error::GasCostOverflow)?;
+        lane.msgs.push_back((msg, gas_cost));
 
         info!(
             message_type,
-            queue_size = self.msgs.len(),
-            queue_gas_cost = self.gas_cost,
+            queue_size = self.len(),
+            queue_gas_cost,
             "pushed a new message into the queue"
         );
 
         Ok(())
     }
 
-    pub fn pop_all(&mut self) -> Vec<Any> {
-        let msgs = self.msgs.clone();
-        self.msgs.clear();
-        self.gas_cost = 0;
+    /// Returns true if adding the message would exceed the gas budget of its lane or the batch gas limit,
+    /// so the queue should be broadcast first
+    pub fn exceeds_gas_limit(&self, msg: &Any, gas_cost: Gas) -> bool {
+        let lane = self.lane(msg);
+
+        gas_cost.saturating_add(lane.gas_cost) >= lane.gas_budget
+            || gas_cost.saturating_add(self.gas_cost()) >= self.batch_gas_limit
+    }
+
+    /// Pops the next batch of messages. Lanes are drained in priority order, each up to its gas budget,
+    /// until the batch gas limit is reached. A batch contains at least one message if the queue is not empty.
+    pub fn pop_batch(&mut self) -> Vec<Any> {
+        let mut batch = vec![];
+        let mut batch_gas_cost: Gas = 0;
+        let priority_gas_limit = self.priority_gas_limit();
+        let catch_all_lane = self.lanes.len().saturating_sub(1);
+
+        for (index, lane) in self.lanes.iter_mut().enumerate() {
+            let mut lane_gas_cost: Gas = 0;
+            let gas_limit = if index == catch_all_lane {
+                self.batch_gas_limit
+            } else {
+                priority_gas_limit
+            };
+
+            while let Some((_, gas_cost)) = lane.msgs.front() {
+                let gas_cost = *gas_cost;
+                let fits = lane_gas_cost.saturating_add(gas_cost) <= lane.gas_budget
+                    && batch_gas_cost.saturating_add(gas_cost) <= gas_limit;
+
+                if !fits && !batch.is_empty() {
+                    break;
+                }
+
+                let (msg, _) = lane.msgs.pop_front().expect("lane must not be empty");
+                lane.gas_cost = lane.gas_cost.saturating_sub(gas_cost);
+                lane_gas_cost = lane_gas_cost.saturating_add(gas_cost);
+                batch_gas_cost = batch_gas_cost.saturating_add(gas_cost);
+                batch.push(msg);
+            }
+        }
+
+        batch
+    }
+
+    /// The part of the batch gas limit that the priority lanes can use together
+    fn priority_gas_limit(&self) -> Gas {
+        let catch_all_lane = self.lanes.last().expect("catch-all lane must exist");
+        if catch_all_lane.msgs.is_empty() {
+            return self.batch_gas_limit;
+        }
+
+        let reserved = self
+            .batch_gas_limit
+            .checked_div(100)
+            .unwrap_or_default()
+            .saturating_mul(CATCH_ALL_LANE_RESERVED_GAS_PERCENT);
 
-        msgs
+        self.batch_gas_limit.saturating_sub(reserved)
     }
 
     pub fn gas_cost(&self) -> Gas {
-        self.gas_cost
+        self.lanes
+            .iter()
+            .fold(0, |acc: Gas, lane| acc.saturating_add(lane.gas_cost))
     }
 
     pub fn len(&self) -> usize {
-        self.msgs.len()
+        self.lanes.iter().map(|lane| lane.msgs.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lanes.iter().all(|lane| lane.msgs.is_empty())
+    }
+
+    fn lane(&self, msg: &Any) -> &Lane {
+        &self.lanes[self.lane_index(msg)]
+    }
+
+    fn lane_mut(&mut self, msg: &Any) -> &mut Lane {
+        let index = self.lane_index(msg);
+
+        &mut self.lanes[index]
+    }
+
+    /// Messages broadcast through authz are sorted into lanes by the message they execute
+    fn lane_index(&self, msg: &Any) -> usize {
+        let msg = authz::executed_msg(msg).unwrap_or_else(|| msg.clone());
+        let msg_type = msg_type(&msg);
+
+        self.lanes
+            .iter()
+            .position(|lane| lane.accepts(&msg.type_url) || lane.accepts(&msg_type))
+            .unwrap_or(self.lanes.len().saturating_sub(1))
     }
 }
 
+/// The name of the contract execute message (e.g. "vote") for wasm messages, an empty string otherwise
+fn msg_type(msg: &Any) -> String {
+    MsgExecuteContract::from_any(msg)
+        .ok()
+        .and_then(|msg| serde_json::from_slice::<Map<String, Value>>(&msg.msg).ok())
+        .and_then(|msg| msg.keys().next().cloned())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod test {
     use cosmrs::bank::MsgSend;
+    use cosmrs::cosmwasm::MsgExecuteContract;
     use cosmrs::tx::Msg;
     use cosmrs::{AccountId, Any};
+    use serde_json::json;
 
     use super::MsgQueue;
+    use crate::broadcaster::PriorityLane;
+    use crate::queue::authz::exec;
+    use crate::types::TMAddress;
+    use crate::PREFIX;
 
     #[test]
     fn msg_queue_push_should_work() {
@@ -68,19 +225,177 @@ mod test {
         }
 
         assert_eq!(queue.gas_cost(), 10);
-        assert_eq!(queue.msgs.len(), 4);
+        assert_eq!(queue.len(), 4);
     }
 
     #[test]
-    fn msg_queue_pop_all_should_work() {
+    fn msg_queue_pop_batch_should_work() {
         let mut queue = MsgQueue::default();
         for gas_cost in 1..5 {
             queue.push(dummy_msg(), gas_cost).unwrap();
         }
 
-        assert_eq!(queue.pop_all().len(), 4);
+        assert_eq!(queue.pop_batch().len(), 4);
         assert_eq!(queue.gas_cost(), 0);
-        assert_eq!(queue.msgs.len(), 0);
+        assert_eq!(queue.len(), 0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn msg_queue_pop_batch_should_order_msgs_by_priority() {
+        let mut queue = MsgQueue::new(priority_lanes(100, 100), 1000);
+        queue.push(dummy_msg(), 1).unwrap();
+        queue.push(wasm_msg("vote"), 1).unwrap();
+        queue.push(wasm_msg("submit_signature"), 1).unwrap();
+        queue.push(dummy_msg(), 1).unwrap();
+        queue.push(wasm_msg("submit_signature"), 1).unwrap();
+
+        assert_eq!(
+            queue.pop_batch(),
+            vec![
+                wasm_msg("submit_signature"),
+                wasm_msg("submit_signature"),
+                wasm_msg("vote"),
+                dummy_msg(),
+                dummy_msg(),
+            ]
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn msg_queue_pop_batch_should_respect_lane_gas_budgets() {
+        let mut queue = MsgQueue::new(priority_lanes(20, 20), 1000);
+        for _ in 0..3 {
+            queue.push(wasm_msg("submit_signature"), 10).unwrap();
+            queue.push(wasm_msg("vote"), 10).unwrap();
+            queue.push(dummy_msg(), 10).unwrap();
+        }
+
+        assert_eq!(
+            queue.pop_batch(),
+            vec![
+                wasm_msg("submit_signature"),
+                wasm_msg("submit_signature"),
+                wasm_msg("vote"),
+                wasm_msg("vote"),
+                dummy_msg(),
+                dummy_msg(),
+                dummy_msg(),
+            ]
+        );
+        assert_eq!(
+            queue.pop_batch(),
+            vec![wasm_msg("submit_signature"), wasm_msg("vote")]
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn msg_queue_pop_batch_should_respect_batch_gas_limit() {
+        let mut queue = MsgQueue::new(priority_lanes(100, 100), 30);
+        for _ in 0..2 {
+            queue.push(dummy_msg(), 10).unwrap();
+            queue.push(wasm_msg("vote"), 10).unwrap();
+            queue.push(wasm_msg("submit_signature"), 10).unwrap();
+        }
+
+        assert_eq!(
+            queue.pop_batch(),
+            vec![
+                wasm_msg("submit_signature"),
+                wasm_msg("submit_signature"),
+                wasm_msg("vote"),
+            ]
+        );
+        assert_eq!(
+            queue.pop_batch(),
+            vec![wasm_msg("vote"), dummy_msg(), dummy_msg()]
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn msg_queue_pop_batch_should_reserve_gas_for_catch_all_lane() {
+        let mut queue = MsgQueue::new(priority_lanes(50, 50), 100);
+        for _ in 0..5 {
+            queue.push(wasm_msg("submit_signature"), 10).unwrap();
+            queue.push(wasm_msg("vote"), 10).unwrap();
+        }
+        queue.push(dummy_msg(), 10).unwrap();
+
+        let batch = queue.pop_batch();
+        assert_eq!(batch.len(), 10);
+        assert_eq!(batch.last(), Some(&dummy_msg()));
+        assert_eq!(queue.pop_batch(), vec![wasm_msg("vote")]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn msg_queue_pop_batch_should_return_msg_exceeding_gas_budget() {
+        let mut queue = MsgQueue::new(priority_lanes(10, 10), 10);
+        queue.push(wasm_msg("vote"), 20).unwrap();
+        queue.push(wasm_msg("vote"), 20).unwrap();
+
+        assert_eq!(queue.pop_batch(), vec![wasm_msg("vote")]);
+        assert_eq!(queue.pop_batch(), vec![wasm_msg("vote")]);
+        assert!(queue.pop_batch().is_empty());
+    }
+
+    #[test]
+    fn msg_queue_exceeds_gas_limit_should_check_lane_and_batch_limits() {
+        let mut queue = MsgQueue::new(priority_lanes(20, 30), 50);
+        queue.push(wasm_msg("submit_signature"), 10).unwrap();
+        queue.push(wasm_msg("vote"), 10).unwrap();
+
+        assert!(queue.exceeds_gas_limit(&wasm_msg("submit_signature"), 10));
+        assert!(!queue.exceeds_gas_limit(&wasm_msg("vote"), 10));
+        assert!(!queue.exceeds_gas_limit(&dummy_msg(), 29));
+        assert!(queue.exceeds_gas_limit(&dummy_msg(), 30));
+    }
+
+    #[test]
+    fn msg_queue_should_sort_authz_msgs_by_executed_msg() {
+        let grantee = TMAddress::random(PREFIX);
+        let mut queue = MsgQueue::new(priority_lanes(100, 100), 1000);
+        queue.push(exec(&grantee, dummy_msg()), 1).unwrap();
+        queue.push(exec(&grantee, wasm_msg("vote")), 1).unwrap();
+        queue
+            .push(exec(&grantee, wasm_msg("submit_signature")), 1)
+            .unwrap();
+
+        assert_eq!(
+            queue.pop_batch(),
+            vec![
+                exec(&grantee, wasm_msg("submit_signature")),
+                exec(&grantee, wasm_msg("vote")),
+                exec(&grantee, dummy_msg()),
+            ]
+        );
+    }
+
+    fn priority_lanes(signature_gas_budget: u64, vote_gas_budget: u64) -> Vec<PriorityLane> {
+        vec![
+            PriorityLane {
+                msgs: vec!["submit_signature".to_string()],
+                gas_budget: signature_gas_budget,
+            },
+            PriorityLane {
+                msgs: vec!["vote".to_string()],
+                gas_budget: vote_gas_budget,
+            },
+        ]
+    }
+
+    fn wasm_msg(msg_type: &str) -> Any {
+        MsgExecuteContract {
+            sender: AccountId::new("axelar", &[1, 2, 3]).unwrap(),
+            contract: AccountId::new("axelar", &[4, 5, 6]).unwrap(),
+            msg: serde_json::to_vec(&json!({ msg_type: {} })).unwrap(),
+            funds: vec![],
+        }
+        .to_any()
+        .unwrap()
     }
 
     fn dummy_msg() -> Any {
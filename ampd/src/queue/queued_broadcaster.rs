@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use cosmrs::tx::MessageExt;
 use cosmrs::{Any, Gas};
@@ -7,12 +9,12 @@ use thiserror::Error;
 use tokio::select;
 use tokio::sync::{mpsc, oneshot};
 use tokio::time::Interval;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
 use super::msg_queue::MsgQueue;
 use super::proto;
 use crate::broadcaster::confirm_tx::{TxResponse, TxStatus};
-use crate::broadcaster::Broadcaster;
+use crate::broadcaster::{self, Broadcaster, PriorityLane};
 
 type Result<T = ()> = error_stack::Result<T, Error>;
 type MsgAndResponseCallback = (Any, oneshot::Sender<Result>);
@@ -71,6 +73,14 @@ where
     )>,
     channel_capacity: usize,
     broadcast_interval: Interval,
+    tx_resubmission_max_retries: u32,
+    pending_txs: HashMap<String, PendingTx>,
+}
+
+/// Messages of a broadcast tx that is waiting for confirmation, kept around so the tx can be resubmitted
+struct PendingTx {
+    msgs: Vec<Any>,
+    resubmissions: u32,
 }
 
 impl<T> QueuedBroadcaster<T>
@@ -85,14 +95,27 @@ where
     ) -> Self {
         Self {
             broadcaster,
-            queue: MsgQueue::default(),
+            queue: MsgQueue::new(vec![], batch_gas_limit),
             batch_gas_limit,
             channel: None,
             broadcast_interval,
             channel_capacity: capacity,
+            tx_resubmission_max_retries: 0,
+            pending_txs: HashMap::new(),
         }
     }
 
+    /// Number of times a tx is resubmitted if it cannot be found on chain after broadcasting
+    pub fn tx_resubmission_max_retries(mut self, tx_resubmission_max_retries: u32) -> Self {
+        self.tx_resubmission_max_retries = tx_resubmission_max_retries;
+        self
+    }
+
+    pub fn priority_lanes(mut self, priority_lanes: Vec<PriorityLane>) -> Self {
+        self.queue = MsgQueue::new(priority_lanes, self.batch_gas_limit);
+        self
+    }
+
     pub async fn run(
         mut self,
         tx_hash_sender: mpsc::Sender<String>,
@@ -113,7 +136,7 @@ where
                     self.broadcast_all(&tx_hash_sender).await?;
                     self.broadcast_interval.reset();
                 },
-                Some(tx_res) = tx_response_receiver.recv() => handle_tx_response(tx_res).await?,
+                Some(tx_res) = tx_response_receiver.recv() => self.handle_tx_response(tx_res, &tx_hash_sender).await?,
             }
         }
 
@@ -130,31 +153,90 @@ where
     }
 
     async fn broadcast_all(&mut self, tx_hash_sender: &mpsc::Sender<String>) -> Result {
-        let msgs = self.queue.pop_all();
+        while !self.queue.is_empty() {
+            self.broadcast_batch(tx_hash_sender).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn broadcast_batch(&mut self, tx_hash_sender: &mpsc::Sender<String>) -> Result {
+        let msgs = self.queue.pop_batch();
 
         match msgs.len() {
             0 => Ok(()),
             n => {
                 info!(message_count = n, "ready to broadcast messages");
 
-                let batch_req = proto::axelar::auxiliary::v1beta1::BatchRequest {
-                    sender: self.broadcaster.sender_address().as_ref().to_bytes(),
-                    messages: msgs,
+                self.broadcast_msgs(msgs, 0, tx_hash_sender).await
+            }
+        }
+    }
+
+    async fn broadcast_msgs(
+        &mut self,
+        msgs: Vec<Any>,
+        resubmissions: u32,
+        tx_hash_sender: &mpsc::Sender<String>,
+    ) -> Result {
+        let batch_req = proto::axelar::auxiliary::v1beta1::BatchRequest {
+            sender: self.broadcaster.sender_address().as_ref().to_bytes(),
+            messages: msgs.clone(),
+        }
+        .to_any()
+        .expect("failed to serialize proto message for batch request");
+
+        let tx_hash = match self.broadcaster.broadcast(vec![batch_req]).await {
+            Ok(response) => response.txhash,
+            // the node rejected the tx even after recovering from sequence mismatches, so give up on these messages
+            Err(err) if matches!(err.current_context(), broadcaster::Error::TxNotAccepted) => {
+                dead_letter(&msgs, "tx not accepted by the node");
+                return Ok(());
+            }
+            Err(err) => return Err(err).change_context(Error::Broadcast),
+        };
+
+        self.pending_txs.insert(
+            tx_hash.clone(),
+            PendingTx {
+                msgs,
+                resubmissions,
+            },
+        );
+        tx_hash_sender
+            .send(tx_hash)
+            .await
+            .change_context(Error::TxConfirmation)?;
+
+        Ok(())
+    }
+
+    async fn handle_tx_response(
+        &mut self,
+        tx_res: TxResponse,
+        tx_hash_sender: &mpsc::Sender<String>,
+    ) -> Result {
+        let pending_tx = self.pending_txs.remove(&tx_res.response.txhash);
+
+        match pending_tx {
+            Some(PendingTx {
+                msgs,
+                resubmissions,
+            }) if tx_res.status == TxStatus::NotFound => {
+                if resubmissions >= self.tx_resubmission_max_retries {
+                    dead_letter(&msgs, "tx not found on chain");
+                    return Ok(());
                 }
-                .to_any()
-                .expect("failed to serialize proto message for batch request");
 
-                let tx_hash = self
-                    .broadcaster
-                    .broadcast(vec![batch_req])
-                    .await
-                    .change_context(Error::Broadcast)?
-                    .txhash;
-                tx_hash_sender
-                    .send(tx_hash)
+                warn!(
+                    tx_hash = tx_res.response.txhash,
+                    resubmissions, "tx not found on chain, resubmitting its messages"
+                );
+                self.broadcast_msgs(msgs, resubmissions.saturating_add(1), tx_hash_sender)
                     .await
-                    .change_context(Error::TxConfirmation)?;
-
+            }
+            _ => {
+                log_tx_response(tx_res);
                 Ok(())
             }
         }
@@ -171,13 +253,13 @@ where
                     .send(Ok(()))
                     .map_err(|_| Report::new(Error::Client))?;
 
-                if fee.gas_limit.saturating_add(self.queue.gas_cost()) >= self.batch_gas_limit {
+                if self.queue.exceeds_gas_limit(&msg, fee.gas_limit) {
                     warn!(
                         queue_size = self.queue.len(),
                         queue_gas_cost = self.queue.gas_cost(),
-                        "exceeded batch gas limit. gas limit can be adjusted in ampd config"
+                        "exceeded batch gas limit. gas limits can be adjusted in ampd config"
                     );
-                    self.broadcast_all(tx_hash_sender).await?;
+                    self.broadcast_batch(tx_hash_sender).await?;
                     self.broadcast_interval.reset();
                 }
 
@@ -203,17 +285,37 @@ where
         info!("exiting broadcaster");
 
         self.broadcast_all(&tx_hash_sender).await?;
+        // keep the tx hash sender alive while txs are pending, so txs that are not found can still be resubmitted
+        while !self.pending_txs.is_empty() {
+            match response_receiver.recv().await {
+                Some(tx_res) => self.handle_tx_response(tx_res, &tx_hash_sender).await?,
+                None => break,
+            }
+        }
         // drop the tx hash sender so the receiver of that channel knows there won't be any more messages
         drop(tx_hash_sender);
         while let Some(tx_res) = response_receiver.recv().await {
-            handle_tx_response(tx_res).await?;
+            log_tx_response(tx_res);
         }
 
         Ok(())
     }
 }
 
-async fn handle_tx_response(tx_res: TxResponse) -> Result {
+/// Logs messages the broadcaster gave up on, so they can be inspected and submitted manually
+fn dead_letter(msgs: &[Any], reason: &str) {
+    msgs.iter().for_each(|msg| {
+        error!(
+            target: "dead_letter",
+            reason,
+            type_url = msg.type_url,
+            value = hex::encode(&msg.value),
+            "dropping message"
+        );
+    });
+}
+
+fn log_tx_response(tx_res: TxResponse) {
     let tx_hash = tx_res.response.txhash;
 
     match tx_res.status {
@@ -237,9 +339,10 @@ async fn handle_tx_response(tx_res: TxResponse) -> Result {
                 "tx failed"
             );
         }
+        TxStatus::NotFound => {
+            warn!(tx_hash, "tx not found on chain");
+        }
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -256,6 +359,7 @@ mod test {
     use tokio_stream::wrappers::ReceiverStream;
 
     use super::{Error, QueuedBroadcaster};
+    use crate::broadcaster::confirm_tx::{self, TxStatus};
     use crate::broadcaster::{self, MockBroadcaster};
     use crate::queue::proto;
     use crate::queue::queued_broadcaster::BroadcasterClient;
@@ -498,6 +602,108 @@ mod test {
         assert!(handle.await.unwrap().is_ok());
     }
 
+    #[test]
+    async fn should_resubmit_txs_that_are_not_found_until_max_retries() {
+        let mut broadcaster = MockBroadcaster::new();
+        broadcaster.expect_estimate_fee().once().returning(|_| {
+            Ok(Fee {
+                gas_limit: 10,
+                amount: vec![],
+                granter: None,
+                payer: None,
+            })
+        });
+        broadcaster
+            .expect_sender_address()
+            .times(2)
+            .returning(|| AccountId::new(PREFIX, &[1, 2, 3]).unwrap().into());
+        let mut broadcast_count = 0;
+        broadcaster
+            .expect_broadcast()
+            .times(2)
+            .returning(move |msgs| {
+                broadcast_count += 1;
+
+                let msg = proto::axelar::auxiliary::v1beta1::BatchRequest::from_any(
+                    msgs.first().unwrap(),
+                )
+                .unwrap();
+                assert_eq!(msg.messages, vec![dummy_msg()]);
+
+                Ok(TxResponse {
+                    txhash: format!("tx_hash_{}", broadcast_count),
+                    ..Default::default()
+                })
+            });
+
+        let (tx_confirmer_sender, mut tx_confirmer_receiver) = mpsc::channel(1000);
+        let (tx_res_sender, tx_res_receiver) = mpsc::channel(1000);
+        let mut queued_broadcaster =
+            QueuedBroadcaster::new(broadcaster, 100, 10, interval(Duration::from_secs(5)))
+                .tx_resubmission_max_retries(1);
+        let client = queued_broadcaster.client();
+        let handle = tokio::spawn(queued_broadcaster.run(tx_confirmer_sender, tx_res_receiver));
+
+        client.broadcast(dummy_msg()).await.unwrap();
+        // the queue is broadcast on shutdown, but pending txs are still tracked until they are confirmed
+        drop(client);
+
+        for expected_tx_hash in ["tx_hash_1", "tx_hash_2"] {
+            let tx_hash = tx_confirmer_receiver.recv().await.unwrap();
+            assert_eq!(tx_hash, expected_tx_hash);
+
+            tx_res_sender
+                .send(confirm_tx::TxResponse {
+                    status: TxStatus::NotFound,
+                    response: TxResponse {
+                        txhash: tx_hash,
+                        ..Default::default()
+                    },
+                })
+                .await
+                .unwrap();
+        }
+
+        // the messages are dead-lettered after the last resubmission, so no more txs are sent for confirmation
+        assert!(tx_confirmer_receiver.recv().await.is_none());
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[test]
+    async fn should_dead_letter_msgs_when_tx_is_not_accepted() {
+        let mut broadcaster = MockBroadcaster::new();
+        broadcaster.expect_estimate_fee().once().returning(|_| {
+            Ok(Fee {
+                gas_limit: 10,
+                amount: vec![],
+                granter: None,
+                payer: None,
+            })
+        });
+        broadcaster
+            .expect_sender_address()
+            .once()
+            .returning(|| AccountId::new(PREFIX, &[1, 2, 3]).unwrap().into());
+        broadcaster
+            .expect_broadcast()
+            .once()
+            .returning(|_| Err(Report::new(broadcaster::Error::TxNotAccepted)));
+
+        let (tx_confirmer_sender, tx_confirmer_receiver) = mpsc::channel(1000);
+        let (tx_res_sender, tx_res_receiver) = mpsc::channel(1000);
+        let mut queued_broadcaster =
+            QueuedBroadcaster::new(broadcaster, 100, 10, interval(Duration::from_secs(5)));
+        let client = queued_broadcaster.client();
+        let handle = tokio::spawn(queued_broadcaster.run(tx_confirmer_sender, tx_res_receiver));
+
+        client.broadcast(dummy_msg()).await.unwrap();
+        drop(client);
+        drop(tx_res_sender);
+
+        assert!(handle.await.unwrap().is_ok());
+        assert_eq!(ReceiverStream::new(tx_confirmer_receiver).count().await, 0);
+    }
+
     fn dummy_msg() -> Any {
         MsgSend {
             from_address: AccountId::new("", &[1, 2, 3]).unwrap(),
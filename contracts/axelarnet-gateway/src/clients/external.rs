@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, CosmosMsg, Empty, HexBinary, QuerierWrapper};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Empty, HexBinary, QuerierWrapper};
 use router_api::{Address, CrossChainId};
 
 /// `AxelarExecutableMsg` is a struct containing the args used by the axelarnet gateway to execute a destination contract on Axelar.
@@ -11,12 +11,31 @@ pub struct AxelarExecutableMsg {
     pub payload: HexBinary,
 }
 
+/// `AxelarAckMsg` is sent by the axelarnet gateway to contracts that called `CallContractWithAck`,
+/// once the outcome of their message is known.
+/// Such contracts need to expose a `ExecuteMsg::Ack(AxelarAckMsg)` variant that only the gateway is allowed to call.
+#[cw_serde]
+pub struct AxelarAckMsg {
+    /// ID of the message sent with `CallContractWithAck`
+    pub cc_id: CrossChainId,
+    pub result: AckResult,
+}
+
+#[cw_serde]
+pub enum AckResult {
+    Success(HexBinary),
+    Failure(HexBinary),
+    Timeout,
+}
+
 /// By convention, amplifier-compatible contracts must expose this `Execute` variant.
 /// Due to identical json serialization, we can imitate it here so the gateway can call it.
 #[cw_serde]
 enum ExecuteMsg {
     /// Execute the message at the destination contract with the corresponding payload.
     Execute(AxelarExecutableMsg),
+    /// Call back the source contract with the acknowledgement of its message.
+    Ack(AxelarAckMsg),
 }
 
 pub struct Client<'a, T = Empty> {
@@ -33,6 +52,15 @@ impl<'a, T> Client<'a, T> {
     pub fn execute(&self, msg: AxelarExecutableMsg) -> CosmosMsg<T> {
         self.client.execute(&ExecuteMsg::Execute(msg))
     }
+
+    pub fn ack(&self, msg: AxelarAckMsg) -> CosmosMsg<T> {
+        self.client.execute(&ExecuteMsg::Ack(msg))
+    }
+
+    pub fn execute_with_token(&self, msg: AxelarExecutableMsg, token: Coin) -> CosmosMsg<T> {
+        self.client
+            .execute_with_funds(&ExecuteMsg::Execute(msg), token)
+    }
 }
 
 #[cfg(test)]
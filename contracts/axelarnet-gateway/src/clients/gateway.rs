@@ -43,7 +43,7 @@ impl<'a> Client<'a> {
         coin: Coin,
     ) -> CosmosMsg {
         self.client.execute_with_funds(
-            &ExecuteMsg::CallContract {
+            &ExecuteMsg::CallContractWithToken {
                 destination_chain,
                 destination_address,
                 payload,
@@ -123,6 +123,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn call_contract_with_token() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let destination_chain: ChainName = "destination-chain".parse().unwrap();
+        let destination_address: Address = "destination-address".parse().unwrap();
+        let payload = HexBinary::from(vec![1, 2, 3]);
+        let coin = Coin::new(100u128, "uaxl");
+
+        let msg = client.call_contract_with_token(
+            destination_chain.clone(),
+            destination_address.clone(),
+            payload.clone(),
+            coin.clone(),
+        );
+
+        assert_eq!(
+            msg,
+            WasmMsg::Execute {
+                contract_addr: addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::CallContractWithToken {
+                    destination_chain,
+                    destination_address,
+                    payload,
+                })
+                .unwrap(),
+                funds: vec![coin],
+            }
+            .into()
+        );
+    }
+
     #[test]
     fn execute_message() {
         let (querier, _, addr) = setup();
@@ -4,7 +4,7 @@ use axelar_wasm_std::{address, FnExt, IntoContractError};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, Storage,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, Storage,
 };
 use error_stack::{Report, ResultExt};
 
@@ -13,9 +13,13 @@ use crate::state::{self, Config};
 
 mod execute;
 mod query;
+mod reply;
 
 pub use execute::Error as ExecuteError;
 
+pub const EXECUTE_REPLY_ID: u64 = 1;
+pub const ACK_REPLY_ID: u64 = 2;
+
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -23,6 +27,12 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub enum Error {
     #[error("failed to make a cross-chain contract call")]
     CallContract,
+    #[error("failed to make a cross-chain contract call with token")]
+    CallContractWithToken,
+    #[error("failed to make a cross-chain contract call with acknowledgement")]
+    CallContractWithAck,
+    #[error("failed to time out acknowledgements")]
+    TimeoutAcks,
     #[error("failed to route messages on the gateway")]
     RouteMessages,
     #[error("failed to execute a cross-chain execution payload")]
@@ -31,6 +41,14 @@ pub enum Error {
     QueryRoutableMessage,
     #[error("failed to query executable messages")]
     QueryExecutableMessages,
+    #[error("failed to query pending acknowledgement")]
+    QueryPendingAck,
+    #[error("failed to query failed executions")]
+    QueryFailedExecutions,
+    #[error("failed to handle the failed execution of a message")]
+    ExecutionFailed,
+    #[error("failed to handle the failed delivery of an acknowledgement")]
+    AckDeliveryFailed,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -68,7 +86,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<nexus::execute::Message>, ContractError> {
@@ -88,16 +106,70 @@ pub fn execute(
             },
         )
         .change_context(Error::CallContract),
+        ExecuteMsg::CallContractWithToken {
+            destination_chain,
+            destination_address,
+            payload,
+        } => execute::call_contract_with_token(
+            deps.storage,
+            deps.querier,
+            info,
+            execute::CallContractData {
+                destination_chain,
+                destination_address,
+                payload,
+            },
+        )
+        .change_context(Error::CallContractWithToken),
+        ExecuteMsg::CallContractWithAck {
+            destination_chain,
+            destination_address,
+            payload,
+            timeout_blocks,
+        } => execute::call_contract_with_ack(
+            deps.storage,
+            deps.querier,
+            info,
+            env.block.height,
+            execute::CallContractData {
+                destination_chain,
+                destination_address,
+                payload,
+            },
+            timeout_blocks,
+        )
+        .change_context(Error::CallContractWithAck),
+        ExecuteMsg::TimeoutAcks(cc_ids) => {
+            execute::timeout_acks(deps, env.block.height, cc_ids).change_context(Error::TimeoutAcks)
+        }
         ExecuteMsg::RouteMessages(msgs) => {
             execute::route_messages(deps.storage, deps.querier, info.sender, msgs)
                 .change_context(Error::RouteMessages)
         }
         ExecuteMsg::Execute { cc_id, payload } => {
-            execute::execute(deps, cc_id, payload).change_context(Error::Execute)
-        }
-        ExecuteMsg::RouteMessagesFromNexus(msgs) => {
-            Ok(execute::route_messages_from_nexus(deps.storage, msgs)?)
+            execute::execute(deps, env, cc_id, payload).change_context(Error::Execute)
         }
+        ExecuteMsg::RouteMessagesFromNexus(msgs) => Ok(execute::route_messages_from_nexus(
+            deps.storage,
+            info.funds,
+            msgs,
+        )?),
+    }?
+    .then(Ok)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    match reply.id {
+        EXECUTE_REPLY_ID => reply::execution_failed(deps.storage, reply.result)
+            .change_context(Error::ExecutionFailed),
+        ACK_REPLY_ID => reply::ack_delivery_failed(reply.payload, reply.result)
+            .change_context(Error::AckDeliveryFailed),
+        _ => unreachable!("unknown reply ID"),
     }?
     .then(Ok)
 }
@@ -113,6 +185,13 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             &query::executable_messages(deps.storage, cc_ids)
                 .change_context(Error::QueryExecutableMessages)?,
         ),
+        QueryMsg::PendingAck { cc_id } => to_json_binary(
+            &query::pending_ack(deps.storage, cc_id).change_context(Error::QueryPendingAck)?,
+        ),
+        QueryMsg::FailedExecutions { start_after, limit } => to_json_binary(
+            &query::failed_executions(deps.storage, start_after, limit)
+                .change_context(Error::QueryFailedExecutions)?,
+        ),
         QueryMsg::ChainName => to_json_binary(&query::chain_name(deps.storage)),
     }?
     .then(Ok)
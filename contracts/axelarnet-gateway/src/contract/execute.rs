@@ -2,10 +2,11 @@ use std::str::FromStr;
 
 use axelar_core_std::nexus;
 use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
-use axelar_wasm_std::{address, FnExt, IntoContractError};
+use axelar_wasm_std::{address, nonempty, FnExt, IntoContractError};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    Addr, CosmosMsg, DepsMut, Event, HexBinary, MessageInfo, QuerierWrapper, Response, Storage,
+    from_json, to_json_binary, Addr, Api, Coin, Coins, CosmosMsg, DepsMut, Env, Event, HexBinary,
+    MessageInfo, QuerierWrapper, Response, Storage, SubMsg,
 };
 use error_stack::{bail, ensure, report, ResultExt};
 use itertools::Itertools;
@@ -13,9 +14,11 @@ use router_api::client::Router;
 use router_api::{Address, ChainName, CrossChainId, Message};
 use sha3::{Digest, Keccak256};
 
-use crate::clients::external;
+use crate::clients::external::{self, AckResult, AxelarAckMsg};
+use crate::contract::{ACK_REPLY_ID, EXECUTE_REPLY_ID};
 use crate::events::AxelarnetGatewayEvent;
-use crate::state::Config;
+use crate::msg::Acknowledgement;
+use crate::state::{Config, ExecutionContext, PendingAck};
 use crate::{state, AxelarExecutableMsg};
 
 #[derive(thiserror::Error, Debug, IntoContractError)]
@@ -55,6 +58,30 @@ pub enum Error {
     InvalidRoutingDestination,
     #[error("failed to convert the nexus message for the router")]
     InvalidNexusMessageForRouter,
+    #[error("tokens can only be sent to chains registered with core, {0} is not registered")]
+    TokenTransferNotSupported(ChainName),
+    #[error("failed to save the token sent with message {0}")]
+    SaveToken(CrossChainId),
+    #[error("failed to release the token received with message {0}")]
+    ReleaseToken(CrossChainId),
+    #[error("funds sent by the nexus module don't match the tokens of the messages")]
+    FundsMismatch,
+    #[error("failed to save the pending acknowledgement for message {0}")]
+    SavePendingAck(CrossChainId),
+    #[error("failed to load the pending acknowledgement for message {0}")]
+    LoadPendingAck(CrossChainId),
+    #[error("no acknowledgement is pending for message {0}")]
+    PendingAckNotFound(CrossChainId),
+    #[error("acknowledgement for message {0} has not timed out yet")]
+    AckNotTimedOut(CrossChainId),
+    #[error("payload of message {0} is not a valid acknowledgement")]
+    InvalidAck(CrossChainId),
+    #[error("acknowledgement for message {0} was not sent by its destination")]
+    AckFromUnexpectedSource(CrossChainId),
+    #[error("invalid address {0} to call back with an acknowledgement")]
+    InvalidAckCallbackAddress(String),
+    #[error("failed to save the execution context of message {0}")]
+    SaveExecutionContext(CrossChainId),
 }
 
 #[cw_serde]
@@ -92,27 +119,142 @@ pub fn call_contract(
     info: MessageInfo,
     call_contract: CallContractData,
 ) -> Result<Response<nexus::execute::Message>> {
-    let Config { chain_name, .. } = state::load_config(storage);
+    let client: nexus::Client = client::CosmosClient::new(querier).into();
+
+    let msg = save_routable_msg(storage, &client, &info.sender, &call_contract)?;
+
+    let event = AxelarnetGatewayEvent::ContractCalled {
+        msg: msg.clone(),
+        payload: call_contract.payload,
+    };
+
+    route_messages(storage, querier, info.sender, vec![msg]).map(|res| res.add_event(event))
+}
+
+pub fn call_contract_with_token(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    info: MessageInfo,
+    call_contract: CallContractData,
+) -> Result<Response<nexus::execute::Message>> {
+    let token = match info.funds.as_slice() {
+        [token] if !token.amount.is_zero() => token.clone(),
+        _ => bail!(Error::InvalidToken),
+    };
 
     let client: nexus::Client = client::CosmosClient::new(querier).into();
 
-    let id = unique_cross_chain_id(&client, chain_name.clone())?;
-    let source_address = Address::from_str(info.sender.as_str())
-        .change_context(Error::InvalidSourceAddress(info.sender.clone()))?;
-    let msg = call_contract.to_message(id, source_address);
+    // the router cannot carry tokens, so they can only be sent through the nexus module
+    ensure!(
+        client
+            .is_chain_registered(&call_contract.destination_chain)
+            .change_context(Error::Nexus)?,
+        Error::TokenTransferNotSupported(call_contract.destination_chain.clone())
+    );
 
-    state::save_unique_routable_msg(storage, &msg.cc_id, &msg)
+    let msg = save_routable_msg(storage, &client, &info.sender, &call_contract)?;
+
+    state::save_sent_token(storage, &msg.cc_id, &token)
         .inspect_err(|err| panic_if_already_exists(err, &msg.cc_id))
-        .change_context(Error::SaveRoutableMessage)?;
+        .change_context(Error::SaveToken(msg.cc_id.clone()))?;
 
-    let event = AxelarnetGatewayEvent::ContractCalled {
+    let event = AxelarnetGatewayEvent::ContractCalledWithToken {
         msg: msg.clone(),
         payload: call_contract.payload,
+        token,
     };
 
     route_messages(storage, querier, info.sender, vec![msg]).map(|res| res.add_event(event))
 }
 
+pub fn call_contract_with_ack(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    info: MessageInfo,
+    block_height: u64,
+    call_contract: CallContractData,
+    timeout_blocks: nonempty::Uint64,
+) -> Result<Response<nexus::execute::Message>> {
+    let client: nexus::Client = client::CosmosClient::new(querier).into();
+
+    let msg = save_routable_msg(storage, &client, &info.sender, &call_contract)?;
+    let timeout_height = block_height.saturating_add(timeout_blocks.into());
+
+    state::save_pending_ack(
+        storage,
+        &msg.cc_id,
+        &PendingAck {
+            msg: msg.clone(),
+            timeout_height,
+        },
+    )
+    .inspect_err(|err| panic_if_already_exists(err, &msg.cc_id))
+    .change_context(Error::SavePendingAck(msg.cc_id.clone()))?;
+
+    let events = [
+        AxelarnetGatewayEvent::ContractCalled {
+            msg: msg.clone(),
+            payload: call_contract.payload,
+        },
+        AxelarnetGatewayEvent::AckRequested {
+            cc_id: msg.cc_id.clone(),
+            timeout_height,
+        },
+    ];
+
+    route_messages(storage, querier, info.sender, vec![msg]).map(|res| res.add_events(events))
+}
+
+pub fn timeout_acks(
+    deps: DepsMut,
+    block_height: u64,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response<nexus::execute::Message>> {
+    cc_ids
+        .into_iter()
+        .unique()
+        .try_fold(Response::new(), |response, cc_id| {
+            let pending_ack = state::may_load_pending_ack(deps.storage, &cc_id)
+                .change_context(Error::LoadPendingAck(cc_id.clone()))?
+                .ok_or_else(|| report!(Error::PendingAckNotFound(cc_id.clone())))?;
+
+            ensure!(
+                block_height >= pending_ack.timeout_height,
+                Error::AckNotTimedOut(cc_id)
+            );
+
+            let (msg, event) = deliver_ack(
+                deps.storage,
+                deps.api,
+                deps.querier,
+                pending_ack,
+                AckResult::Timeout,
+            )?;
+
+            Ok(response.add_submessage(msg).add_event(event))
+        })
+}
+
+fn save_routable_msg(
+    storage: &mut dyn Storage,
+    client: &nexus::Client,
+    sender: &Addr,
+    call_contract: &CallContractData,
+) -> Result<Message> {
+    let Config { chain_name, .. } = state::load_config(storage);
+
+    let id = unique_cross_chain_id(client, chain_name)?;
+    let source_address = Address::from_str(sender.as_str())
+        .change_context(Error::InvalidSourceAddress(sender.clone()))?;
+    let msg = call_contract.to_message(id, source_address);
+
+    state::save_unique_routable_msg(storage, &msg.cc_id, &msg)
+        .inspect_err(|err| panic_if_already_exists(err, &msg.cc_id))
+        .change_context(Error::SaveRoutableMessage)?;
+
+    Ok(msg)
+}
+
 pub fn route_messages(
     storage: &mut dyn Storage,
     querier: QuerierWrapper,
@@ -152,7 +294,7 @@ pub fn route_messages(
                 RoutingDestination::This => {
                     prepare_for_execution(storage, chain_name.clone(), msgs.collect())
                 }
-                RoutingDestination::Nexus => route_to_nexus(&client, msgs.collect()),
+                RoutingDestination::Nexus => route_to_nexus(storage, &client, msgs.collect()),
                 RoutingDestination::Router => route_to_router(&router, msgs.collect()),
             }?;
 
@@ -162,11 +304,12 @@ pub fn route_messages(
 
 pub fn execute(
     deps: DepsMut,
+    env: Env,
     cc_id: CrossChainId,
     payload: HexBinary,
 ) -> Result<Response<nexus::execute::Message>> {
     let payload_hash: [u8; 32] = Keccak256::digest(payload.as_slice()).into();
-    let msg = state::mark_as_executed(
+    let (msg, failed_attempts) = state::mark_as_executed(
         deps.storage,
         &cc_id,
         ensure_same_payload_hash(&payload_hash),
@@ -184,25 +327,189 @@ pub fn execute(
             msg.destination_address.to_string(),
         ))?;
 
-    Response::new()
-        .add_message(external::Client::new(deps.querier, &destination).execute(executable_msg))
-        .add_event(AxelarnetGatewayEvent::MessageExecuted { msg })
-        .then(Ok)
+    if destination == env.contract.address {
+        return receive_ack(deps, env.block.height, msg, executable_msg.payload);
+    }
+
+    let token = state::take_received_token(deps.storage, &msg.cc_id)
+        .change_context(Error::ReleaseToken(msg.cc_id.clone()))?;
+
+    // if the execution fails, the reply marks the message as failed, so it can be executed again
+    state::save_execution_context(
+        deps.storage,
+        &ExecutionContext {
+            cc_id: msg.cc_id.clone(),
+            failed_attempts,
+            token: token.clone(),
+        },
+    )
+    .change_context(Error::SaveExecutionContext(msg.cc_id.clone()))?;
+
+    let executable = external::Client::new(deps.querier, &destination);
+    let response = Response::new();
+
+    match token {
+        Some(token) => response
+            .add_submessage(SubMsg::reply_on_error(
+                executable.execute_with_token(executable_msg, token.clone()),
+                EXECUTE_REPLY_ID,
+            ))
+            .add_event(AxelarnetGatewayEvent::TokenReleased {
+                cc_id: msg.cc_id.clone(),
+                token,
+            }),
+        None => response.add_submessage(SubMsg::reply_on_error(
+            executable.execute(executable_msg),
+            EXECUTE_REPLY_ID,
+        )),
+    }
+    .add_event(AxelarnetGatewayEvent::MessageExecuted { msg })
+    .then(Ok)
+}
+
+fn receive_ack(
+    deps: DepsMut,
+    block_height: u64,
+    msg: Message,
+    payload: HexBinary,
+) -> Result<Response<nexus::execute::Message>> {
+    let ack: Acknowledgement =
+        from_json(&payload).change_context(Error::InvalidAck(msg.cc_id.clone()))?;
+
+    let response =
+        Response::new().add_event(AxelarnetGatewayEvent::MessageExecuted { msg: msg.clone() });
+
+    let Some(pending_ack) = state::may_load_pending_ack(deps.storage, &ack.cc_id)
+        .change_context(Error::LoadPendingAck(ack.cc_id.clone()))?
+    else {
+        // the acknowledgement might arrive after the timeout callback, so there is nothing left to do
+        return Ok(response.add_event(AxelarnetGatewayEvent::AckIgnored { cc_id: ack.cc_id }));
+    };
+
+    ensure!(
+        pending_ack.msg.destination_chain == msg.cc_id.source_chain
+            && pending_ack.msg.destination_address == msg.source_address,
+        Error::AckFromUnexpectedSource(ack.cc_id)
+    );
+
+    let result = if block_height >= pending_ack.timeout_height {
+        AckResult::Timeout
+    } else if ack.success {
+        AckResult::Success(ack.data)
+    } else {
+        AckResult::Failure(ack.data)
+    };
+
+    let (callback, event) = deliver_ack(deps.storage, deps.api, deps.querier, pending_ack, result)?;
+
+    Ok(response.add_submessage(callback).add_event(event))
+}
+
+fn deliver_ack(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    pending_ack: PendingAck,
+    result: AckResult,
+) -> Result<(SubMsg<nexus::execute::Message>, AxelarnetGatewayEvent)> {
+    let PendingAck { msg, .. } = pending_ack;
+
+    state::remove_pending_ack(storage, &msg.cc_id);
+
+    // the source address of messages sent from this gateway is always the contract that sent them
+    let source = address::validate_cosmwasm_address(api, &msg.source_address).change_context(
+        Error::InvalidAckCallbackAddress(msg.source_address.to_string()),
+    )?;
+
+    let callback = external::Client::new(querier, &source).ack(AxelarAckMsg {
+        cc_id: msg.cc_id.clone(),
+        result: result.clone(),
+    });
+
+    // the pending acknowledgement is removed even if the callback fails,
+    // so a failing sender can neither block other acknowledgements nor receive it twice
+    let callback = SubMsg::reply_on_error(callback, ACK_REPLY_ID)
+        .with_payload(to_json_binary(&msg.cc_id).expect("failed to serialize cross chain id"));
+
+    Ok((
+        callback,
+        AxelarnetGatewayEvent::AckDelivered {
+            cc_id: msg.cc_id,
+            result,
+        },
+    ))
 }
 
 pub fn route_messages_from_nexus(
-    storage: &dyn Storage,
+    storage: &mut dyn Storage,
+    funds: Vec<Coin>,
     msgs: Vec<nexus::execute::Message>,
 ) -> Result<Response<nexus::execute::Message>> {
+    let Config {
+        chain_name, router, ..
+    } = state::load_config(storage);
+
+    let tokens: Vec<_> = msgs
+        .iter()
+        .filter_map(|msg| msg.token.clone().map(|token| (msg, token)))
+        .collect();
+
+    // only this gateway can hold tokens until their message is executed
+    if let Some((msg, _)) = tokens
+        .iter()
+        .find(|(msg, _)| msg.destination_chain != chain_name)
+    {
+        bail!(Error::InvalidDestination {
+            expected: chain_name,
+            actual: msg.destination_chain.clone(),
+        });
+    }
+
+    ensure_funds_match(
+        funds,
+        tokens.iter().map(|(_, token)| token.clone()).collect(),
+    )?;
+
+    let token_events = tokens
+        .into_iter()
+        .map(|(msg, token)| {
+            let cc_id = router_api::Message::try_from(msg.clone())
+                .change_context(Error::InvalidNexusMessageForRouter)?
+                .cc_id;
+
+            state::save_received_token(storage, &cc_id, &token)
+                .change_context(Error::SaveToken(cc_id.clone()))?;
+
+            Ok(AxelarnetGatewayEvent::TokenReceived { cc_id, token })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
     let msgs: Vec<_> = msgs
         .into_iter()
         .map(router_api::Message::try_from)
         .collect::<error_stack::Result<Vec<_>, _>>()
         .change_context(Error::InvalidNexusMessageForRouter)?;
 
-    let router = Router::new(state::load_config(storage).router);
+    let router = Router::new(router);
+
+    Ok(Response::new()
+        .add_messages(router.route(msgs))
+        .add_events(token_events))
+}
+
+fn ensure_funds_match(funds: Vec<Coin>, tokens: Vec<Coin>) -> Result<()> {
+    let funds = Coins::try_from(funds).change_context(Error::FundsMismatch)?;
+    let tokens = tokens
+        .into_iter()
+        .try_fold(Coins::default(), |mut acc, token| {
+            acc.add(token)?;
+            Ok::<_, cosmwasm_std::StdError>(acc)
+        })
+        .change_context(Error::FundsMismatch)?;
+
+    ensure!(funds == tokens, Error::FundsMismatch);
 
-    Ok(Response::new().add_messages(router.route(msgs)))
+    Ok(())
 }
 
 fn ensure_same_payload_hash(
@@ -318,13 +625,26 @@ fn determine_routing_destination(
     .then(Ok)
 }
 
-/// Route messages to the Nexus module
-pub fn route_to_nexus(client: &nexus::Client, msgs: Vec<Message>) -> Result<CosmosMsgWithEvent> {
+/// Route messages to the Nexus module, together with any token that was sent with them.
+/// The token is only attached the first time a message is routed.
+pub fn route_to_nexus(
+    storage: &mut dyn Storage,
+    client: &nexus::Client,
+    msgs: Vec<Message>,
+) -> Result<CosmosMsgWithEvent> {
     let nexus_msgs = msgs
         .clone()
         .into_iter()
-        .map(|msg| client.route_message(msg.into()))
-        .collect();
+        .map(|msg| {
+            let token = state::take_sent_token(storage, &msg.cc_id)
+                .change_context(Error::RoutableMessageAccess)?;
+
+            Ok(client.route_message(nexus::execute::Message {
+                token,
+                ..msg.into()
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     Ok((
         nexus_msgs,
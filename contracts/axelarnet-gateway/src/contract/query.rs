@@ -2,7 +2,9 @@ use cosmwasm_std::Storage;
 use itertools::Itertools;
 use router_api::{ChainName, CrossChainId, Message};
 
-use crate::state::{self, ExecutableMessage};
+use crate::state::{self, ExecutableMessage, PendingAck};
+
+const DEFAULT_LIMIT: u32 = 100;
 
 pub fn routable_messages(
     storage: &dyn Storage,
@@ -24,6 +26,21 @@ pub fn executable_messages(
         .try_collect()
 }
 
+pub fn pending_ack(
+    storage: &dyn Storage,
+    cc_id: CrossChainId,
+) -> Result<Option<PendingAck>, state::Error> {
+    state::may_load_pending_ack(storage, &cc_id)
+}
+
+pub fn failed_executions(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Vec<ExecutableMessage>, state::Error> {
+    state::load_failed_executions(storage, start_after, limit.unwrap_or(DEFAULT_LIMIT))
+}
+
 pub fn chain_name(storage: &dyn Storage) -> ChainName {
     state::load_config(storage).chain_name
 }
@@ -0,0 +1,74 @@
+use axelar_core_std::nexus;
+use axelar_wasm_std::IntoContractError;
+use cosmwasm_std::{from_json, Binary, Response, Storage, SubMsgResult};
+use error_stack::ResultExt;
+use router_api::CrossChainId;
+
+use crate::events::AxelarnetGatewayEvent;
+use crate::state;
+
+#[derive(thiserror::Error, Debug, IntoContractError)]
+pub enum Error {
+    #[error("failed to load the execution context")]
+    LoadExecutionContext,
+    #[error("failed to mark message with ID {0} as failed")]
+    MarkFailed(CrossChainId),
+    #[error("failed to hold back the token received with message {0}")]
+    SaveToken(CrossChainId),
+    #[error("failed to decode the ID of the message whose acknowledgement failed")]
+    InvalidAckReplyPayload,
+}
+
+type Result<T> = error_stack::Result<T, Error>;
+
+/// Marks the message whose execution failed as failed, so it can be executed again later.
+/// The token released with the execution was returned by the failed submessage, so it is held again.
+pub fn execution_failed(
+    storage: &mut dyn Storage,
+    result: SubMsgResult,
+) -> Result<Response<nexus::execute::Message>> {
+    let error = match result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => {
+            unreachable!("violated invariant: replied successful submessage with ReplyOn::Error")
+        }
+    };
+
+    let context =
+        state::load_execution_context(storage).change_context(Error::LoadExecutionContext)?;
+    let attempts = context.failed_attempts.saturating_add(1);
+
+    let msg = state::mark_as_failed(storage, &context.cc_id, error.clone(), attempts)
+        .change_context(Error::MarkFailed(context.cc_id.clone()))?;
+
+    if let Some(token) = context.token {
+        state::save_received_token(storage, &context.cc_id, &token)
+            .change_context(Error::SaveToken(context.cc_id.clone()))?;
+    }
+
+    Ok(
+        Response::new().add_event(AxelarnetGatewayEvent::MessageExecutionFailed {
+            msg,
+            error,
+            attempts,
+        }),
+    )
+}
+
+/// Records that the acknowledgement for a message could not be delivered to its sender.
+/// The pending acknowledgement has already been removed, so it is not delivered again.
+pub fn ack_delivery_failed(
+    payload: Binary,
+    result: SubMsgResult,
+) -> Result<Response<nexus::execute::Message>> {
+    let error = match result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => {
+            unreachable!("violated invariant: replied successful submessage with ReplyOn::Error")
+        }
+    };
+
+    let cc_id: CrossChainId = from_json(payload).change_context(Error::InvalidAckReplyPayload)?;
+
+    Ok(Response::new().add_event(AxelarnetGatewayEvent::AckDeliveryFailed { cc_id, error }))
+}
@@ -1,11 +1,18 @@
-use cosmwasm_std::{Attribute, Event, HexBinary};
-use router_api::Message;
+use cosmwasm_std::{Attribute, Coin, Event, HexBinary};
+use router_api::{CrossChainId, Message};
+
+use crate::clients::external::AckResult;
 
 pub enum AxelarnetGatewayEvent {
     ContractCalled {
         msg: Message,
         payload: HexBinary,
     },
+    ContractCalledWithToken {
+        msg: Message,
+        payload: HexBinary,
+        token: Coin,
+    },
     /// Uses the same event name as `GatewayEvent` for consistency
     Routing {
         msg: Message,
@@ -13,6 +20,35 @@ pub enum AxelarnetGatewayEvent {
     MessageExecuted {
         msg: Message,
     },
+    MessageExecutionFailed {
+        msg: Message,
+        error: String,
+        attempts: u64,
+    },
+    AckRequested {
+        cc_id: CrossChainId,
+        timeout_height: u64,
+    },
+    AckDelivered {
+        cc_id: CrossChainId,
+        result: AckResult,
+    },
+    /// An acknowledgement was executed for a message that doesn't await one (anymore)
+    AckDeliveryFailed {
+        cc_id: CrossChainId,
+        error: String,
+    },
+    AckIgnored {
+        cc_id: CrossChainId,
+    },
+    TokenReceived {
+        cc_id: CrossChainId,
+        token: Coin,
+    },
+    TokenReleased {
+        cc_id: CrossChainId,
+        token: Coin,
+    },
 }
 
 impl From<AxelarnetGatewayEvent> for Event {
@@ -22,10 +58,50 @@ impl From<AxelarnetGatewayEvent> for Event {
                 make_message_event("contract_called", msg)
                     .add_attribute("payload", payload.to_string())
             }
+            AxelarnetGatewayEvent::ContractCalledWithToken {
+                msg,
+                payload,
+                token,
+            } => make_message_event("contract_called_with_token", msg)
+                .add_attribute("payload", payload.to_string())
+                .add_attribute("token", token.to_string()),
             AxelarnetGatewayEvent::Routing { msg } => make_message_event("routing", msg),
             AxelarnetGatewayEvent::MessageExecuted { msg } => {
                 make_message_event("message_executed", msg)
             }
+            AxelarnetGatewayEvent::MessageExecutionFailed {
+                msg,
+                error,
+                attempts,
+            } => make_message_event("message_execution_failed", msg)
+                .add_attribute("error", error)
+                .add_attribute("attempts", attempts.to_string()),
+            AxelarnetGatewayEvent::AckRequested {
+                cc_id,
+                timeout_height,
+            } => Event::new("ack_requested")
+                .add_attribute("cc_id", cc_id.to_string())
+                .add_attribute("timeout_height", timeout_height.to_string()),
+            AxelarnetGatewayEvent::AckDelivered { cc_id, result } => Event::new("ack_delivered")
+                .add_attribute("cc_id", cc_id.to_string())
+                .add_attribute(
+                    "result",
+                    serde_json::to_string(&result).expect("failed to serialize ack result"),
+                ),
+            AxelarnetGatewayEvent::AckDeliveryFailed { cc_id, error } => {
+                Event::new("ack_delivery_failed")
+                    .add_attribute("cc_id", cc_id.to_string())
+                    .add_attribute("error", error)
+            }
+            AxelarnetGatewayEvent::AckIgnored { cc_id } => {
+                Event::new("ack_ignored").add_attribute("cc_id", cc_id.to_string())
+            }
+            AxelarnetGatewayEvent::TokenReceived { cc_id, token } => Event::new("token_received")
+                .add_attribute("cc_id", cc_id.to_string())
+                .add_attribute("token", token.to_string()),
+            AxelarnetGatewayEvent::TokenReleased { cc_id, token } => Event::new("token_released")
+                .add_attribute("cc_id", cc_id.to_string())
+                .add_attribute("token", token.to_string()),
         }
     }
 }
@@ -4,6 +4,8 @@ pub mod msg;
 mod state;
 
 mod clients;
-pub use clients::external::AxelarExecutableMsg;
+pub use clients::external::{
+    AckResult, AxelarAckMsg, AxelarExecutableMsg, Client as AxelarExecutableClient,
+};
 pub use clients::gateway::Client;
-pub use state::{Error as StateError, ExecutableMessage};
+pub use state::{Error as StateError, ExecutableMessage, PendingAck};
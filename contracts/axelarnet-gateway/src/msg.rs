@@ -1,10 +1,11 @@
 use axelar_core_std::nexus;
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::HexBinary;
 use msgs_derive::EnsurePermissions;
 use router_api::{Address, ChainName, CrossChainId, Message};
 
-use crate::state::ExecutableMessage;
+use crate::state::{ExecutableMessage, PendingAck};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -26,7 +27,8 @@ pub enum ExecuteMsg {
     RouteMessages(Vec<Message>),
 
     /// Execute the message at the destination contract with the corresponding payload.
-    /// The message is marked as executed and thus can't be executed again.
+    /// The message is marked as executed and thus can't be executed again,
+    /// unless the execution fails, in which case it is marked as failed and can be retried.
     #[permission(Any)]
     Execute {
         cc_id: CrossChainId,
@@ -43,7 +45,35 @@ pub enum ExecuteMsg {
         payload: HexBinary,
     },
 
+    /// Initiate a cross-chain contract call from Axelarnet to another chain with a token attached.
+    /// Exactly one coin must be sent along, which is escrowed in the gateway.
+    /// The destination chain must be registered with core, because only the nexus module can forward the token.
+    #[permission(Any)]
+    CallContractWithToken {
+        destination_chain: ChainName,
+        destination_address: Address,
+        payload: HexBinary,
+    },
+
+    /// Initiate a cross-chain contract call like `CallContract`, and get called back with its outcome.
+    /// The destination is expected to send an `Acknowledgement` back to this gateway. Once it is executed,
+    /// the sender is called back with an `AxelarAckMsg`. If no acknowledgement arrives within `timeout_blocks` blocks,
+    /// the sender is called back with a timeout instead. The sender is called back at most once; if the callback fails,
+    /// the acknowledgement is dropped and an `ack_delivery_failed` event is emitted.
+    #[permission(Any)]
+    CallContractWithAck {
+        destination_chain: ChainName,
+        destination_address: Address,
+        payload: HexBinary,
+        timeout_blocks: nonempty::Uint64,
+    },
+
+    /// Call back the senders of the given messages with a timeout, if their acknowledgements did not arrive in time.
+    #[permission(Any)]
+    TimeoutAcks(Vec<CrossChainId>),
+
     /// Forward the given nexus messages to the next step of the routing layer.
+    /// Tokens attached to the messages must be sent along and are escrowed until the messages are executed.
     #[permission(Specific(nexus))]
     RouteMessagesFromNexus(Vec<nexus::execute::Message>),
 }
@@ -59,7 +89,31 @@ pub enum QueryMsg {
     #[returns(Vec<ExecutableMessage>)]
     ExecutableMessages { cc_ids: Vec<CrossChainId> },
 
+    /// Returns the acknowledgement that is awaited for the given sent message, if any.
+    #[returns(Option<PendingAck>)]
+    PendingAck { cc_id: CrossChainId },
+
+    /// Returns the received messages whose last execution failed, ordered by their cross-chain id.
+    /// They can be executed again with `Execute`.
+    #[returns(Vec<ExecutableMessage>)]
+    FailedExecutions {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
     /// Returns the chain name for this gateway.
     #[returns(ChainName)]
     ChainName,
 }
+
+/// Payload of a message that acknowledges a message sent with `CallContractWithAck`.
+/// It must be sent to the address of this gateway by the destination address of the acknowledged message,
+/// and is encoded as JSON.
+#[cw_serde]
+pub struct Acknowledgement {
+    /// ID of the acknowledged message
+    pub cc_id: CrossChainId,
+    pub success: bool,
+    /// data returned by the destination, passed on to the source contract as is
+    pub data: HexBinary,
+}
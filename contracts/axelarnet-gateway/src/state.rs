@@ -1,13 +1,22 @@
 use axelar_wasm_std::{FnExt, IntoContractError};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdError, Storage};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Coin, Order, StdError, Storage};
+use cw_storage_plus::{Bound, Item, Map};
 use error_stack::report;
 use router_api::{ChainName, CrossChainId, Message};
 
 const CONFIG: Item<Config> = Item::new("config");
 const ROUTABLE_MESSAGES: Map<&CrossChainId, Message> = Map::new("routable_messages");
 const EXECUTABLE_MESSAGES: Map<&CrossChainId, ExecutableMessage> = Map::new("executable_messages");
+/// executable messages whose last execution attempt failed
+const FAILED_EXECUTIONS: Map<CrossChainId, ()> = Map::new("failed_executions");
+/// context of the execution that awaits its reply
+const EXECUTION_REPLY_TRACKER: Item<ExecutionContext> = Item::new("execution_reply_tracker");
+/// tokens sent with `CallContractWithToken`, held by the gateway
+const SENT_TOKENS: Map<&CrossChainId, Coin> = Map::new("sent_tokens");
+const PENDING_ACKS: Map<&CrossChainId, PendingAck> = Map::new("pending_acks");
+/// tokens received from the nexus module, held by the gateway until the message is executed
+const RECEIVED_TOKENS: Map<&CrossChainId, Coin> = Map::new("received_tokens");
 
 #[derive(thiserror::Error, Debug, PartialEq, IntoContractError)]
 pub enum Error {
@@ -23,10 +32,14 @@ pub enum Error {
     MessageNotApproved(CrossChainId),
     #[error("message with ID {0} already executed")]
     MessageAlreadyExecuted(CrossChainId),
+    #[error("message with ID {0} has not been executed")]
+    MessageNotExecuted(CrossChainId),
     #[error("sent message with ID {0} already exists")]
     MessageAlreadyExists(CrossChainId),
     #[error("payload hash doesn't match message")]
     PayloadHashMismatch,
+    #[error("token for message with ID {0} has already been received")]
+    TokenAlreadyReceived(CrossChainId),
 }
 
 #[cw_serde]
@@ -42,12 +55,39 @@ pub enum ExecutableMessage {
     Approved(Message),
     /// An approved message that has been executed.
     Executed(Message),
+    /// An approved message whose execution failed. It can be executed again.
+    Failed {
+        msg: Message,
+        /// error returned by the last execution attempt
+        error: String,
+        /// number of failed execution attempts
+        attempts: u64,
+    },
+}
+
+#[cw_serde]
+pub struct ExecutionContext {
+    pub cc_id: CrossChainId,
+    /// number of failed execution attempts before the current one
+    pub failed_attempts: u64,
+    /// token released with the execution, it is held again if the execution fails
+    pub token: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct PendingAck {
+    /// the sent message that awaits an acknowledgement
+    pub msg: Message,
+    /// block height from which on the acknowledgement is considered timed out
+    pub timeout_height: u64,
 }
 
 impl ExecutableMessage {
     pub fn msg(&self) -> &Message {
         match self {
-            ExecutableMessage::Approved(msg) | ExecutableMessage::Executed(msg) => msg,
+            ExecutableMessage::Approved(msg)
+            | ExecutableMessage::Executed(msg)
+            | ExecutableMessage::Failed { msg, .. } => msg,
         }
     }
 }
@@ -113,21 +153,151 @@ pub fn load_executable_msg(
     may_load_executable_msg(storage, cc_id)?.ok_or_else(|| Error::MessageNotApproved(cc_id.clone()))
 }
 
-/// Update the status of a message to executed if it is in approved status, error otherwise.
+/// Update the status of a message to executed if it is in approved or failed status, error otherwise.
 /// The validation function can define additional checks on the message.
+/// Returns the message together with the number of its previously failed execution attempts.
 pub fn mark_as_executed(
     storage: &mut dyn Storage,
     cc_id: &CrossChainId,
     // this uses a reference to ensure the caller cannot mutate the message
     validate: impl FnOnce(&Message) -> Result<(), Error>,
-) -> Result<Message, Error> {
-    let msg = match may_load_executable_msg(storage, cc_id)? {
+) -> Result<(Message, u64), Error> {
+    let (msg, failed_attempts) = match may_load_executable_msg(storage, cc_id)? {
         None => Err(Error::MessageNotApproved(cc_id.clone())),
         Some(ExecutableMessage::Executed(_)) => Err(Error::MessageAlreadyExecuted(cc_id.clone())),
-        Some(ExecutableMessage::Approved(msg)) => validate(&msg)?.then(|_| Ok(msg)),
+        Some(ExecutableMessage::Approved(msg)) => validate(&msg)?.then(|_| Ok((msg, 0))),
+        Some(ExecutableMessage::Failed { msg, attempts, .. }) => {
+            validate(&msg)?.then(|_| Ok((msg, attempts)))
+        }
     }?;
 
     EXECUTABLE_MESSAGES.save(storage, cc_id, &ExecutableMessage::Executed(msg.clone()))?;
+    FAILED_EXECUTIONS.remove(storage, cc_id.clone());
+
+    Ok((msg, failed_attempts))
+}
+
+/// Update the status of an executed message to failed, so its execution can be retried.
+pub fn mark_as_failed(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    error: String,
+    attempts: u64,
+) -> Result<Message, Error> {
+    let msg = match may_load_executable_msg(storage, cc_id)? {
+        Some(ExecutableMessage::Executed(msg)) => Ok(msg),
+        _ => Err(Error::MessageNotExecuted(cc_id.clone())),
+    }?;
+
+    EXECUTABLE_MESSAGES.save(
+        storage,
+        cc_id,
+        &ExecutableMessage::Failed {
+            msg: msg.clone(),
+            error,
+            attempts,
+        },
+    )?;
+    FAILED_EXECUTIONS.save(storage, cc_id.clone(), &())?;
 
     Ok(msg)
 }
+
+pub fn load_failed_executions(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: u32,
+) -> Result<Vec<ExecutableMessage>, Error> {
+    FAILED_EXECUTIONS
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|cc_id| load_executable_msg(storage, &cc_id?))
+        .collect()
+}
+
+pub fn save_sent_token(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    token: &Coin,
+) -> Result<(), Error> {
+    // same as for routable messages, these cc IDs are generated by the gateway and should be unique
+    if SENT_TOKENS.has(storage, cc_id) {
+        return Err(Error::MessageAlreadyExists(cc_id.clone()));
+    }
+
+    Ok(SENT_TOKENS.save(storage, cc_id, token)?)
+}
+
+/// Removes the token that was sent with the given message, so it is only routed once
+pub fn take_sent_token(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<Option<Coin>, Error> {
+    let token = SENT_TOKENS.may_load(storage, cc_id)?;
+    SENT_TOKENS.remove(storage, cc_id);
+
+    Ok(token)
+}
+
+pub fn save_received_token(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    token: &Coin,
+) -> Result<(), Error> {
+    // a token that is still held for the message must not be replaced or deposited twice
+    if RECEIVED_TOKENS.has(storage, cc_id) {
+        return Err(Error::TokenAlreadyReceived(cc_id.clone()));
+    }
+
+    Ok(RECEIVED_TOKENS.save(storage, cc_id, token)?)
+}
+
+/// Removes the token that was received for the given message, so it can only be released once
+pub fn take_received_token(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<Option<Coin>, Error> {
+    let token = RECEIVED_TOKENS.may_load(storage, cc_id)?;
+    RECEIVED_TOKENS.remove(storage, cc_id);
+
+    Ok(token)
+}
+
+pub fn save_pending_ack(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    pending_ack: &PendingAck,
+) -> Result<(), Error> {
+    if PENDING_ACKS.has(storage, cc_id) {
+        return Err(Error::MessageAlreadyExists(cc_id.clone()));
+    }
+
+    Ok(PENDING_ACKS.save(storage, cc_id, pending_ack)?)
+}
+
+pub fn may_load_pending_ack(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<Option<PendingAck>, Error> {
+    Ok(PENDING_ACKS.may_load(storage, cc_id)?)
+}
+
+pub fn remove_pending_ack(storage: &mut dyn Storage, cc_id: &CrossChainId) {
+    PENDING_ACKS.remove(storage, cc_id)
+}
+
+pub fn save_execution_context(
+    storage: &mut dyn Storage,
+    context: &ExecutionContext,
+) -> Result<(), Error> {
+    Ok(EXECUTION_REPLY_TRACKER.save(storage, context)?)
+}
+
+pub fn load_execution_context(storage: &dyn Storage) -> Result<ExecutionContext, Error> {
+    Ok(EXECUTION_REPLY_TRACKER.load(storage)?)
+}
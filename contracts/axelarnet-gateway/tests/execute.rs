@@ -1,14 +1,22 @@
 use assert_ok::assert_ok;
+use axelar_core_std::nexus;
 use axelar_core_std::nexus::test_utils::reply_with_is_chain_registered;
+use axelar_core_std::query::AxelarQueryMsg;
 use axelar_wasm_std::assert_err_contains;
 use axelar_wasm_std::response::inspect_response_msg;
 use axelarnet_gateway::contract::ExecuteError;
-use axelarnet_gateway::StateError;
-use cosmwasm_std::testing::{message_info, mock_dependencies};
-use cosmwasm_std::HexBinary;
+use axelarnet_gateway::msg::Acknowledgement;
+use axelarnet_gateway::{AckResult, ExecutableMessage, PendingAck, StateError};
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_json, to_json_vec, Coin, CosmosMsg, HexBinary, OwnedDeps, ReplyOn, WasmMsg,
+};
 use rand::RngCore;
 use router_api::msg::ExecuteMsg as RouterExecuteMsg;
 use router_api::{CrossChainId, Message};
+use sha3::Digest;
 
 use crate::utils::{
     axelar_query_handler, messages, mock_axelar_dependencies, params, OwnedDepsExt,
@@ -494,3 +502,528 @@ fn route_to_router_after_contract_call_to_self_succeeds_multiple_times() {
         goldie::assert_json!(msg);
     }
 }
+
+#[test]
+fn contract_call_with_token_routes_token_to_nexus() {
+    let mut deps = mock_axelar_dependencies();
+    let api = deps.api;
+
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([1; 32], 5, true));
+
+    let token = Coin::new(100u128, "uaxl");
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    let response = assert_ok!(utils::call_contract_with_token(
+        deps.as_default_mut(),
+        message_info(&api.addr_make("sender"), &[token.clone()]),
+        "legacy-chain".parse().unwrap(),
+        "destination-address".parse().unwrap(),
+        vec![1, 2, 3].into(),
+    ));
+
+    assert_eq!(response.messages.len(), 1);
+    match &response.messages[0].msg {
+        CosmosMsg::Custom(msg) => assert_eq!(msg.token, Some(token.clone())),
+        msg => panic!("unexpected message {:?}", msg),
+    }
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "contract_called_with_token"));
+
+    // the token is only sent once, re-routing the message must not attach it again
+    let nexus_msg: nexus::execute::Message = match &response.messages[0].msg {
+        CosmosMsg::Custom(msg) => msg.clone(),
+        _ => unreachable!(),
+    };
+    let msg = router_api::Message::try_from(nexus_msg).unwrap();
+    let response = assert_ok!(utils::route_to_router(deps.as_default_mut(), vec![msg]));
+    match &response.messages[0].msg {
+        CosmosMsg::Custom(msg) => assert_eq!(msg.token, None),
+        msg => panic!("unexpected message {:?}", msg),
+    }
+}
+
+#[test]
+fn contract_call_with_token_to_chain_not_registered_with_core_fails() {
+    let mut deps = mock_axelar_dependencies();
+    let api = deps.api;
+
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([1; 32], 5, false));
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    assert_err_contains!(
+        utils::call_contract_with_token(
+            deps.as_default_mut(),
+            message_info(&api.addr_make("sender"), &[Coin::new(100u128, "uaxl")]),
+            "destination-chain".parse().unwrap(),
+            "destination-address".parse().unwrap(),
+            vec![1, 2, 3].into(),
+        ),
+        ExecuteError,
+        ExecuteError::TokenTransferNotSupported(..)
+    );
+}
+
+#[test]
+fn contract_call_with_token_without_exactly_one_coin_fails() {
+    let mut deps = mock_axelar_dependencies();
+    let api = deps.api;
+
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([1; 32], 5, true));
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    for funds in [
+        vec![],
+        vec![Coin::new(0u128, "uaxl")],
+        vec![Coin::new(1u128, "uaxl"), Coin::new(1u128, "uusdc")],
+    ] {
+        assert_err_contains!(
+            utils::call_contract_with_token(
+                deps.as_default_mut(),
+                message_info(&api.addr_make("sender"), &funds),
+                "legacy-chain".parse().unwrap(),
+                "destination-address".parse().unwrap(),
+                vec![1, 2, 3].into(),
+            ),
+            ExecuteError,
+            ExecuteError::InvalidToken
+        );
+    }
+}
+
+#[test]
+fn route_from_nexus_with_token_releases_token_on_execute() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let token = Coin::new(100u128, "uaxl");
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let nexus_msg = nexus::execute::Message {
+        destination_chain: params::AXELARNET.parse().unwrap(),
+        destination_address: messages::dummy_from_router(&payload).destination_address,
+        token: Some(token.clone()),
+        ..messages::dummy_from_nexus(&payload)
+    };
+    let msg = router_api::Message::try_from(nexus_msg.clone()).unwrap();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    assert_err_contains!(
+        utils::route_from_nexus_with_funds(deps.as_default_mut(), vec![nexus_msg.clone()], &[]),
+        ExecuteError,
+        ExecuteError::FundsMismatch
+    );
+
+    let response = assert_ok!(utils::route_from_nexus_with_funds(
+        deps.as_default_mut(),
+        vec![nexus_msg],
+        &[token.clone()]
+    ));
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "token_received"));
+
+    utils::route_from_router(deps.as_default_mut(), vec![msg.clone()]).unwrap();
+
+    let response = assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        msg.cc_id,
+        payload.clone()
+    ));
+    match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, funds, .. }) => {
+            assert_eq!(funds, &vec![token]);
+            let executable: utils::ExecuteMsg = from_json(msg).unwrap();
+            assert!(matches!(executable, utils::ExecuteMsg::Execute(..)));
+        }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "token_released"));
+}
+
+#[test]
+fn route_from_nexus_with_token_to_other_chain_fails() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let token = Coin::new(100u128, "uaxl");
+    let nexus_msg = nexus::execute::Message {
+        token: Some(token.clone()),
+        ..messages::dummy_from_nexus(&[1, 2, 3])
+    };
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    assert_err_contains!(
+        utils::route_from_nexus_with_funds(deps.as_default_mut(), vec![nexus_msg], &[token]),
+        ExecuteError,
+        ExecuteError::InvalidDestination { .. }
+    );
+}
+
+#[test]
+fn route_from_nexus_with_token_twice_fails() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let token = Coin::new(100u128, "uaxl");
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let nexus_msg = nexus::execute::Message {
+        destination_chain: params::AXELARNET.parse().unwrap(),
+        token: Some(token.clone()),
+        ..messages::dummy_from_nexus(&payload)
+    };
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    assert_ok!(utils::route_from_nexus_with_funds(
+        deps.as_default_mut(),
+        vec![nexus_msg.clone()],
+        &[token.clone()]
+    ));
+    assert_err_contains!(
+        utils::route_from_nexus_with_funds(deps.as_default_mut(), vec![nexus_msg], &[token]),
+        ExecuteError,
+        ExecuteError::SaveToken(..)
+    );
+}
+
+#[test]
+fn failed_execution_is_recorded_and_can_be_retried() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let msg = messages::dummy_from_router(&payload);
+    let cc_id = msg.cc_id.clone();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    utils::route_from_router(deps.as_default_mut(), vec![msg.clone()]).unwrap();
+
+    for attempts in 1..=2 {
+        let response = assert_ok!(utils::execute_payload(
+            deps.as_default_mut(),
+            cc_id.clone(),
+            payload.clone()
+        ));
+        assert_eq!(response.messages[0].reply_on, ReplyOn::Error);
+
+        let response = assert_ok!(utils::reply_execution_failed(
+            deps.as_default_mut(),
+            "execution reverted"
+        ));
+        assert!(response
+            .events
+            .iter()
+            .any(|event| event.ty == "message_execution_failed"));
+
+        assert_eq!(
+            query_failed_executions(&deps, None, None),
+            vec![ExecutableMessage::Failed {
+                msg: msg.clone(),
+                error: "execution reverted".to_string(),
+                attempts,
+            }]
+        );
+    }
+
+    assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        cc_id.clone(),
+        payload.clone()
+    ));
+    assert!(query_failed_executions(&deps, None, None).is_empty());
+
+    assert_err_contains!(
+        utils::execute_payload(deps.as_default_mut(), cc_id, payload),
+        StateError,
+        StateError::MessageAlreadyExecuted(..)
+    );
+}
+
+#[test]
+fn failed_execution_with_token_holds_token_until_retry() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let token = Coin::new(100u128, "uaxl");
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let nexus_msg = nexus::execute::Message {
+        destination_chain: params::AXELARNET.parse().unwrap(),
+        destination_address: messages::dummy_from_router(&payload).destination_address,
+        token: Some(token.clone()),
+        ..messages::dummy_from_nexus(&payload)
+    };
+    let msg = router_api::Message::try_from(nexus_msg.clone()).unwrap();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    utils::route_from_nexus_with_funds(deps.as_default_mut(), vec![nexus_msg], &[token.clone()])
+        .unwrap();
+    utils::route_from_router(deps.as_default_mut(), vec![msg.clone()]).unwrap();
+
+    utils::execute_payload(deps.as_default_mut(), msg.cc_id.clone(), payload.clone()).unwrap();
+    utils::reply_execution_failed(deps.as_default_mut(), "execution reverted").unwrap();
+
+    let response = assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        msg.cc_id,
+        payload
+    ));
+    match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) => assert_eq!(funds, &vec![token]),
+        msg => panic!("unexpected message {:?}", msg),
+    }
+}
+
+#[test]
+fn contract_call_with_ack_delivers_ack_to_sender() {
+    let mut deps = mock_axelar_dependencies();
+    let api = deps.api;
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([1; 32], 7, false));
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    let (cc_id, sent_msg) = call_contract_with_ack(&mut deps, 10);
+    assert!(query_pending_ack(&deps, cc_id.clone()).is_some());
+
+    let ack = Acknowledgement {
+        cc_id: cc_id.clone(),
+        success: true,
+        data: vec![4, 5, 6].into(),
+    };
+    let (ack_msg, ack_payload) = ack_from(&sent_msg, &ack, "ack-0");
+
+    // acknowledgements from any other address than the destination are rejected
+    let (spoofed_ack_msg, _) = ack_from(
+        &Message {
+            destination_address: "other-address".parse().unwrap(),
+            ..sent_msg.clone()
+        },
+        &ack,
+        "ack-1",
+    );
+    utils::route_from_router(deps.as_default_mut(), vec![spoofed_ack_msg.clone()]).unwrap();
+    assert_err_contains!(
+        utils::execute_payload(
+            deps.as_default_mut(),
+            spoofed_ack_msg.cc_id,
+            ack_payload.clone()
+        ),
+        ExecuteError,
+        ExecuteError::AckFromUnexpectedSource(..)
+    );
+
+    utils::route_from_router(deps.as_default_mut(), vec![ack_msg.clone()]).unwrap();
+    let response = assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        ack_msg.cc_id,
+        ack_payload
+    ));
+
+    assert_eq!(response.messages[0].reply_on, ReplyOn::Error);
+    match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, api.addr_make("sender").as_str());
+            match from_json(msg).unwrap() {
+                utils::ExecuteMsg::Ack(ack_msg) => {
+                    assert_eq!(ack_msg.cc_id, cc_id);
+                    assert_eq!(ack_msg.result, AckResult::Success(vec![4, 5, 6].into()));
+                }
+                msg => panic!("unexpected message {:?}", msg),
+            }
+        }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+
+    assert!(query_pending_ack(&deps, cc_id).is_none());
+}
+
+#[test]
+fn timeout_acks_calls_back_sender_with_timeout() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([2; 32], 3, false));
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    let (cc_id, sent_msg) = call_contract_with_ack(&mut deps, 10);
+
+    let mut env = mock_env();
+    env.block.height = env.block.height.checked_add(9).unwrap();
+    assert_err_contains!(
+        utils::timeout_acks(deps.as_default_mut(), env.clone(), vec![cc_id.clone()]),
+        ExecuteError,
+        ExecuteError::AckNotTimedOut(..)
+    );
+
+    env.block.height = env.block.height.checked_add(1).unwrap();
+    let response = assert_ok!(utils::timeout_acks(
+        deps.as_default_mut(),
+        env.clone(),
+        vec![cc_id.clone()]
+    ));
+    assert_eq!(response.messages[0].reply_on, ReplyOn::Error);
+    match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_json(msg).unwrap() {
+            utils::ExecuteMsg::Ack(ack_msg) => assert_eq!(ack_msg.result, AckResult::Timeout),
+            msg => panic!("unexpected message {:?}", msg),
+        },
+        msg => panic!("unexpected message {:?}", msg),
+    }
+
+    assert_err_contains!(
+        utils::timeout_acks(deps.as_default_mut(), env, vec![cc_id.clone()]),
+        ExecuteError,
+        ExecuteError::PendingAckNotFound(..)
+    );
+
+    // a late acknowledgement is ignored
+    let (ack_msg, ack_payload) = ack_from(
+        &sent_msg,
+        &Acknowledgement {
+            cc_id,
+            success: true,
+            data: vec![].into(),
+        },
+        "ack-0",
+    );
+    utils::route_from_router(deps.as_default_mut(), vec![ack_msg.clone()]).unwrap();
+    let response = assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        ack_msg.cc_id,
+        ack_payload
+    ));
+    assert!(response.messages.is_empty());
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "ack_ignored"));
+}
+
+#[test]
+fn failed_ack_delivery_is_recorded_and_not_retried() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(axelar_query_handler([3; 32], 4, false));
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+
+    let (cc_id, _) = call_contract_with_ack(&mut deps, 10);
+
+    let mut env = mock_env();
+    env.block.height = env.block.height.checked_add(10).unwrap();
+    let response = assert_ok!(utils::timeout_acks(
+        deps.as_default_mut(),
+        env.clone(),
+        vec![cc_id.clone()]
+    ));
+    assert_eq!(response.messages[0].reply_on, ReplyOn::Error);
+
+    let response = assert_ok!(utils::reply_ack_delivery_failed(
+        deps.as_default_mut(),
+        &cc_id,
+        "callback reverted"
+    ));
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "ack_delivery_failed"));
+
+    assert!(query_pending_ack(&deps, cc_id.clone()).is_none());
+    assert_err_contains!(
+        utils::timeout_acks(deps.as_default_mut(), env, vec![cc_id]),
+        ExecuteError,
+        ExecuteError::PendingAckNotFound(..)
+    );
+}
+
+fn call_contract_with_ack(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier<AxelarQueryMsg>, AxelarQueryMsg>,
+    timeout_blocks: u64,
+) -> (CrossChainId, Message) {
+    let api = deps.api;
+    let response = assert_ok!(utils::call_contract_with_ack(
+        deps.as_default_mut(),
+        message_info(&api.addr_make("sender"), &[]),
+        "destination-chain".parse().unwrap(),
+        "destination-address".parse().unwrap(),
+        vec![1, 2, 3].into(),
+        timeout_blocks.try_into().unwrap(),
+    ));
+
+    let RouterExecuteMsg::RouteMessages(msgs) = assert_ok!(inspect_response_msg(response)) else {
+        panic!("expected messages to be routed");
+    };
+
+    (msgs[0].cc_id.clone(), msgs[0].clone())
+}
+
+fn ack_from(sent_msg: &Message, ack: &Acknowledgement, ack_id: &str) -> (Message, HexBinary) {
+    let payload: HexBinary = to_json_vec(ack).unwrap().into();
+
+    let msg = Message {
+        cc_id: CrossChainId::new(sent_msg.destination_chain.clone(), ack_id).unwrap(),
+        source_address: sent_msg.destination_address.clone(),
+        destination_chain: params::AXELARNET.parse().unwrap(),
+        destination_address: mock_env().contract.address.to_string().parse().unwrap(),
+        payload_hash: sha3::Keccak256::digest(payload.as_slice()).into(),
+    };
+
+    (msg, payload)
+}
+
+fn query_pending_ack(deps: &impl OwnedDepsExt, cc_id: CrossChainId) -> Option<PendingAck> {
+    from_json(
+        axelarnet_gateway::contract::query(
+            deps.as_default_deps(),
+            mock_env(),
+            axelarnet_gateway::msg::QueryMsg::PendingAck { cc_id },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_failed_executions(
+    deps: &impl OwnedDepsExt,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Vec<ExecutableMessage> {
+    from_json(
+        axelarnet_gateway::contract::query(
+            deps.as_default_deps(),
+            mock_env(),
+            axelarnet_gateway::msg::QueryMsg::FailedExecutions { start_after, limit },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
@@ -1,10 +1,13 @@
 use axelar_core_std::nexus;
 use axelar_wasm_std::error::ContractError;
+use axelar_wasm_std::nonempty;
 use axelarnet_gateway::msg::ExecuteMsg as GatewayExecuteMsg;
-use axelarnet_gateway::{contract, AxelarExecutableMsg};
+use axelarnet_gateway::{contract, AxelarAckMsg, AxelarExecutableMsg};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::testing::{message_info, mock_env, MockApi};
-use cosmwasm_std::{DepsMut, HexBinary, MessageInfo, Response};
+use cosmwasm_std::{
+    to_json_binary, Coin, DepsMut, Env, HexBinary, MessageInfo, Reply, Response, SubMsgResult,
+};
 use router_api::{Address, ChainName, CrossChainId, Message};
 
 use crate::utils::params;
@@ -13,6 +16,7 @@ use crate::utils::params;
 /// simulating a contract's implementation of the `Execute` variant of `ExecuteMsg` from `axelarnet-gateway`
 pub enum ExecuteMsg {
     Execute(AxelarExecutableMsg),
+    Ack(AxelarAckMsg),
 }
 
 pub fn call_contract(
@@ -34,6 +38,25 @@ pub fn call_contract(
     )
 }
 
+pub fn call_contract_with_token(
+    deps: DepsMut,
+    info: MessageInfo,
+    destination_chain: ChainName,
+    destination_address: Address,
+    payload: HexBinary,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        info,
+        GatewayExecuteMsg::CallContractWithToken {
+            destination_chain,
+            destination_address,
+            payload,
+        },
+    )
+}
+
 pub fn route_from_router(
     deps: DepsMut,
     msgs: Vec<Message>,
@@ -82,3 +105,83 @@ pub fn route_from_nexus(
         GatewayExecuteMsg::RouteMessagesFromNexus(msgs),
     )
 }
+
+pub fn route_from_nexus_with_funds(
+    deps: DepsMut,
+    msgs: Vec<nexus::execute::Message>,
+    funds: &[Coin],
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::NEXUS), funds),
+        GatewayExecuteMsg::RouteMessagesFromNexus(msgs),
+    )
+}
+
+pub fn call_contract_with_ack(
+    deps: DepsMut,
+    info: MessageInfo,
+    destination_chain: ChainName,
+    destination_address: Address,
+    payload: HexBinary,
+    timeout_blocks: nonempty::Uint64,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        info,
+        GatewayExecuteMsg::CallContractWithAck {
+            destination_chain,
+            destination_address,
+            payload,
+            timeout_blocks,
+        },
+    )
+}
+
+pub fn timeout_acks(
+    deps: DepsMut,
+    env: Env,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        env,
+        message_info(&MockApi::default().addr_make("sender"), &[]),
+        GatewayExecuteMsg::TimeoutAcks(cc_ids),
+    )
+}
+
+pub fn reply_execution_failed(
+    deps: DepsMut,
+    error: &str,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::reply(
+        deps,
+        mock_env(),
+        Reply {
+            id: contract::EXECUTE_REPLY_ID,
+            payload: vec![].into(),
+            gas_used: 0,
+            result: SubMsgResult::Err(error.to_string()),
+        },
+    )
+}
+
+pub fn reply_ack_delivery_failed(
+    deps: DepsMut,
+    cc_id: &CrossChainId,
+    error: &str,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::reply(
+        deps,
+        mock_env(),
+        Reply {
+            id: contract::ACK_REPLY_ID,
+            payload: to_json_binary(cc_id).unwrap(),
+            gas_used: 0,
+            result: SubMsgResult::Err(error.to_string()),
+        },
+    )
+}
@@ -1,15 +1,16 @@
 use std::fmt::Debug;
 
-use axelar_wasm_std::{address, FnExt};
+use axelar_wasm_std::{address, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{ensure, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response};
+use cosmwasm_std::{ensure, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use cw2::VersionError;
 use error_stack::{report, ResultExt};
 use router_api::client::Router;
+use router_api::CrossChainId;
 use semver::Version;
 
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::state;
 use crate::state::Config;
 
@@ -36,15 +37,25 @@ pub enum Error {
     OutgoingMessages,
     #[error("failed to save outgoing message")]
     SaveOutgoingMessage,
+    #[error("failed to save incoming message")]
+    SaveIncomingMessage,
     #[error("failed to execute gateway command")]
     Execute,
+    #[error("failed to cancel outgoing messages")]
+    CancelMessages,
+    #[error("sender {sender} is not allowed to cancel message {cc_id}")]
+    CancelNotAllowed { sender: Addr, cc_id: CrossChainId },
+    #[error("failed to query outgoing message statuses")]
+    OutgoingMessageStatuses,
+    #[error("failed to query incoming message statuses")]
+    IncomingMessageStatuses,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: Empty,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     let old_version = Version::parse(&cw2::get_contract_version(deps.storage)?.version)?;
     ensure!(
@@ -55,6 +66,25 @@ pub fn migrate(
         })
     );
 
+    // incoming messages routed by the previous version are unknown to this one
+    state::mark_routed_incoming_messages_untracked(deps.storage)?;
+
+    if let Some(governance_address) = msg.governance_address {
+        let governance = address::validate_cosmwasm_address(deps.api, &governance_address)?;
+        permission_control::set_governance(deps.storage, &governance)?;
+    }
+
+    if let Some(axelar_chain_name) = msg.axelar_chain_name {
+        let config = state::load_config(deps.storage)?;
+        state::save_config(
+            deps.storage,
+            &Config {
+                axelar_chain_name: Some(axelar_chain_name),
+                ..config
+            },
+        )?;
+    }
+
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::default())
@@ -71,15 +101,26 @@ pub fn instantiate(
 
     let router = address::validate_cosmwasm_address(deps.api, &msg.router_address)?;
     let verifier = address::validate_cosmwasm_address(deps.api, &msg.verifier_address)?;
+    let governance = address::validate_cosmwasm_address(deps.api, &msg.governance_address)?;
+
+    permission_control::set_governance(deps.storage, &governance)?;
 
-    state::save_config(deps.storage, &Config { verifier, router })?;
+    state::save_config(
+        deps.storage,
+        &Config {
+            verifier,
+            router,
+            message_expiry: msg.message_expiry,
+            axelar_chain_name: msg.axelar_chain_name,
+        },
+    )?;
     Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
@@ -94,13 +135,27 @@ pub fn execute(
             let router = Router::new(config.router);
 
             if info.sender == router.address {
-                execute::route_outgoing_messages(deps.storage, msgs)
-                    .change_context(Error::RouteOutgoingMessages)
+                execute::route_outgoing_messages(
+                    deps.storage,
+                    env.block.height,
+                    config.message_expiry,
+                    msgs,
+                )
+                .change_context(Error::RouteOutgoingMessages)
             } else {
-                execute::route_incoming_messages(&verifier, &router, msgs)
+                execute::route_incoming_messages(deps.storage, &verifier, &router, msgs)
                     .change_context(Error::RouteIncomingMessages)
             }
         }
+        ExecuteMsg::CancelMessages(cc_ids) => execute::cancel_messages(
+            deps.storage,
+            config.axelar_chain_name.as_ref(),
+            &info.sender,
+            cc_ids,
+        ),
+        ExecuteMsg::SetMessageExpiry { blocks } => {
+            execute::set_message_expiry(deps.storage, blocks)
+        }
     }?
     .then(Ok)
 }
@@ -108,7 +163,7 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -116,6 +171,33 @@ pub fn query(
             query::outgoing_messages(deps.storage, message_ids.iter())
                 .change_context(Error::OutgoingMessages)
         }
+        QueryMsg::OutgoingMessageStatuses(message_ids) => {
+            query::outgoing_message_statuses(deps.storage, env.block.height, message_ids.iter())
+                .change_context(Error::OutgoingMessageStatuses)
+        }
+        QueryMsg::OutgoingMessagesByDestination {
+            destination_address,
+            start_after,
+            limit,
+        } => query::outgoing_messages_by_destination(
+            deps.storage,
+            destination_address,
+            start_after,
+            limit,
+        )
+        .change_context(Error::OutgoingMessages),
+        QueryMsg::AllOutgoingMessages { start_after, limit } => {
+            query::all_outgoing_messages(deps.storage, start_after, limit)
+                .change_context(Error::OutgoingMessages)
+        }
+        QueryMsg::IncomingMessageStatuses(msgs) => {
+            let config =
+                state::load_config(deps.storage).change_context(Error::IncomingMessageStatuses)?;
+            let verifier = client::ContractClient::new(deps.querier, &config.verifier).into();
+
+            query::incoming_message_statuses(deps.storage, &verifier, msgs)
+                .change_context(Error::IncomingMessageStatuses)
+        }
     }?
     .then(Ok)
 }
@@ -123,11 +205,12 @@ pub fn query(
 #[cfg(test)]
 mod test {
     use assert_ok::assert_ok;
+    use axelar_wasm_std::permission_control::{self, Permission};
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-    use cosmwasm_std::Empty;
 
     use crate::contract::{instantiate, migrate, CONTRACT_NAME, CONTRACT_VERSION};
-    use crate::msg::InstantiateMsg;
+    use crate::msg::{InstantiateMsg, MigrateMsg};
+    use crate::state;
 
     #[test]
     fn migrate_sets_contract_version() {
@@ -138,6 +221,9 @@ mod test {
         let instantiate_msg = InstantiateMsg {
             verifier_address: api.addr_make("verifier").to_string(),
             router_address: api.addr_make("router").to_string(),
+            governance_address: api.addr_make("governance").to_string(),
+            message_expiry: None,
+            axelar_chain_name: None,
         };
 
         assert_ok!(instantiate(
@@ -147,10 +233,67 @@ mod test {
             instantiate_msg
         ));
 
-        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+        let new_governance = api.addr_make("new_governance");
+        migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                governance_address: Some(new_governance.to_string()),
+                axelar_chain_name: Some("axelar".parse().unwrap()),
+            },
+        )
+        .unwrap();
 
         let contract_version = cw2::get_contract_version(deps.as_mut().storage).unwrap();
         assert_eq!(contract_version.contract, CONTRACT_NAME);
         assert_eq!(contract_version.version, CONTRACT_VERSION);
+        assert!(
+            permission_control::sender_role(deps.as_ref().storage, &new_governance)
+                .unwrap()
+                .contains(Permission::Governance)
+        );
+        assert_eq!(
+            state::load_config(deps.as_ref().storage)
+                .unwrap()
+                .axelar_chain_name,
+            Some("axelar".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn migrate_without_parameters_keeps_state() {
+        let mut deps = mock_dependencies();
+        let api = deps.api;
+        let governance = api.addr_make("governance");
+        let instantiate_msg = InstantiateMsg {
+            verifier_address: api.addr_make("verifier").to_string(),
+            router_address: api.addr_make("router").to_string(),
+            governance_address: governance.to_string(),
+            message_expiry: None,
+            axelar_chain_name: None,
+        };
+        assert_ok!(instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("sender"), &[]),
+            instantiate_msg
+        ));
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.1.0").unwrap();
+
+        assert!(state::are_all_routed_incoming_messages_tracked(deps.as_ref().storage).unwrap());
+        assert_ok!(migrate(deps.as_mut(), mock_env(), MigrateMsg::default()));
+        assert!(!state::are_all_routed_incoming_messages_tracked(deps.as_ref().storage).unwrap());
+
+        assert!(
+            permission_control::sender_role(deps.as_ref().storage, &governance)
+                .unwrap()
+                .contains(Permission::Governance)
+        );
+        assert_eq!(
+            state::load_config(deps.as_ref().storage)
+                .unwrap()
+                .axelar_chain_name,
+            None
+        );
     }
 }
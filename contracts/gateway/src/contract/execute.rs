@@ -1,9 +1,10 @@
-use axelar_wasm_std::{FnExt, VerificationStatus};
-use cosmwasm_std::{CosmosMsg, Event, Response, Storage};
-use error_stack::{Result, ResultExt};
+use axelar_wasm_std::permission_control::{self, Permission};
+use axelar_wasm_std::{nonempty, FnExt, VerificationStatus};
+use cosmwasm_std::{Addr, CosmosMsg, Event, Response, Storage};
+use error_stack::{bail, Result, ResultExt};
 use itertools::Itertools;
 use router_api::client::Router;
-use router_api::Message;
+use router_api::{ChainName, CrossChainId, Message};
 use voting_verifier::msg::MessageStatus;
 
 use crate::contract::Error;
@@ -15,45 +16,119 @@ pub fn verify_messages(
     msgs: Vec<Message>,
 ) -> Result<Response, Error> {
     apply(verifier, msgs, |msgs_by_status| {
-        verify(verifier, msgs_by_status)
+        Ok(verify(verifier, msgs_by_status))
     })
 }
 
 pub fn route_incoming_messages(
+    store: &mut dyn Storage,
     verifier: &voting_verifier::Client,
     router: &Router,
     msgs: Vec<Message>,
 ) -> Result<Response, Error> {
     apply(verifier, msgs, |msgs_by_status| {
-        route(router, msgs_by_status)
+        mark_routed(store, &msgs_by_status)?;
+        Ok(route(router, msgs_by_status))
     })
 }
 
 // because the messages came from the router, we can assume they are already verified
 pub fn route_outgoing_messages(
     store: &mut dyn Storage,
+    block_height: u64,
+    message_expiry: Option<nonempty::Uint64>,
     verified: Vec<Message>,
 ) -> Result<Response, Error> {
     let msgs = check_for_duplicates(verified)?;
+    let expires_at = message_expiry.map(|blocks| block_height.saturating_add(blocks.into()));
 
+    let mut expiry_events = vec![];
     for msg in msgs.iter() {
-        state::save_outgoing_message(store, &msg.cc_id, msg)
+        let is_new = state::save_outgoing_message(store, &msg.cc_id, msg)
             .change_context(Error::SaveOutgoingMessage)?;
+
+        // messages keep the expiry they got when they were first routed
+        if let Some(expires_at) = expires_at.filter(|_| is_new) {
+            state::save_outgoing_message_expiry(store, &msg.cc_id, expires_at)
+                .change_context(Error::SaveOutgoingMessage)?;
+            expiry_events.push(GatewayEvent::ExpirySet {
+                msg: msg.clone(),
+                expires_at,
+            });
+        }
     }
 
-    Ok(Response::new().add_events(msgs.into_iter().map(|msg| GatewayEvent::Routing { msg })))
+    Ok(Response::new()
+        .add_events(msgs.into_iter().map(|msg| GatewayEvent::Routing { msg }))
+        .add_events(expiry_events))
+}
+
+pub fn cancel_messages(
+    store: &mut dyn Storage,
+    axelar_chain_name: Option<&ChainName>,
+    sender: &Addr,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response, Error> {
+    let is_governance = permission_control::sender_role(store, sender)
+        .change_context(Error::CancelMessages)?
+        .contains(Permission::Governance);
+
+    let mut events = vec![];
+    for cc_id in cc_ids {
+        let msg =
+            state::load_outgoing_message(store, &cc_id).change_context(Error::CancelMessages)?;
+
+        // only messages that originate on Axelar have a source address that can cancel them
+        let originates_on_axelar =
+            axelar_chain_name.is_some_and(|chain| *chain == msg.cc_id.source_chain);
+        if !is_governance
+            && !(originates_on_axelar && msg.source_address.as_str() == sender.as_str())
+        {
+            bail!(Error::CancelNotAllowed {
+                sender: sender.clone(),
+                cc_id,
+            });
+        }
+
+        if state::cancel_outgoing_message(store, &cc_id).change_context(Error::CancelMessages)? {
+            events.push(GatewayEvent::Cancelled { msg });
+        }
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
+pub fn set_message_expiry(
+    store: &mut dyn Storage,
+    blocks: Option<nonempty::Uint64>,
+) -> Result<Response, Error> {
+    let config = state::load_config(store).change_context(Error::Execute)?;
+    state::save_config(
+        store,
+        &state::Config {
+            message_expiry: blocks,
+            ..config
+        },
+    )
+    .change_context(Error::Execute)?;
+
+    Ok(Response::new().add_event(GatewayEvent::MessageExpirySet {
+        blocks: blocks.map(u64::from),
+    }))
 }
 
 fn apply(
     verifier: &voting_verifier::Client,
     msgs: Vec<Message>,
-    action: impl Fn(Vec<(VerificationStatus, Vec<Message>)>) -> (Option<CosmosMsg>, Vec<Event>),
+    action: impl FnOnce(
+        Vec<(VerificationStatus, Vec<Message>)>,
+    ) -> Result<(Option<CosmosMsg>, Vec<Event>), Error>,
 ) -> Result<Response, Error> {
     check_for_duplicates(msgs)?
         .then(|msgs| verifier.messages_status(msgs))
         .change_context(Error::MessageStatus)?
         .then(group_by_status)
-        .then(action)
+        .then(action)?
         .then(|(msgs, events)| Response::new().add_messages(msgs).add_events(events))
         .then(Ok)
 }
@@ -102,6 +177,17 @@ fn verify(
         .then(|(msgs, events)| (verifier.verify_messages(msgs), events))
 }
 
+fn mark_routed(
+    store: &mut dyn Storage,
+    msgs_by_status: &[(VerificationStatus, Vec<Message>)],
+) -> Result<(), Error> {
+    msgs_by_status
+        .iter()
+        .flat_map(|(status, msgs)| filter_routable_messages(*status, msgs))
+        .try_for_each(|msg| state::mark_incoming_message_routed(store, &msg.cc_id))
+        .change_context(Error::SaveIncomingMessage)
+}
+
 fn route(
     router: &Router,
     msgs_by_status: Vec<(VerificationStatus, Vec<Message>)>,
@@ -198,13 +284,13 @@ mod tests {
 
         let mut deps = mock_dependencies();
 
-        let response = route_outgoing_messages(deps.as_mut().storage, vec![msg.clone()]);
+        let response = route_outgoing_messages(deps.as_mut().storage, 0, None, vec![msg.clone()]);
         assert!(response.is_ok());
 
         // re-route with different payload
         msg.payload_hash = [2; 32];
 
-        let response = route_outgoing_messages(deps.as_mut().storage, vec![msg]);
+        let response = route_outgoing_messages(deps.as_mut().storage, 0, None, vec![msg]);
         assert!(response.is_err_and(|err| err_contains!(
             err,
             state::Error,
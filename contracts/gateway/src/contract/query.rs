@@ -1,10 +1,15 @@
 use axelar_wasm_std::error::extend_err;
 use cosmwasm_std::{to_json_binary, Binary, Storage};
-use error_stack::Result;
-use router_api::{CrossChainId, Message};
+use error_stack::{Result, ResultExt};
+use gateway_api::msg::{IncomingMessageStatus, OutgoingMessageStatus};
+use router_api::{Address, CrossChainId, Message};
 
+use crate::contract::Error;
 use crate::state;
 
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 1000;
+
 pub fn outgoing_messages<'a>(
     storage: &dyn Storage,
     cross_chain_ids: impl Iterator<Item = &'a CrossChainId>,
@@ -16,10 +21,78 @@ pub fn outgoing_messages<'a>(
     Ok(to_json_binary(&msgs).map_err(state::Error::from)?)
 }
 
-fn accumulate_errs(
-    acc: Result<Vec<Message>, state::Error>,
-    msg: std::result::Result<Message, state::Error>,
-) -> Result<Vec<Message>, state::Error> {
+pub fn outgoing_message_statuses<'a>(
+    storage: &dyn Storage,
+    block_height: u64,
+    cross_chain_ids: impl Iterator<Item = &'a CrossChainId>,
+) -> Result<Binary, state::Error> {
+    let statuses: Vec<OutgoingMessageStatus> = cross_chain_ids
+        .map(|id| state::load_outgoing_message_status(storage, id, block_height))
+        .fold(Ok(vec![]), accumulate_errs)?;
+
+    Ok(to_json_binary(&statuses).map_err(state::Error::from)?)
+}
+
+pub fn outgoing_messages_by_destination(
+    storage: &dyn Storage,
+    destination_address: Address,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let msgs = state::load_outgoing_messages_by_destination(
+        storage,
+        &destination_address,
+        start_after.as_ref(),
+        page_limit(limit),
+    )?;
+
+    Ok(to_json_binary(&msgs).map_err(state::Error::from)?)
+}
+
+pub fn all_outgoing_messages(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let msgs = state::load_outgoing_messages(storage, start_after.as_ref(), page_limit(limit))?;
+
+    Ok(to_json_binary(&msgs).map_err(state::Error::from)?)
+}
+
+pub fn incoming_message_statuses(
+    storage: &dyn Storage,
+    verifier: &voting_verifier::Client,
+    msgs: Vec<Message>,
+) -> Result<Binary, Error> {
+    let routing_tracked = state::are_all_routed_incoming_messages_tracked(storage)
+        .change_context(Error::IncomingMessageStatuses)?;
+
+    let statuses: Vec<_> = verifier
+        .messages_status(msgs)
+        .change_context(Error::MessageStatus)?
+        .into_iter()
+        .map(|msg_status| IncomingMessageStatus {
+            routed: match state::is_incoming_message_routed(storage, &msg_status.message.cc_id) {
+                true => Some(true),
+                false if routing_tracked => Some(false),
+                false => None,
+            },
+            message: msg_status.message,
+            verification_status: msg_status.status,
+        })
+        .collect();
+
+    to_json_binary(&statuses).change_context(Error::IncomingMessageStatuses)
+}
+
+fn page_limit(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT)
+}
+
+fn accumulate_errs<T>(
+    acc: Result<Vec<T>, state::Error>,
+    msg: std::result::Result<T, state::Error>,
+) -> Result<Vec<T>, state::Error> {
     match (acc, msg) {
         (Ok(mut msgs), Ok(msg)) => {
             msgs.push(msg);
@@ -34,6 +107,7 @@ fn accumulate_errs(
 mod test {
     use cosmwasm_std::from_json;
     use cosmwasm_std::testing::mock_dependencies;
+    use gateway_api::msg::OutgoingMessageStatus;
     use router_api::{CrossChainId, Message};
 
     use crate::state;
@@ -85,6 +159,65 @@ mod test {
         assert_eq!(res.unwrap_err().current_frames().len(), messages.len() - 1);
     }
 
+    #[test]
+    fn outgoing_message_statuses_reflect_expiry_and_cancellation() {
+        let mut deps = mock_dependencies();
+
+        let messages = generate_messages();
+
+        for message in messages.iter() {
+            state::save_outgoing_message(deps.as_mut().storage, &message.cc_id, message).unwrap();
+        }
+        state::save_outgoing_message_expiry(deps.as_mut().storage, &messages[0].cc_id, 10).unwrap();
+        state::save_outgoing_message_expiry(deps.as_mut().storage, &messages[1].cc_id, 10).unwrap();
+        state::cancel_outgoing_message(deps.as_mut().storage, &messages[1].cc_id).unwrap();
+
+        let ids = messages.iter().map(|msg| &msg.cc_id);
+        let res = super::outgoing_message_statuses(&deps.storage, 9, ids.clone()).unwrap();
+        let statuses: Vec<OutgoingMessageStatus> = from_json(res).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                OutgoingMessageStatus::Pending {
+                    expires_at: Some(10)
+                },
+                OutgoingMessageStatus::Cancelled,
+                OutgoingMessageStatus::Pending { expires_at: None },
+            ]
+        );
+
+        let res = super::outgoing_message_statuses(&deps.storage, 10, ids).unwrap();
+        let statuses: Vec<OutgoingMessageStatus> = from_json(res).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                OutgoingMessageStatus::Expired,
+                OutgoingMessageStatus::Cancelled,
+                OutgoingMessageStatus::Pending { expires_at: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn outgoing_message_statuses_unknown_message_returns_not_found_error() {
+        let deps = mock_dependencies();
+
+        let messages = generate_messages();
+        let ids = messages.iter().map(|msg| &msg.cc_id);
+
+        let res = super::outgoing_message_statuses(&deps.storage, 0, ids);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().current_frames().len(), messages.len());
+    }
+
+    #[test]
+    fn page_limit_has_default_and_maximum() {
+        assert_eq!(super::page_limit(None), super::DEFAULT_LIMIT);
+        assert_eq!(super::page_limit(Some(5)), 5);
+        assert_eq!(super::page_limit(Some(u32::MAX)), super::MAX_LIMIT);
+    }
+
     fn generate_messages() -> Vec<Message> {
         vec![
             Message {
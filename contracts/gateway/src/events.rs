@@ -7,6 +7,9 @@ pub enum GatewayEvent {
     AlreadyRejected { msg: Message },
     Routing { msg: Message },
     UnfitForRouting { msg: Message },
+    ExpirySet { msg: Message, expires_at: u64 },
+    Cancelled { msg: Message },
+    MessageExpirySet { blocks: Option<u64> },
 }
 
 fn make_message_event(event_name: &str, msg: Message) -> Event {
@@ -23,6 +26,14 @@ impl From<GatewayEvent> for Event {
             GatewayEvent::AlreadyRejected { msg } => make_message_event("already_rejected", msg),
             GatewayEvent::Routing { msg } => make_message_event("routing", msg),
             GatewayEvent::UnfitForRouting { msg } => make_message_event("unfit_for_routing", msg),
+            GatewayEvent::ExpirySet { msg, expires_at } => make_message_event("expiry_set", msg)
+                .add_attribute("expires_at", expires_at.to_string()),
+            GatewayEvent::Cancelled { msg } => make_message_event("cancelled", msg),
+            GatewayEvent::MessageExpirySet { blocks } => Event::new("message_expiry_set")
+                .add_attribute(
+                    "blocks",
+                    blocks.map_or_else(|| "none".to_string(), |blocks| blocks.to_string()),
+                ),
         }
     }
 }
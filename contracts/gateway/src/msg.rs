@@ -1,4 +1,6 @@
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::cw_serde;
+use router_api::ChainName;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -6,6 +8,23 @@ pub struct InstantiateMsg {
     pub verifier_address: String,
     /// Address of the router contract on axelar.
     pub router_address: String,
+    /// Address of the governance account that can cancel outgoing messages and change the message expiry.
+    pub governance_address: String,
+    /// Number of blocks after which routed outgoing messages expire. None means messages never expire.
+    pub message_expiry: Option<nonempty::Uint64>,
+    /// Name of the Axelar chain. Messages from this chain can be cancelled by their source address.
+    /// None means only governance can cancel messages.
+    pub axelar_chain_name: Option<ChainName>,
+}
+
+/// Both fields are optional, so the migration can be run without any parameters.
+/// Without a governance address, cancelling messages and changing the message expiry stay unavailable
+/// until a governance address is set by a later migration.
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateMsg {
+    pub governance_address: Option<String>,
+    pub axelar_chain_name: Option<ChainName>,
 }
 
 // these messages are extracted into a separate package to avoid circular dependencies
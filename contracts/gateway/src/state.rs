@@ -1,17 +1,39 @@
-use axelar_wasm_std::IntoContractError;
+use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdError, Storage};
-use cw_storage_plus::{Item, Map};
-use router_api::{CrossChainId, Message};
+use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+use gateway_api::msg::OutgoingMessageStatus;
+use router_api::{Address, ChainName, CrossChainId, Message};
 
 #[cw_serde]
 pub struct Config {
     pub verifier: Addr,
     pub router: Addr,
+    /// number of blocks after which newly routed outgoing messages expire
+    pub message_expiry: Option<nonempty::Uint64>,
+    /// messages from this chain originate on Axelar, so their source address can cancel them
+    #[serde(default)]
+    pub axelar_chain_name: Option<ChainName>,
 }
 
 const CONFIG: Item<Config> = Item::new("config");
 const OUTGOING_MESSAGES: Map<&CrossChainId, Message> = Map::new("outgoing_messages");
+/// block heights at which outgoing messages expire, only set if expiry was configured when they were routed
+const OUTGOING_MESSAGE_EXPIRIES: Map<&CrossChainId, u64> = Map::new("outgoing_message_expiries");
+const CANCELLED_MESSAGES: Map<&CrossChainId, ()> = Map::new("cancelled_messages");
+
+// secondary indexes to list outgoing messages in the order they were routed to the gateway
+const OUTGOING_MESSAGE_COUNTER: Item<u64> = Item::new("outgoing_message_counter");
+const OUTGOING_MESSAGE_SEQUENCES: Map<&CrossChainId, u64> = Map::new("outgoing_message_sequences");
+const OUTGOING_MESSAGES_BY_SEQUENCE: Map<u64, CrossChainId> =
+    Map::new("outgoing_messages_by_sequence");
+const OUTGOING_MESSAGES_BY_DESTINATION: Map<(&str, u64), CrossChainId> =
+    Map::new("outgoing_messages_by_destination");
+
+const ROUTED_INCOMING_MESSAGES: Map<&CrossChainId, ()> = Map::new("routed_incoming_messages");
+/// set if the gateway has been migrated from a version that didn't keep track of routed incoming messages
+const UNTRACKED_ROUTED_INCOMING_MESSAGES: Item<()> =
+    Item::new("untracked_routed_incoming_messages");
 
 #[derive(thiserror::Error, Debug, IntoContractError)]
 pub enum Error {
@@ -46,11 +68,12 @@ pub fn load_outgoing_message(
         .ok_or_else(|| Error::MessageNotFound(cc_id.clone()))
 }
 
+/// Returns true if the message is new, false if an identical message has been saved before
 pub fn save_outgoing_message(
     storage: &mut dyn Storage,
     cc_id: &CrossChainId,
     msg: &Message,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     let existing = OUTGOING_MESSAGES
         .may_load(storage, cc_id)
         .map_err(Error::from)?;
@@ -59,10 +82,166 @@ pub fn save_outgoing_message(
         Some(existing) if msg.hash() != existing.hash() => {
             Err(Error::MessageMismatch(msg.cc_id.clone()))
         }
-        Some(_) => Ok(()), // new message is identical, no need to store it
-        None => Ok(OUTGOING_MESSAGES
-            .save(storage, cc_id, msg)
-            .map_err(Error::from)?),
+        Some(_) => Ok(false), // new message is identical, no need to store it
+        None => {
+            OUTGOING_MESSAGES
+                .save(storage, cc_id, msg)
+                .map_err(Error::from)?;
+            index_outgoing_message(storage, msg).map_err(Error::from)?;
+            Ok(true)
+        }
+    }
+}
+
+fn index_outgoing_message(storage: &mut dyn Storage, msg: &Message) -> StdResult<()> {
+    let sequence = OUTGOING_MESSAGE_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default();
+
+    OUTGOING_MESSAGE_SEQUENCES.save(storage, &msg.cc_id, &sequence)?;
+    OUTGOING_MESSAGES_BY_SEQUENCE.save(storage, sequence, &msg.cc_id)?;
+    OUTGOING_MESSAGES_BY_DESTINATION.save(
+        storage,
+        (msg.destination_address.as_str(), sequence),
+        &msg.cc_id,
+    )?;
+
+    OUTGOING_MESSAGE_COUNTER.save(storage, &sequence.saturating_add(1))
+}
+
+fn load_outgoing_message_sequence(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<u64, Error> {
+    OUTGOING_MESSAGE_SEQUENCES
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .ok_or_else(|| Error::MessageNotFound(cc_id.clone()))
+}
+
+/// Messages that were routed before the secondary indexes were introduced are not included
+pub fn load_outgoing_messages(
+    storage: &dyn Storage,
+    start_after: Option<&CrossChainId>,
+    limit: u32,
+) -> Result<Vec<Message>, Error> {
+    let start_after = start_after
+        .map(|cc_id| load_outgoing_message_sequence(storage, cc_id))
+        .transpose()?;
+
+    OUTGOING_MESSAGES_BY_SEQUENCE
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|item| {
+            item.map_err(Error::from)
+                .and_then(|(_, cc_id)| load_outgoing_message(storage, &cc_id))
+        })
+        .collect()
+}
+
+/// Messages that were routed before the secondary indexes were introduced are not included
+pub fn load_outgoing_messages_by_destination(
+    storage: &dyn Storage,
+    destination_address: &Address,
+    start_after: Option<&CrossChainId>,
+    limit: u32,
+) -> Result<Vec<Message>, Error> {
+    let start_after = start_after
+        .map(|cc_id| load_outgoing_message_sequence(storage, cc_id))
+        .transpose()?;
+
+    OUTGOING_MESSAGES_BY_DESTINATION
+        .prefix(destination_address.as_str())
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|item| {
+            item.map_err(Error::from)
+                .and_then(|(_, cc_id)| load_outgoing_message(storage, &cc_id))
+        })
+        .collect()
+}
+
+pub fn mark_incoming_message_routed(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<(), Error> {
+    ROUTED_INCOMING_MESSAGES
+        .save(storage, cc_id, &())
+        .map_err(Error::from)
+}
+
+pub fn is_incoming_message_routed(storage: &dyn Storage, cc_id: &CrossChainId) -> bool {
+    ROUTED_INCOMING_MESSAGES.has(storage, cc_id)
+}
+
+/// Records that incoming messages might have been routed without being tracked
+pub fn mark_routed_incoming_messages_untracked(storage: &mut dyn Storage) -> Result<(), Error> {
+    UNTRACKED_ROUTED_INCOMING_MESSAGES
+        .save(storage, &())
+        .map_err(Error::from)
+}
+
+pub fn are_all_routed_incoming_messages_tracked(storage: &dyn Storage) -> Result<bool, Error> {
+    UNTRACKED_ROUTED_INCOMING_MESSAGES
+        .may_load(storage)
+        .map(|untracked| untracked.is_none())
+        .map_err(Error::from)
+}
+
+pub fn save_outgoing_message_expiry(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    expires_at: u64,
+) -> Result<(), Error> {
+    OUTGOING_MESSAGE_EXPIRIES
+        .save(storage, cc_id, &expires_at)
+        .map_err(Error::from)
+}
+
+/// Returns true if the message was newly cancelled, false if it had been cancelled before
+pub fn cancel_outgoing_message(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<bool, Error> {
+    if CANCELLED_MESSAGES.has(storage, cc_id) {
+        return Ok(false);
+    }
+
+    CANCELLED_MESSAGES
+        .save(storage, cc_id, &())
+        .map_err(Error::from)?;
+    Ok(true)
+}
+
+pub fn load_outgoing_message_status(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> Result<OutgoingMessageStatus, Error> {
+    if !OUTGOING_MESSAGES.has(storage, cc_id) {
+        return Err(Error::MessageNotFound(cc_id.clone()));
+    }
+
+    if CANCELLED_MESSAGES.has(storage, cc_id) {
+        return Ok(OutgoingMessageStatus::Cancelled);
+    }
+
+    match OUTGOING_MESSAGE_EXPIRIES
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+    {
+        Some(expires_at) if expires_at <= block_height => Ok(OutgoingMessageStatus::Expired),
+        expires_at => Ok(OutgoingMessageStatus::Pending { expires_at }),
     }
 }
 
@@ -71,7 +250,7 @@ mod test {
     use cosmwasm_std::testing::mock_dependencies;
     use router_api::{CrossChainId, Message};
 
-    use crate::state::OUTGOING_MESSAGES;
+    use crate::state::{self, OUTGOING_MESSAGES};
 
     #[test]
     fn outgoing_messages_storage() {
@@ -113,4 +292,69 @@ mod test {
             None
         );
     }
+
+    #[test]
+    fn outgoing_messages_are_listed_in_insertion_order() {
+        let mut deps = mock_dependencies();
+
+        let ids = ["f", "e", "d", "c", "b", "a"];
+        let destinations = ["destination-address-0", "destination-address-1"];
+        let messages: Vec<_> = (0..6u8)
+            .zip(ids)
+            .zip(destinations.into_iter().cycle())
+            .map(|((i, id), destination)| Message {
+                cc_id: CrossChainId::new("chain", id).unwrap(),
+                source_address: "source-address".parse().unwrap(),
+                destination_chain: "destination".parse().unwrap(),
+                destination_address: destination.parse().unwrap(),
+                payload_hash: [i; 32],
+            })
+            .collect();
+
+        for message in messages.iter() {
+            assert!(
+                state::save_outgoing_message(deps.as_mut().storage, &message.cc_id, message)
+                    .unwrap()
+            );
+        }
+        // saving an identical message again must not index it twice
+        assert!(!state::save_outgoing_message(
+            deps.as_mut().storage,
+            &messages[0].cc_id,
+            &messages[0]
+        )
+        .unwrap());
+
+        assert_eq!(
+            state::load_outgoing_messages(&deps.storage, None, u32::MAX).unwrap(),
+            messages
+        );
+        assert_eq!(
+            state::load_outgoing_messages(&deps.storage, Some(&messages[1].cc_id), 3).unwrap(),
+            messages[2..5].to_vec()
+        );
+
+        let destination = messages[1].destination_address.clone();
+        assert_eq!(
+            state::load_outgoing_messages_by_destination(&deps.storage, &destination, None, 2)
+                .unwrap(),
+            vec![messages[1].clone(), messages[3].clone()]
+        );
+        assert_eq!(
+            state::load_outgoing_messages_by_destination(
+                &deps.storage,
+                &destination,
+                Some(&messages[3].cc_id),
+                u32::MAX
+            )
+            .unwrap(),
+            vec![messages[5].clone()]
+        );
+
+        let unknown = CrossChainId::new("chain", "unknown").unwrap();
+        assert!(matches!(
+            state::load_outgoing_messages(&deps.storage, Some(&unknown), u32::MAX),
+            Err(state::Error::MessageNotFound(_))
+        ));
+    }
 }
@@ -13,7 +13,7 @@ use cosmwasm_std::{
 };
 use gateway::contract::*;
 use gateway::msg::InstantiateMsg;
-use gateway_api::msg::{ExecuteMsg, QueryMsg};
+use gateway_api::msg::{ExecuteMsg, IncomingMessageStatus, OutgoingMessageStatus, QueryMsg};
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
 use router_api::{CrossChainId, Message};
@@ -22,6 +22,8 @@ use voting_verifier::msg::MessageStatus;
 
 const ROUTER: &str = "router";
 const VERIFIER: &str = "verifier";
+const GOVERNANCE: &str = "governance";
+const AXELAR: &str = "axelar";
 
 #[test]
 fn instantiate_works() {
@@ -37,6 +39,9 @@ fn instantiate_works() {
         InstantiateMsg {
             verifier_address: verifier_address.into_string(),
             router_address: router_address.into_string(),
+            governance_address: api.addr_make(GOVERNANCE).into_string(),
+            message_expiry: None,
+            axelar_chain_name: Some(AXELAR.parse().unwrap()),
         },
     );
 
@@ -159,6 +164,105 @@ fn successful_route_outgoing() {
     goldie::assert_json!(responses);
 }
 
+#[test]
+fn incoming_message_statuses_show_routed_messages() {
+    let all_messages = generate_msgs_with_all_statuses(2);
+    let status_by_msg = map_status_by_msg(all_messages.clone());
+    let handler = correctly_working_verifier_handler(status_by_msg.clone());
+    let msgs = all_messages.into_values().flatten().collect::<Vec<_>>();
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+    update_query_handler(&mut deps.querier, handler);
+
+    let query_statuses = |deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>| {
+        from_json::<Vec<IncomingMessageStatus>>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::IncomingMessageStatuses(msgs.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    assert!(query_statuses(&deps)
+        .iter()
+        .all(|status| status.routed == Some(false)));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+
+    let statuses = query_statuses(&deps);
+    assert_eq!(statuses.len(), msgs.len());
+    for status in statuses {
+        assert_eq!(status.verification_status, status_by_msg[&status.message]);
+        assert_eq!(
+            status.routed,
+            Some(status.verification_status == VerificationStatus::SucceededOnSourceChain)
+        );
+    }
+}
+
+#[test]
+fn outgoing_messages_are_paginated_by_destination() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+
+    let mut msgs = generate_msgs("paginated", 6);
+    msgs.iter_mut()
+        .skip(1)
+        .step_by(2)
+        .for_each(|msg| msg.destination_address = "other".parse().unwrap());
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+
+    let query_page = |msg: QueryMsg| -> Vec<Message> {
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        query_page(QueryMsg::AllOutgoingMessages {
+            start_after: None,
+            limit: None
+        }),
+        msgs
+    );
+    assert_eq!(
+        query_page(QueryMsg::AllOutgoingMessages {
+            start_after: Some(msgs[3].cc_id.clone()),
+            limit: Some(1)
+        }),
+        vec![msgs[4].clone()]
+    );
+
+    let first_page = query_page(QueryMsg::OutgoingMessagesByDestination {
+        destination_address: "other".parse().unwrap(),
+        start_after: None,
+        limit: Some(2),
+    });
+    assert_eq!(first_page, vec![msgs[1].clone(), msgs[3].clone()]);
+
+    let second_page = query_page(QueryMsg::OutgoingMessagesByDestination {
+        destination_address: "other".parse().unwrap(),
+        start_after: first_page.last().map(|msg| msg.cc_id.clone()),
+        limit: Some(2),
+    });
+    assert_eq!(second_page, vec![msgs[5].clone()]);
+}
+
 #[test]
 fn verify_with_faulty_verifier_fails() {
     // if the mock querier is not overwritten, it will return an error
@@ -280,6 +384,163 @@ fn reject_reroute_outgoing_message_with_different_contents() {
     )));
 }
 
+#[test]
+#[allow(clippy::arithmetic_side_effects)]
+fn outgoing_messages_expire_after_configured_blocks() {
+    let msgs = generate_msgs("expiry", 2);
+
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+    let governance = deps.api.addr_make(GOVERNANCE);
+
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::SetMessageExpiry {
+            blocks: Some(10u64.try_into().unwrap())
+        },
+    )
+    .is_err());
+
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&governance, &[]),
+        ExecuteMsg::SetMessageExpiry {
+            blocks: Some(10u64.try_into().unwrap())
+        },
+    )
+    .is_ok_and(|response| response.events[0].ty == "message_expiry_set"));
+
+    let mut env = mock_env();
+    let routed_at = env.block.height;
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+    assert_eq!(
+        response
+            .events
+            .iter()
+            .filter(|event| event.ty == "expiry_set")
+            .count(),
+        msgs.len()
+    );
+
+    let query_msg =
+        QueryMsg::OutgoingMessageStatuses(msgs.iter().map(|msg| msg.cc_id.clone()).collect());
+
+    env.block.height = routed_at + 9;
+    let statuses: Vec<OutgoingMessageStatus> =
+        from_json(query(deps.as_ref(), env.clone(), query_msg.clone()).unwrap()).unwrap();
+    assert!(statuses.iter().all(|status| *status
+        == OutgoingMessageStatus::Pending {
+            expires_at: Some(routed_at + 10)
+        }));
+
+    // re-routing the same messages later must not extend their expiry
+    env.block.height = routed_at + 10;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+
+    let statuses: Vec<OutgoingMessageStatus> =
+        from_json(query(deps.as_ref(), env, query_msg).unwrap()).unwrap();
+    assert!(statuses
+        .iter()
+        .all(|status| *status == OutgoingMessageStatus::Expired));
+}
+
+#[test]
+fn cancel_outgoing_messages() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+    let governance = deps.api.addr_make(GOVERNANCE);
+    let source = deps.api.addr_make("source");
+
+    // the first message claims the same source address, but doesn't originate on Axelar
+    let mut msgs = generate_msgs("cancel", 2);
+    msgs[0].source_address = source.to_string().parse().unwrap();
+    msgs[1].source_address = source.to_string().parse().unwrap();
+    msgs[1].cc_id = CrossChainId::new(AXELAR, "cancel1").unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+
+    // only governance or the source address of a message from Axelar can cancel it
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&source, &[]),
+        ExecuteMsg::CancelMessages(vec![msgs[0].cc_id.clone()]),
+    );
+    assert!(response.is_err_and(|err| err_contains!(
+        err.report,
+        Error,
+        Error::CancelNotAllowed { .. }
+    )));
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&source, &[]),
+        ExecuteMsg::CancelMessages(vec![msgs[1].cc_id.clone()]),
+    )
+    .unwrap();
+    assert_eq!(response.events.len(), 1);
+    assert_eq!(response.events[0].ty, "cancelled");
+
+    // cancelling is idempotent and only emits events for newly cancelled messages
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&governance, &[]),
+        ExecuteMsg::CancelMessages(msgs.iter().map(|msg| msg.cc_id.clone()).collect()),
+    )
+    .unwrap();
+    assert_eq!(response.events.len(), 1);
+
+    let statuses: Vec<OutgoingMessageStatus> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::OutgoingMessageStatuses(msgs.iter().map(|msg| msg.cc_id.clone()).collect()),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(statuses
+        .iter()
+        .all(|status| *status == OutgoingMessageStatus::Cancelled));
+}
+
+#[test]
+fn cancel_unknown_message_fails() {
+    let mut deps = instantiate_contract();
+    let governance = deps.api.addr_make(GOVERNANCE);
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&governance, &[]),
+        ExecuteMsg::CancelMessages(vec![CrossChainId::new("mock-chain", "unknown").unwrap()]),
+    );
+    assert!(response.is_err_and(|err| err_contains!(err.report, Error, Error::CancelMessages)));
+}
+
 fn test_cases_for_correct_verifier() -> (
     Vec<Vec<Message>>,
     impl Fn(voting_verifier::msg::QueryMsg) -> Result<Vec<MessageStatus>, ContractError> + Clone,
@@ -444,6 +705,9 @@ fn instantiate_contract() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
         InstantiateMsg {
             verifier_address: verifier_address.into_string(),
             router_address: router_address.into_string(),
+            governance_address: api.addr_make(GOVERNANCE).into_string(),
+            message_expiry: None,
+            axelar_chain_name: Some(AXELAR.parse().unwrap()),
         }
         .clone(),
     );
@@ -1,5 +1,6 @@
 use alloy_primitives::{FixedBytes, U256};
-use alloy_sol_types::{sol, SolValue};
+use alloy_sol_types::abi::TokenSeq;
+use alloy_sol_types::{sol, SolType, SolValue};
 use axelar_wasm_std::{nonempty, FnExt, IntoContractError};
 use cosmwasm_std::{HexBinary, Uint256};
 use error_stack::{bail, ensure, report, Report, ResultExt};
@@ -71,12 +72,84 @@ sol! {
     }
 }
 
+/// The lowest byte of the `messageType` word holds the message type id, the byte above it the encoding version.
+/// Version 0 payloads must match the message schema exactly. From version 1 onwards, newer edge contracts can add
+/// optional fields after the known fields, i.e. `abi.encode(knownFields..., newFields...)`. Decoders that don't know them
+/// only read the head slots of the known fields, so the additional head slots and tails are ignored.
+/// The ITS hub always encodes messages with version 0, so edge contracts that don't support versioning can still decode them.
+const STRICT_VERSION: u8 = 0;
+
+const WORD_SIZE: usize = 32;
+
+/// Encoding of a field in its head slot, either the value itself or the offset of its tail.
+enum HeadSlot {
+    Static,
+    Dynamic,
+}
+
+/// Head slots of the known fields of a message, in field order.
+trait HeadSlots {
+    const HEAD_SLOTS: &'static [HeadSlot];
+}
+
+impl HeadSlots for InterchainTransfer {
+    const HEAD_SLOTS: &'static [HeadSlot] = &[
+        HeadSlot::Static,
+        HeadSlot::Static,
+        HeadSlot::Dynamic,
+        HeadSlot::Dynamic,
+        HeadSlot::Static,
+        HeadSlot::Dynamic,
+    ];
+}
+
+impl HeadSlots for DeployInterchainToken {
+    const HEAD_SLOTS: &'static [HeadSlot] = &[
+        HeadSlot::Static,
+        HeadSlot::Static,
+        HeadSlot::Dynamic,
+        HeadSlot::Dynamic,
+        HeadSlot::Static,
+        HeadSlot::Dynamic,
+    ];
+}
+
+impl HeadSlots for SendToHub {
+    const HEAD_SLOTS: &'static [HeadSlot] =
+        &[HeadSlot::Static, HeadSlot::Dynamic, HeadSlot::Dynamic];
+}
+
+impl HeadSlots for ReceiveFromHub {
+    const HEAD_SLOTS: &'static [HeadSlot] =
+        &[HeadSlot::Static, HeadSlot::Dynamic, HeadSlot::Dynamic];
+}
+
+impl HeadSlots for RegisterTokenMetadata {
+    const HEAD_SLOTS: &'static [HeadSlot] =
+        &[HeadSlot::Static, HeadSlot::Dynamic, HeadSlot::Static];
+}
+
+impl HeadSlots for LinkToken {
+    const HEAD_SLOTS: &'static [HeadSlot] = &[
+        HeadSlot::Static,
+        HeadSlot::Static,
+        HeadSlot::Static,
+        HeadSlot::Dynamic,
+        HeadSlot::Dynamic,
+        HeadSlot::Dynamic,
+    ];
+}
+
 #[derive(thiserror::Error, Debug, IntoContractError)]
 pub enum Error {
     #[error("insufficient message length")]
     InsufficientMessageLength,
     #[error("invalid message type")]
     InvalidMessageType,
+    #[error("non-canonical encoding of the message fields")]
+    NonCanonicalEncoding,
+    #[error("invalid offset of the message field at head slot {0}")]
+    InvalidOffset(usize),
     #[error("invalid chain name")]
     InvalidChainName,
     #[error(transparent)]
@@ -138,15 +211,11 @@ impl Message {
     }
 
     pub fn abi_decode(payload: &[u8]) -> Result<Self, Report<Error>> {
-        ensure!(payload.len() >= 32, Error::InsufficientMessageLength);
-
-        let message_type = MessageType::abi_decode(&payload[0..32], true)
-            .change_context(Error::InvalidMessageType)?;
+        let (message_type, version) = decode_message_type(payload)?;
 
         let message = match message_type {
             MessageType::InterchainTransfer => {
-                let decoded = InterchainTransfer::abi_decode_params(payload, true)
-                    .map_err(Error::AbiDecodeFailed)?;
+                let decoded = decode_params::<InterchainTransfer>(payload, version)?;
 
                 primitives::InterchainTransfer {
                     token_id: TokenId::new(decoded.tokenId.into()),
@@ -164,8 +233,7 @@ impl Message {
                 .into()
             }
             MessageType::DeployInterchainToken => {
-                let decoded = DeployInterchainToken::abi_decode_params(payload, true)
-                    .map_err(Error::AbiDecodeFailed)?;
+                let decoded = decode_params::<DeployInterchainToken>(payload, version)?;
 
                 primitives::DeployInterchainToken {
                     token_id: TokenId::new(decoded.tokenId.into()),
@@ -184,7 +252,7 @@ impl Message {
                     destinationToken,
                     params,
                     ..
-                } = LinkToken::abi_decode_params(payload, true).map_err(Error::AbiDecodeFailed)?;
+                } = decode_params::<LinkToken>(payload, version)?;
 
                 primitives::LinkToken {
                     token_id: TokenId::new(tokenId.into()),
@@ -243,15 +311,11 @@ impl HubMessage {
     }
 
     pub fn abi_decode(payload: &[u8]) -> Result<Self, Report<Error>> {
-        ensure!(payload.len() >= 32, Error::InsufficientMessageLength);
-
-        let message_type = MessageType::abi_decode(&payload[0..32], true)
-            .change_context(Error::InvalidMessageType)?;
+        let (message_type, version) = decode_message_type(payload)?;
 
         let hub_message = match message_type {
             MessageType::SendToHub => {
-                let decoded =
-                    SendToHub::abi_decode_params(payload, true).map_err(Error::AbiDecodeFailed)?;
+                let decoded = decode_params::<SendToHub>(payload, version)?;
 
                 HubMessage::SendToHub {
                     destination_chain: ChainNameRaw::try_from(decoded.destination_chain)
@@ -260,8 +324,7 @@ impl HubMessage {
                 }
             }
             MessageType::ReceiveFromHub => {
-                let decoded = ReceiveFromHub::abi_decode_params(payload, true)
-                    .map_err(Error::AbiDecodeFailed)?;
+                let decoded = decode_params::<ReceiveFromHub>(payload, version)?;
 
                 HubMessage::ReceiveFromHub {
                     source_chain: ChainNameRaw::try_from(decoded.source_chain)
@@ -274,8 +337,7 @@ impl HubMessage {
                     tokenAddress,
                     decimals,
                     ..
-                } = RegisterTokenMetadata::abi_decode_params(payload, true)
-                    .map_err(Error::AbiDecodeFailed)?;
+                } = decode_params::<RegisterTokenMetadata>(payload, version)?;
                 HubMessage::RegisterTokenMetadata(primitives::RegisterTokenMetadata {
                     decimals,
                     token_address: Vec::<u8>::from(tokenAddress)
@@ -290,6 +352,113 @@ impl HubMessage {
     }
 }
 
+fn decode_message_type(payload: &[u8]) -> Result<(MessageType, u8), Report<Error>> {
+    ensure!(payload.len() >= 32, Error::InsufficientMessageLength);
+
+    let [reserved @ .., version, message_type] =
+        <[u8; 32]>::try_from(&payload[0..32]).expect("payload must contain at least 32 bytes");
+    ensure!(
+        reserved.iter().all(|byte| *byte == 0),
+        Error::InvalidMessageType
+    );
+
+    let message_type =
+        MessageType::try_from(message_type).change_context(Error::InvalidMessageType)?;
+
+    Ok((message_type, version))
+}
+
+/// Decodes the known fields of `T` from their head slots. Head slots and tails of fields added by newer versions
+/// of the encoding are ignored, but the known fields must still be encoded canonically.
+fn decode_params<T>(payload: &[u8], version: u8) -> Result<T, Error>
+where
+    T: SolType<RustType = T> + SolValue<SolType = T> + HeadSlots,
+    for<'a> <T as SolType>::Token<'a>: TokenSeq<'a>,
+{
+    if version == STRICT_VERSION {
+        return <T as SolType>::abi_decode_params(payload, true).map_err(Error::AbiDecodeFailed);
+    }
+
+    let decoded = <T as SolType>::abi_decode_params(payload, false)?;
+    ensure_canonical_head_slots(payload, &decoded.abi_encode_params(), T::HEAD_SLOTS)?;
+
+    Ok(decoded)
+}
+
+/// Compares the known fields of the payload with their canonical encoding. Static fields must match their head slot,
+/// and the tails of dynamic fields must be word aligned, in field order after the known head slots, and match their
+/// canonical tails. Additional head slots shift the offsets of the tails, so only the tails themselves are compared.
+fn ensure_canonical_head_slots(
+    payload: &[u8],
+    canonical: &[u8],
+    head_slots: &[HeadSlot],
+) -> Result<(), Error> {
+    let head_len = head_slots
+        .len()
+        .checked_mul(WORD_SIZE)
+        .ok_or(Error::NonCanonicalEncoding)?;
+    let canonical_offsets: Vec<_> = head_slots
+        .iter()
+        .enumerate()
+        .filter(|(_, head_slot)| matches!(head_slot, HeadSlot::Dynamic))
+        .map(|(slot, _)| offset(canonical, slot).ok_or(Error::NonCanonicalEncoding))
+        .collect::<Result<_, _>>()?;
+    let canonical_tail_ends = canonical_offsets
+        .iter()
+        .skip(1)
+        .copied()
+        .chain(std::iter::once(canonical.len()));
+    let mut canonical_tails = canonical_offsets.iter().zip(canonical_tail_ends);
+    let mut min_offset = head_len;
+
+    for (slot, head_slot) in head_slots.iter().enumerate() {
+        match head_slot {
+            HeadSlot::Static => {
+                ensure!(
+                    word(payload, slot) == word(canonical, slot),
+                    Error::NonCanonicalEncoding
+                );
+            }
+            HeadSlot::Dynamic => {
+                let (canonical_start, canonical_end) =
+                    canonical_tails.next().ok_or(Error::NonCanonicalEncoding)?;
+                let tail = canonical
+                    .get(*canonical_start..canonical_end)
+                    .ok_or(Error::NonCanonicalEncoding)?;
+
+                let start = offset(payload, slot)
+                    .filter(|start| *start >= min_offset && start.checked_rem(WORD_SIZE) == Some(0))
+                    .ok_or(Error::InvalidOffset(slot))?;
+                let end = start
+                    .checked_add(tail.len())
+                    .ok_or(Error::InvalidOffset(slot))?;
+                ensure!(
+                    payload.get(start..end) == Some(tail),
+                    Error::NonCanonicalEncoding
+                );
+
+                min_offset = end;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn word(data: &[u8], slot: usize) -> Option<&[u8]> {
+    let start = slot.checked_mul(WORD_SIZE)?;
+    data.get(start..start.checked_add(WORD_SIZE)?)
+}
+
+fn offset(data: &[u8], slot: usize) -> Option<usize> {
+    let (high, low) = word(data, slot)?.split_last_chunk::<8>()?;
+    if high.iter().any(|byte| *byte != 0) {
+        return None;
+    }
+
+    usize::try_from(u64::from_be_bytes(*low)).ok()
+}
+
 impl From<MessageType> for U256 {
     fn from(value: MessageType) -> Self {
         U256::from(value as u8)
@@ -313,7 +482,7 @@ fn from_vec(value: std::vec::Vec<u8>) -> Result<Option<nonempty::HexBinary>, Err
 mod tests {
     use std::str::FromStr;
 
-    use alloy_primitives::{FixedBytes, U256};
+    use alloy_primitives::{Bytes, FixedBytes, U256};
     use alloy_sol_types::SolValue;
     use assert_ok::assert_ok;
     use axelar_wasm_std::{assert_err_contains, nonempty};
@@ -322,12 +491,20 @@ mod tests {
 
     use super::{DeployInterchainToken, InterchainTransfer};
     use crate::abi::{Error, MessageType, SendToHub};
-    use crate::{primitives, HubMessage};
+    use crate::{primitives, HubMessage, Message};
 
     fn from_hex(hex: &str) -> nonempty::HexBinary {
         HexBinary::from_hex(hex).unwrap().try_into().unwrap()
     }
 
+    /// Test vector shared with ITS edge implementations. A missing `decoded` message means the payload must be rejected.
+    #[derive(serde::Deserialize)]
+    struct ConformanceVector {
+        description: String,
+        payload: HexBinary,
+        decoded: Option<HubMessage>,
+    }
+
     #[test]
     fn interchain_transfer_encode_decode() {
         let remote_chain = ChainNameRaw::from_str("chain").unwrap();
@@ -647,4 +824,82 @@ mod tests {
         let decoded = assert_ok!(HubMessage::abi_decode(&encoded));
         assert_eq!(original, decoded);
     }
+
+    #[test]
+    fn abi_conformance_vectors() {
+        let vectors: Vec<ConformanceVector> =
+            serde_json::from_str(include_str!("../testdata/abi_conformance_vectors.json")).unwrap();
+
+        for vector in vectors {
+            let result = HubMessage::abi_decode(&vector.payload);
+
+            match vector.decoded {
+                Some(expected) => {
+                    assert_eq!(assert_ok!(result), expected, "{}", vector.description)
+                }
+                None => assert!(result.is_err(), "{}", vector.description),
+            }
+        }
+    }
+
+    #[test]
+    fn additional_fields_are_ignored_from_version_1() {
+        let message = primitives::InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: from_hex("1234"),
+            destination_address: from_hex("5678"),
+            amount: 1u64.try_into().unwrap(),
+            data: None,
+        };
+        let encode_with_additional_fields = |version: u8| {
+            (
+                U256::from_be_slice(&[version, MessageType::InterchainTransfer as u8]),
+                FixedBytes::<32>::new([1u8; 32]),
+                Bytes::from(vec![0x12, 0x34]),
+                Bytes::from(vec![0x56, 0x78]),
+                U256::from(1),
+                Bytes::new(),
+                U256::from(250000),
+                Bytes::from(vec![0xbe, 0xef]),
+            )
+                .abi_encode_params()
+        };
+
+        let decoded = assert_ok!(Message::abi_decode(&encode_with_additional_fields(1)));
+        assert_eq!(decoded, message.into());
+
+        assert!(Message::abi_decode(&encode_with_additional_fields(0)).is_err());
+    }
+
+    #[test]
+    fn invalid_offsets_are_rejected_from_version_1() {
+        let encoded = InterchainTransfer {
+            messageType: U256::from_be_slice(&[1, MessageType::InterchainTransfer as u8]),
+            tokenId: FixedBytes::<32>::new([1u8; 32]),
+            sourceAddress: vec![0x12, 0x34].into(),
+            destinationAddress: vec![0x56, 0x78].into(),
+            amount: U256::from(1),
+            data: vec![].into(),
+        }
+        .abi_encode_params();
+        assert_ok!(Message::abi_decode(&encoded));
+
+        // the source address offset points to the amount head slot
+        let mut into_head = encoded.clone();
+        into_head[64..96].copy_from_slice(&U256::from(0x80).to_be_bytes::<32>());
+        assert_err_contains!(
+            Message::abi_decode(&into_head),
+            Error,
+            Error::InvalidOffset(2)
+        );
+
+        // the destination address tail overlaps the source address tail
+        let mut overlapping = encoded;
+        overlapping.copy_within(64..96, 96);
+        assert_err_contains!(
+            Message::abi_decode(&overlapping),
+            Error,
+            Error::InvalidOffset(3)
+        );
+    }
 }
@@ -0,0 +1,372 @@
+use axelar_wasm_std::{nonempty, IntoContractError};
+use cosmwasm_std::{HexBinary, Uint256};
+use error_stack::{Report, ResultExt};
+use router_api::ChainNameRaw;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{HubMessage, Message};
+use crate::{primitives, TokenId};
+
+// ITS message payload types for BCS encoding.
+// The variants mirror the ABI message types, but BCS encodes enum variants by their index,
+// so the order of the variants must not change. Empty byte vectors encode optional values.
+// uint256 values are encoded as 32 bytes in little-endian order, matching Move's u256.
+#[derive(Serialize, Deserialize)]
+enum BcsHubMessage {
+    SendToHub {
+        destination_chain: String,
+        message: BcsMessage,
+    },
+    ReceiveFromHub {
+        source_chain: String,
+        message: BcsMessage,
+    },
+    RegisterTokenMetadata {
+        token_address: Vec<u8>,
+        decimals: u8,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+enum BcsMessage {
+    InterchainTransfer {
+        token_id: [u8; 32],
+        source_address: Vec<u8>,
+        destination_address: Vec<u8>,
+        amount: [u8; 32],
+        data: Vec<u8>,
+    },
+    DeployInterchainToken {
+        token_id: [u8; 32],
+        name: String,
+        symbol: String,
+        decimals: u8,
+        minter: Vec<u8>,
+    },
+    LinkToken {
+        token_id: [u8; 32],
+        token_manager_type: [u8; 32],
+        source_token: Vec<u8>,
+        destination_token: Vec<u8>,
+        params: Vec<u8>,
+    },
+}
+
+#[derive(thiserror::Error, Debug, IntoContractError)]
+pub enum Error {
+    #[error("invalid chain name")]
+    InvalidChainName,
+    #[error(transparent)]
+    NonEmpty(#[from] nonempty::Error),
+    #[error("failed to decode BCS payload")]
+    BcsDecodeFailed,
+}
+
+impl From<Message> for BcsMessage {
+    fn from(message: Message) -> Self {
+        match message {
+            Message::InterchainTransfer(primitives::InterchainTransfer {
+                token_id,
+                source_address,
+                destination_address,
+                amount,
+                data,
+            }) => BcsMessage::InterchainTransfer {
+                token_id: token_id.into(),
+                source_address: source_address.into(),
+                destination_address: destination_address.into(),
+                amount: amount.to_le_bytes(),
+                data: into_vec(data),
+            },
+            Message::DeployInterchainToken(primitives::DeployInterchainToken {
+                token_id,
+                name,
+                symbol,
+                decimals,
+                minter,
+            }) => BcsMessage::DeployInterchainToken {
+                token_id: token_id.into(),
+                name: name.into(),
+                symbol: symbol.into(),
+                decimals,
+                minter: into_vec(minter),
+            },
+            Message::LinkToken(primitives::LinkToken {
+                token_id,
+                token_manager_type,
+                source_token_address,
+                destination_token_address,
+                params,
+            }) => BcsMessage::LinkToken {
+                token_id: token_id.into(),
+                token_manager_type: token_manager_type.to_le_bytes(),
+                source_token: source_token_address.into(),
+                destination_token: destination_token_address.into(),
+                params: into_vec(params),
+            },
+        }
+    }
+}
+
+impl TryFrom<BcsMessage> for Message {
+    type Error = Error;
+
+    fn try_from(message: BcsMessage) -> Result<Self, Self::Error> {
+        let message = match message {
+            BcsMessage::InterchainTransfer {
+                token_id,
+                source_address,
+                destination_address,
+                amount,
+                data,
+            } => primitives::InterchainTransfer {
+                token_id: TokenId::new(token_id),
+                source_address: source_address.try_into()?,
+                destination_address: destination_address.try_into()?,
+                amount: Uint256::from_le_bytes(amount).try_into()?,
+                data: from_vec(data)?,
+            }
+            .into(),
+            BcsMessage::DeployInterchainToken {
+                token_id,
+                name,
+                symbol,
+                decimals,
+                minter,
+            } => primitives::DeployInterchainToken {
+                token_id: TokenId::new(token_id),
+                name: name.try_into()?,
+                symbol: symbol.try_into()?,
+                decimals,
+                minter: from_vec(minter)?,
+            }
+            .into(),
+            BcsMessage::LinkToken {
+                token_id,
+                token_manager_type,
+                source_token,
+                destination_token,
+                params,
+            } => primitives::LinkToken {
+                token_id: TokenId::new(token_id),
+                token_manager_type: Uint256::from_le_bytes(token_manager_type),
+                source_token_address: source_token.try_into()?,
+                destination_token_address: destination_token.try_into()?,
+                params: from_vec(params)?,
+            }
+            .into(),
+        };
+
+        Ok(message)
+    }
+}
+
+impl HubMessage {
+    pub fn bcs_encode(self) -> HexBinary {
+        let message = match self {
+            HubMessage::SendToHub {
+                destination_chain,
+                message,
+            } => BcsHubMessage::SendToHub {
+                destination_chain: destination_chain.into(),
+                message: message.into(),
+            },
+            HubMessage::ReceiveFromHub {
+                source_chain,
+                message,
+            } => BcsHubMessage::ReceiveFromHub {
+                source_chain: source_chain.into(),
+                message: message.into(),
+            },
+            HubMessage::RegisterTokenMetadata(primitives::RegisterTokenMetadata {
+                decimals,
+                token_address,
+            }) => BcsHubMessage::RegisterTokenMetadata {
+                token_address: token_address.into(),
+                decimals,
+            },
+        };
+
+        bcs::to_bytes(&message)
+            .expect("failed to serialize hub message")
+            .into()
+    }
+
+    pub fn bcs_decode(payload: &[u8]) -> Result<Self, Report<Error>> {
+        let hub_message = match bcs::from_bytes(payload).change_context(Error::BcsDecodeFailed)? {
+            BcsHubMessage::SendToHub {
+                destination_chain,
+                message,
+            } => HubMessage::SendToHub {
+                destination_chain: ChainNameRaw::try_from(destination_chain)
+                    .change_context(Error::InvalidChainName)?,
+                message: message.try_into()?,
+            },
+            BcsHubMessage::ReceiveFromHub {
+                source_chain,
+                message,
+            } => HubMessage::ReceiveFromHub {
+                source_chain: ChainNameRaw::try_from(source_chain)
+                    .change_context(Error::InvalidChainName)?,
+                message: message.try_into()?,
+            },
+            BcsHubMessage::RegisterTokenMetadata {
+                token_address,
+                decimals,
+            } => HubMessage::RegisterTokenMetadata(primitives::RegisterTokenMetadata {
+                decimals,
+                token_address: token_address.try_into().map_err(Error::NonEmpty)?,
+            }),
+        };
+
+        Ok(hub_message)
+    }
+}
+
+fn into_vec(value: Option<nonempty::HexBinary>) -> Vec<u8> {
+    value.map(|v| v.into()).unwrap_or_default()
+}
+
+fn from_vec(value: Vec<u8>) -> Result<Option<nonempty::HexBinary>, Error> {
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(nonempty::HexBinary::try_from(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_ok::assert_ok;
+    use axelar_wasm_std::{assert_err_contains, nonempty};
+    use cosmwasm_std::{HexBinary, Uint256};
+    use router_api::ChainNameRaw;
+
+    use super::{BcsHubMessage, BcsMessage, Error};
+    use crate::{primitives, HubMessage};
+
+    fn from_hex(hex: &str) -> nonempty::HexBinary {
+        HexBinary::from_hex(hex).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn hub_message_encode_decode() {
+        let remote_chain = ChainNameRaw::from_str("chain").unwrap();
+
+        let cases = vec![
+            HubMessage::SendToHub {
+                destination_chain: remote_chain.clone(),
+                message: primitives::InterchainTransfer {
+                    token_id: [0u8; 32].into(),
+                    source_address: from_hex("00"),
+                    destination_address: from_hex("00"),
+                    amount: 1u64.try_into().unwrap(),
+                    data: None,
+                }
+                .into(),
+            },
+            HubMessage::ReceiveFromHub {
+                source_chain: remote_chain.clone(),
+                message: primitives::InterchainTransfer {
+                    token_id: [255u8; 32].into(),
+                    source_address: from_hex("4F4495243837681061C4743b74B3eEdf548D56A5"),
+                    destination_address: from_hex("4F4495243837681061C4743b74B3eEdf548D56A5"),
+                    amount: Uint256::MAX.try_into().unwrap(),
+                    data: Some(from_hex("abcd")),
+                }
+                .into(),
+            },
+            HubMessage::ReceiveFromHub {
+                source_chain: remote_chain.clone(),
+                message: primitives::DeployInterchainToken {
+                    token_id: [1u8; 32].into(),
+                    name: "Unicode Token 🪙".try_into().unwrap(),
+                    symbol: "UNI🔣".try_into().unwrap(),
+                    decimals: 18,
+                    minter: Some(from_hex("1234")),
+                }
+                .into(),
+            },
+            HubMessage::SendToHub {
+                destination_chain: remote_chain.clone(),
+                message: primitives::LinkToken {
+                    token_id: [1u8; 32].into(),
+                    token_manager_type: Uint256::from(2u8),
+                    source_token_address: from_hex("1234"),
+                    destination_token_address: from_hex("5678"),
+                    params: None,
+                }
+                .into(),
+            },
+            HubMessage::RegisterTokenMetadata(primitives::RegisterTokenMetadata {
+                decimals: 6,
+                token_address: from_hex("4F4495243837681061C4743b74B3eEdf548D56A5"),
+            }),
+        ];
+
+        let encoded: Vec<_> = cases
+            .iter()
+            .map(|original| original.clone().bcs_encode().to_hex())
+            .collect();
+
+        goldie::assert_json!(encoded);
+
+        for original in cases {
+            let encoded = original.clone().bcs_encode();
+            let decoded = assert_ok!(HubMessage::bcs_decode(&encoded));
+            assert_eq!(original, decoded);
+        }
+    }
+
+    #[test]
+    fn fail_decode_on_empty_fields() {
+        let payload = bcs::to_bytes(&BcsHubMessage::SendToHub {
+            destination_chain: "destination".into(),
+            message: BcsMessage::InterchainTransfer {
+                token_id: [1u8; 32],
+                source_address: vec![1, 2],
+                destination_address: vec![],
+                amount: Uint256::one().to_le_bytes(),
+                data: vec![],
+            },
+        })
+        .unwrap();
+
+        let result = HubMessage::bcs_decode(&payload);
+        assert_err_contains!(result, Error, Error::NonEmpty(..));
+    }
+
+    #[test]
+    fn fail_decode_on_invalid_chain_name() {
+        let payload = bcs::to_bytes(&BcsHubMessage::ReceiveFromHub {
+            source_chain: "".into(),
+            message: BcsMessage::DeployInterchainToken {
+                token_id: [1u8; 32],
+                name: "Test Token".into(),
+                symbol: "TST".into(),
+                decimals: 18,
+                minter: vec![],
+            },
+        })
+        .unwrap();
+
+        let result = HubMessage::bcs_decode(&payload);
+        assert_err_contains!(result, Error, Error::InvalidChainName);
+    }
+
+    #[test]
+    fn fail_decode_on_trailing_bytes() {
+        let mut payload = HubMessage::RegisterTokenMetadata(primitives::RegisterTokenMetadata {
+            decimals: 6,
+            token_address: from_hex("1234"),
+        })
+        .bcs_encode()
+        .to_vec();
+        payload.push(0);
+
+        let result = HubMessage::bcs_decode(&payload);
+        assert_err_contains!(result, Error, Error::BcsDecodeFailed);
+    }
+}
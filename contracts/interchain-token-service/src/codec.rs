@@ -0,0 +1,102 @@
+use axelar_wasm_std::IntoContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::HexBinary;
+use error_stack::{Report, ResultExt};
+
+use crate::HubMessage;
+
+#[derive(thiserror::Error, Debug, IntoContractError)]
+pub enum Error {
+    #[error("invalid {0:?} payload")]
+    InvalidPayload(PayloadCodec),
+}
+
+/// Encoding of the messages exchanged between the ITS hub and an ITS edge contract
+pub trait Codec {
+    fn encode(&self, message: HubMessage) -> HexBinary;
+    fn decode(&self, payload: &[u8]) -> Result<HubMessage, Report<Error>>;
+}
+
+/// The payload codec an ITS edge contract uses. The ITS hub translates messages between the codecs of the
+/// source and destination chain when routing them.
+#[cw_serde]
+#[derive(Copy, Default, Eq)]
+pub enum PayloadCodec {
+    /// Ethereum ABI encoding
+    #[default]
+    Abi,
+    /// Binary Canonical Serialization, as used by Move based chains
+    Bcs,
+}
+
+impl Codec for PayloadCodec {
+    fn encode(&self, message: HubMessage) -> HexBinary {
+        match self {
+            PayloadCodec::Abi => message.abi_encode(),
+            PayloadCodec::Bcs => message.bcs_encode(),
+        }
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<HubMessage, Report<Error>> {
+        match self {
+            PayloadCodec::Abi => {
+                HubMessage::abi_decode(payload).change_context(Error::InvalidPayload(*self))
+            }
+            PayloadCodec::Bcs => {
+                HubMessage::bcs_decode(payload).change_context(Error::InvalidPayload(*self))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_ok::assert_ok;
+    use axelar_wasm_std::assert_err_contains;
+    use cosmwasm_std::{HexBinary, Uint256};
+    use router_api::ChainNameRaw;
+
+    use super::{Codec, Error, PayloadCodec};
+    use crate::{primitives, HubMessage};
+
+    #[test]
+    fn translate_between_codecs() {
+        let message = primitives::InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: HexBinary::from_hex("1234").unwrap().try_into().unwrap(),
+            destination_address: HexBinary::from_hex("5678").unwrap().try_into().unwrap(),
+            amount: Uint256::MAX.try_into().unwrap(),
+            data: None,
+        };
+        let send_to_hub = HubMessage::SendToHub {
+            destination_chain: ChainNameRaw::from_str("sui").unwrap(),
+            message: message.clone().into(),
+        };
+
+        let abi_payload = PayloadCodec::Abi.encode(send_to_hub.clone());
+        let bcs_payload = PayloadCodec::Bcs.encode(send_to_hub.clone());
+        assert_ne!(abi_payload, bcs_payload);
+
+        assert_eq!(
+            assert_ok!(PayloadCodec::Abi.decode(&abi_payload)),
+            send_to_hub
+        );
+        assert_eq!(
+            assert_ok!(PayloadCodec::Bcs.decode(&bcs_payload)),
+            send_to_hub
+        );
+
+        assert_err_contains!(
+            PayloadCodec::Bcs.decode(&abi_payload),
+            Error,
+            Error::InvalidPayload(PayloadCodec::Bcs)
+        );
+        assert_err_contains!(
+            PayloadCodec::Abi.decode(&bcs_payload),
+            Error,
+            Error::InvalidPayload(PayloadCodec::Abi)
+        );
+    }
+}
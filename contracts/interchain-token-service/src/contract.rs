@@ -14,6 +14,7 @@ use crate::state;
 use crate::state::Config;
 
 mod execute;
+mod migrations;
 mod query;
 
 pub use execute::Error as ExecuteError;
@@ -35,10 +36,28 @@ pub enum Error {
     UnfreezeChain,
     #[error("failed to set chain config")]
     SetChainConfig,
+    #[error("failed to set token transfer fee")]
+    SetTokenTransferFee,
+    #[error("failed to set chain pair transfer fee")]
+    SetChainPairTransferFee,
+    #[error("failed to withdraw fees")]
+    WithdrawFees,
+    #[error("failed to transfer tokens from axelar")]
+    InterchainTransfer,
     #[error("failed to disable execution")]
     DisableExecution,
     #[error("failed to enable execution")]
     EnableExecution,
+    #[error("failed to freeze token")]
+    FreezeToken,
+    #[error("failed to unfreeze token")]
+    UnfreezeToken,
+    #[error("failed to set flow limit")]
+    SetFlowLimit,
+    #[error("failed to update custom token metadata")]
+    UpdateCustomTokenMetadata,
+    #[error("failed to remove custom token metadata")]
+    RemoveCustomTokenMetadata,
     #[error("failed to query its address")]
     QueryItsContract,
     #[error("failed to query all its addresses")]
@@ -47,15 +66,33 @@ pub enum Error {
     QueryTokenInstance,
     #[error("failed to query the token config")]
     QueryTokenConfig,
+    #[error("failed to query tokens")]
+    QueryTokens,
+    #[error("failed to query the token instances of a token")]
+    QueryTokenInstances,
+    #[error("failed to query the token instances on a chain")]
+    QueryChainTokens,
+    #[error("failed to check the supply invariant of a token")]
+    QueryInvariantCheck,
+    #[error("failed to query custom token metadata")]
+    QueryCustomTokenMetadata,
+    #[error("failed to query the transfer fee")]
+    QueryTransferFee,
+    #[error("failed to query accrued fees")]
+    QueryAccruedFees,
+    #[error("failed to simulate message")]
+    QuerySimulateMessage,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
-    // Implement migration logic if needed
+    let events = migrations::v1_0_0::migrate(deps.storage)?;
 
+    // this needs to be the last thing to do during migration,
+    // because previous migration steps should check the old version
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    Ok(Response::default())
+    Ok(Response::default().add_events(events))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -86,7 +123,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -95,8 +132,16 @@ pub fn execute(
             cc_id,
             source_address,
             payload,
-        }) => execute::execute_message(deps, cc_id, source_address, payload)
-            .change_context(Error::Execute),
+        }) => execute::execute_message(
+            deps.storage,
+            deps.api,
+            deps.querier,
+            &env,
+            cc_id,
+            source_address,
+            payload,
+        )
+        .change_context(Error::Execute),
         ExecuteMsg::RegisterChains { chains } => {
             execute::register_chains(deps, chains).change_context(Error::RegisterChains)
         }
@@ -112,6 +157,58 @@ pub fn execute(
         ExecuteMsg::UnfreezeChain { chain } => {
             unfreeze_chain(deps, chain).change_context(Error::UnfreezeChain)
         }
+        ExecuteMsg::FreezeToken { token_id } => {
+            execute::freeze_token(deps, token_id).change_context(Error::FreezeToken)
+        }
+        ExecuteMsg::UnfreezeToken { token_id } => {
+            execute::unfreeze_token(deps, token_id).change_context(Error::UnfreezeToken)
+        }
+        ExecuteMsg::SetFlowLimit {
+            token_id,
+            chain,
+            flow_limit,
+        } => execute::set_flow_limit(deps, token_id, chain, flow_limit)
+            .change_context(Error::SetFlowLimit),
+        ExecuteMsg::UpdateCustomTokenMetadata {
+            chain,
+            token_address,
+            decimals,
+        } => execute::update_custom_token_metadata(deps, chain, token_address, decimals)
+            .change_context(Error::UpdateCustomTokenMetadata),
+        ExecuteMsg::RemoveCustomTokenMetadata {
+            chain,
+            token_address,
+        } => execute::remove_custom_token_metadata(deps, chain, token_address)
+            .change_context(Error::RemoveCustomTokenMetadata),
+        ExecuteMsg::SetTokenTransferFee { token_id, fee } => {
+            execute::set_token_transfer_fee(deps, token_id, fee)
+                .change_context(Error::SetTokenTransferFee)
+        }
+        ExecuteMsg::SetChainPairTransferFee {
+            source_chain,
+            destination_chain,
+            fee,
+        } => execute::set_chain_pair_transfer_fee(deps, source_chain, destination_chain, fee)
+            .change_context(Error::SetChainPairTransferFee),
+        ExecuteMsg::WithdrawFees {
+            chain,
+            token_id,
+            recipient,
+        } => execute::withdraw_fees(deps, env, chain, token_id, recipient)
+            .change_context(Error::WithdrawFees),
+        ExecuteMsg::InterchainTransfer {
+            destination_chain,
+            destination_address,
+            data,
+        } => execute::interchain_transfer_from_axelar(
+            deps,
+            env,
+            info,
+            destination_chain,
+            destination_address,
+            data,
+        )
+        .change_context(Error::InterchainTransfer),
         ExecuteMsg::DisableExecution => {
             execute::disable_execution(deps).change_context(Error::DisableExecution)
         }
@@ -127,7 +224,7 @@ fn match_gateway(storage: &dyn Storage, _: &ExecuteMsg) -> Result<Addr, Report<E
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ItsContract { chain } => {
             query::its_contract(deps, chain).change_context(Error::QueryItsContract)
@@ -136,11 +233,48 @@ pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> Result<Binary, ContractError>
             query::all_its_contracts(deps).change_context(Error::QueryAllItsContracts)
         }
         QueryMsg::TokenInstance { chain, token_id } => {
-            query::token_instance(deps, chain, token_id).change_context(Error::QueryTokenInstance)
+            query::token_instance(deps, env.block.time, chain, token_id)
+                .change_context(Error::QueryTokenInstance)
         }
         QueryMsg::TokenConfig { token_id } => {
             query::token_config(deps, token_id).change_context(Error::QueryTokenConfig)
         }
+        QueryMsg::Tokens { start_after, limit } => {
+            query::tokens(deps, start_after, limit).change_context(Error::QueryTokens)
+        }
+        QueryMsg::TokenInstances { token_id } => {
+            query::token_instances(deps, env.block.time, token_id)
+                .change_context(Error::QueryTokenInstances)
+        }
+        QueryMsg::ChainTokens {
+            chain,
+            start_after,
+            limit,
+        } => query::chain_tokens(deps, env.block.time, chain, start_after, limit)
+            .change_context(Error::QueryChainTokens),
+        QueryMsg::InvariantCheck { token_id } => {
+            query::invariant_check(deps, token_id).change_context(Error::QueryInvariantCheck)
+        }
+        QueryMsg::CustomTokenMetadata {
+            chain,
+            token_address,
+        } => query::custom_token_metadata(deps, chain, token_address)
+            .change_context(Error::QueryCustomTokenMetadata),
+        QueryMsg::TransferFee {
+            source_chain,
+            destination_chain,
+            token_id,
+        } => query::transfer_fee(deps, source_chain, destination_chain, token_id)
+            .change_context(Error::QueryTransferFee),
+        QueryMsg::AccruedFees { chain, token_id } => {
+            query::accrued_fees(deps, chain, token_id).change_context(Error::QueryAccruedFees)
+        }
+        QueryMsg::SimulateMessage {
+            source_chain,
+            source_address,
+            payload,
+        } => query::simulate_message(deps, env, source_chain, source_address, payload)
+            .change_context(Error::QuerySimulateMessage),
     }?
     .then(Ok)
 }
@@ -1,13 +1,13 @@
 use axelar_wasm_std::{nonempty, FnExt};
-use cosmwasm_std::{Storage, Uint256};
+use cosmwasm_std::{Storage, Timestamp, Uint256};
 use error_stack::{bail, ensure, report, Result, ResultExt};
 use router_api::ChainNameRaw;
 
 use super::Error;
 use crate::state::{self, TokenDeploymentType};
 use crate::{
-    DeployInterchainToken, InterchainTransfer, RegisterTokenMetadata, TokenConfig, TokenId,
-    TokenInstance,
+    DeployInterchainToken, InterchainTransfer, RegisterTokenMetadata, TokenConfig, TokenFlow,
+    TokenId, TokenInstance,
 };
 
 pub fn subtract_supply_amount(
@@ -24,6 +24,8 @@ pub fn subtract_supply_amount(
             token_id: transfer.token_id,
             chain: chain.clone(),
         })?;
+    // the counters only serve the supply invariant check, so they must never block a transfer
+    token.total_outflow = token.total_outflow.saturating_add(transfer.amount.into());
 
     state::save_token_instance(storage, chain.clone(), transfer.token_id, &token)
         .change_context(Error::State)
@@ -43,11 +45,189 @@ pub fn add_supply_amount(
             token_id: transfer.token_id,
             chain: chain.clone(),
         })?;
+    token.total_inflow = token.total_inflow.saturating_add(transfer.amount.into());
 
     state::save_token_instance(storage, chain.clone(), transfer.token_id, &token)
         .change_context(Error::State)
 }
 
+/// Adds the transfer amount to the outflow of the token on the source chain in the current epoch.
+/// Fails if the net outflow would exceed the token's flow limit on that chain.
+pub fn add_flow_out(
+    storage: &mut dyn Storage,
+    chain: &ChainNameRaw,
+    transfer: &InterchainTransfer,
+    block_time: Timestamp,
+) -> Result<(), Error> {
+    add_flow(storage, chain, transfer, block_time, FlowDirection::Out)
+}
+
+/// Adds the transfer amount to the inflow of the token on the destination chain in the current epoch.
+/// Fails if the net inflow would exceed the token's flow limit on that chain.
+pub fn add_flow_in(
+    storage: &mut dyn Storage,
+    chain: &ChainNameRaw,
+    transfer: &InterchainTransfer,
+    block_time: Timestamp,
+) -> Result<(), Error> {
+    add_flow(storage, chain, transfer, block_time, FlowDirection::In)
+}
+
+enum FlowDirection {
+    In,
+    Out,
+}
+
+fn add_flow(
+    storage: &mut dyn Storage,
+    chain: &ChainNameRaw,
+    transfer: &InterchainTransfer,
+    block_time: Timestamp,
+    direction: FlowDirection,
+) -> Result<(), Error> {
+    let mut token = try_load_token_instance(storage, chain.clone(), transfer.token_id)?;
+    let flow = token.flow.at(block_time);
+    let amount = Uint256::from(transfer.amount);
+
+    let (flow_to_add, flow_to_compare) = match direction {
+        FlowDirection::In => (flow.flow_in, flow.flow_out),
+        FlowDirection::Out => (flow.flow_out, flow.flow_in),
+    };
+
+    let flow_limit_exceeded = || Error::FlowLimitExceeded {
+        token_id: transfer.token_id,
+        chain: chain.clone(),
+    };
+    let flow_to_add = flow_to_add
+        .checked_add(amount)
+        .change_context_lazy(flow_limit_exceeded)?;
+
+    // same rule as the EVM edge contracts: a single transfer can't exceed the limit,
+    // and the flow in one direction can't exceed the flow in the other direction by more than the limit
+    if let Some(flow_limit) = token.flow_limit.map(Uint256::from) {
+        ensure!(
+            amount <= flow_limit && flow_to_add <= flow_to_compare.saturating_add(flow_limit),
+            flow_limit_exceeded()
+        );
+    }
+
+    token.flow = match direction {
+        FlowDirection::In => TokenFlow {
+            flow_in: flow_to_add,
+            ..flow
+        },
+        FlowDirection::Out => TokenFlow {
+            flow_out: flow_to_add,
+            ..flow
+        },
+    };
+
+    state::save_token_instance(storage, chain.clone(), transfer.token_id, &token)
+        .change_context(Error::State)
+}
+
+/// Transfer fees are expressed in basis points of the transfer amount
+pub const BASIS_POINTS: u16 = 10_000;
+
+/// Deducts the transfer fee from the transfer amount and adds it to the fees accrued for the token on the source chain.
+/// Returns the transfer with the net amount and the deducted fee.
+pub fn deduct_transfer_fee(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainNameRaw,
+    mut transfer: InterchainTransfer,
+) -> Result<(InterchainTransfer, Uint256), Error> {
+    let fee_bps = state::load_transfer_fee(
+        storage,
+        source_chain.clone(),
+        destination_chain.clone(),
+        transfer.token_id,
+    )
+    .change_context(Error::State)?;
+
+    let invalid_transfer_amount = || Error::InvalidTransferAmount {
+        source_chain: source_chain.to_owned(),
+        destination_chain: destination_chain.to_owned(),
+        amount: transfer.amount,
+    };
+    let fee = Uint256::from(transfer.amount)
+        .checked_multiply_ratio(fee_bps, BASIS_POINTS)
+        .change_context_lazy(invalid_transfer_amount)?;
+
+    if fee.is_zero() {
+        return Ok((transfer, fee));
+    }
+
+    let accrued_fees = state::load_accrued_fees(storage, source_chain.clone(), transfer.token_id)
+        .change_context(Error::State)?
+        .checked_add(fee)
+        .change_context_lazy(invalid_transfer_amount)?;
+    state::save_accrued_fees(
+        storage,
+        source_chain.clone(),
+        transfer.token_id,
+        &accrued_fees,
+    )
+    .change_context(Error::State)?;
+
+    let net_amount = Uint256::from(transfer.amount)
+        .checked_sub(fee)
+        .change_context_lazy(invalid_transfer_amount)?
+        .try_into()
+        .change_context_lazy(invalid_transfer_amount)?;
+    transfer.amount = net_amount;
+
+    Ok((transfer, fee))
+}
+
+/// Adds the part of the transfer amount that is truncated when scaling it to the decimals of the destination chain
+/// to the rounding loss of the token on the source chain.
+pub fn add_rounding_loss(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainNameRaw,
+    transfer: &InterchainTransfer,
+) -> Result<(), Error> {
+    let mut source_token =
+        try_load_token_instance(storage, source_chain.clone(), transfer.token_id)?;
+    let destination_token =
+        try_load_token_instance(storage, destination_chain.clone(), transfer.token_id)?;
+
+    if source_token.decimals <= destination_token.decimals {
+        return Ok(());
+    }
+
+    let invalid_transfer_amount = || Error::InvalidTransferAmount {
+        source_chain: source_chain.to_owned(),
+        destination_chain: destination_chain.to_owned(),
+        amount: transfer.amount,
+    };
+    let scaling_factor = Uint256::from_u128(10)
+        .checked_pow(
+            source_token
+                .decimals
+                .abs_diff(destination_token.decimals)
+                .into(),
+        )
+        .change_context_lazy(invalid_transfer_amount)?;
+    let rounding_loss = Uint256::from(transfer.amount)
+        .checked_rem(scaling_factor)
+        .expect("scaling_factor must be non-zero");
+
+    source_token.rounding_loss = source_token
+        .rounding_loss
+        .checked_add(rounding_loss)
+        .change_context_lazy(invalid_transfer_amount)?;
+
+    state::save_token_instance(
+        storage,
+        source_chain.clone(),
+        transfer.token_id,
+        &source_token,
+    )
+    .change_context(Error::State)
+}
+
 pub fn apply_scaling_factor_to_amount(
     storage: &dyn Storage,
     source_chain: &ChainNameRaw,
@@ -79,6 +259,7 @@ pub fn deploy_token_to_source_chain(
             // Token is being deployed for the first time
             let token_config = TokenConfig {
                 origin_chain: chain.clone(),
+                frozen: false,
             };
             state::save_token_config(storage, token_id, &token_config)
                 .and_then(|_| {
@@ -313,14 +494,16 @@ mod test {
     use assert_ok::assert_ok;
     use axelar_wasm_std::assert_err_contains;
     use cosmwasm_std::testing::MockStorage;
-    use cosmwasm_std::Uint256;
+    use cosmwasm_std::{Timestamp, Uint256};
     use router_api::ChainNameRaw;
 
     use super::Error;
     use crate::contract::execute::interceptors;
     use crate::msg::TruncationConfig;
     use crate::state::{self, TokenDeploymentType};
-    use crate::{msg, DeployInterchainToken, InterchainTransfer, TokenInstance};
+    use crate::{
+        msg, DeployInterchainToken, InterchainTransfer, PayloadCodec, TokenFlow, TokenInstance,
+    };
 
     #[test]
     fn apply_scaling_factor_to_amount_when_source_decimals_are_bigger() {
@@ -359,6 +542,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -412,6 +596,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -465,6 +650,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -518,6 +704,7 @@ mod test {
                     max_uint: Uint256::from(100_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -571,6 +758,7 @@ mod test {
                     max_uint: Uint256::from(100_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -587,6 +775,124 @@ mod test {
         );
     }
 
+    #[test]
+    fn add_rounding_loss_tracks_truncated_amount_on_source_chain() {
+        let mut storage = MockStorage::new();
+        let source_chain: ChainNameRaw = "sourcechain".try_into().unwrap();
+        let destination_chain: ChainNameRaw = "destinationchain".try_into().unwrap();
+        let transfer = InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: b"source_address".to_vec().try_into().unwrap(),
+            destination_address: b"destination_address".to_vec().try_into().unwrap(),
+            amount: Uint256::from(1_000_000_123_456u128).try_into().unwrap(),
+            data: None,
+        };
+
+        state::save_token_instance(
+            &mut storage,
+            source_chain.clone(),
+            transfer.token_id,
+            &TokenInstance::new_on_origin(18),
+        )
+        .unwrap();
+        state::save_token_instance(
+            &mut storage,
+            destination_chain.clone(),
+            transfer.token_id,
+            &TokenInstance::new(&TokenDeploymentType::Trustless, 12),
+        )
+        .unwrap();
+
+        assert_ok!(interceptors::add_rounding_loss(
+            &mut storage,
+            &source_chain,
+            &destination_chain,
+            &transfer,
+        ));
+        assert_ok!(interceptors::add_rounding_loss(
+            &mut storage,
+            &source_chain,
+            &destination_chain,
+            &transfer,
+        ));
+        // nothing is truncated when scaling up
+        assert_ok!(interceptors::add_rounding_loss(
+            &mut storage,
+            &destination_chain,
+            &source_chain,
+            &transfer,
+        ));
+
+        let source_token =
+            state::may_load_token_instance(&storage, source_chain, transfer.token_id)
+                .unwrap()
+                .unwrap();
+        assert_eq!(source_token.rounding_loss, Uint256::from(246_912u128));
+        let destination_token =
+            state::may_load_token_instance(&storage, destination_chain, transfer.token_id)
+                .unwrap()
+                .unwrap();
+        assert_eq!(destination_token.rounding_loss, Uint256::zero());
+    }
+
+    #[test]
+    fn deduct_transfer_fee_prefers_token_fee_and_rounds_down() {
+        let mut storage = MockStorage::new();
+        let source_chain: ChainNameRaw = "sourcechain".try_into().unwrap();
+        let destination_chain: ChainNameRaw = "destinationchain".try_into().unwrap();
+        let transfer = InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: b"source_address".to_vec().try_into().unwrap(),
+            destination_address: b"destination_address".to_vec().try_into().unwrap(),
+            amount: Uint256::from(1_999u128).try_into().unwrap(),
+            data: None,
+        };
+
+        let (unchanged, fee) = assert_ok!(interceptors::deduct_transfer_fee(
+            &mut storage,
+            &source_chain,
+            &destination_chain,
+            transfer.clone(),
+        ));
+        assert_eq!(unchanged, transfer);
+        assert_eq!(fee, Uint256::zero());
+
+        state::save_chain_pair_transfer_fee(
+            &mut storage,
+            source_chain.clone(),
+            destination_chain.clone(),
+            Some(100),
+        )
+        .unwrap();
+        let (charged, fee) = assert_ok!(interceptors::deduct_transfer_fee(
+            &mut storage,
+            &source_chain,
+            &destination_chain,
+            transfer.clone(),
+        ));
+        assert_eq!(fee, Uint256::from(19u128));
+        assert_eq!(Uint256::from(charged.amount), Uint256::from(1_980u128));
+
+        state::save_token_transfer_fee(&mut storage, transfer.token_id, Some(5)).unwrap();
+        let (charged, fee) = assert_ok!(interceptors::deduct_transfer_fee(
+            &mut storage,
+            &source_chain,
+            &destination_chain,
+            transfer.clone(),
+        ));
+        assert_eq!(fee, Uint256::zero());
+        assert_eq!(charged, transfer);
+
+        assert_eq!(
+            state::load_accrued_fees(&storage, source_chain, transfer.token_id).unwrap(),
+            Uint256::from(19u128)
+        );
+        assert_eq!(
+            state::load_accrued_fees(&storage, destination_chain, transfer.token_id).unwrap(),
+            Uint256::zero()
+        );
+    }
+
     #[test]
     fn calculate_scaling_factor_when_source_max_uint_is_bigger() {
         let mut storage = MockStorage::new();
@@ -603,6 +909,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 12,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -616,6 +923,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -667,6 +975,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -680,6 +989,7 @@ mod test {
                     max_uint: Uint256::from(1_000_000_000_000_000u128).try_into().unwrap(),
                     max_decimals_when_truncating: 6,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         )
         .unwrap();
@@ -714,4 +1024,104 @@ mod test {
         ));
         assert_eq!(deploy_token.decimals, 3);
     }
+
+    #[test]
+    fn add_flow_within_flow_limit_succeeds_and_resets_every_epoch() {
+        let mut storage = MockStorage::new();
+        let chain: ChainNameRaw = "chain".try_into().unwrap();
+        let transfer = InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: b"source_address".to_vec().try_into().unwrap(),
+            destination_address: b"destination_address".to_vec().try_into().unwrap(),
+            amount: Uint256::from(60u128).try_into().unwrap(),
+            data: None,
+        };
+        let block_time = Timestamp::from_seconds(1_000_000);
+        let next_epoch_time = block_time.plus_hours(6);
+
+        state::save_token_instance(
+            &mut storage,
+            chain.clone(),
+            transfer.token_id,
+            &TokenInstance {
+                flow_limit: Some(Uint256::from(100u128).try_into().unwrap()),
+                ..TokenInstance::new_on_origin(18)
+            },
+        )
+        .unwrap();
+
+        assert_ok!(interceptors::add_flow_out(
+            &mut storage,
+            &chain,
+            &transfer,
+            block_time
+        ));
+        assert_ok!(interceptors::add_flow_in(
+            &mut storage,
+            &chain,
+            &transfer,
+            block_time
+        ));
+        assert_ok!(interceptors::add_flow_out(
+            &mut storage,
+            &chain,
+            &transfer,
+            block_time
+        ));
+        // net outflow would be 120
+        assert_err_contains!(
+            interceptors::add_flow_out(&mut storage, &chain, &transfer, block_time),
+            Error,
+            Error::FlowLimitExceeded { .. }
+        );
+
+        assert_ok!(interceptors::add_flow_out(
+            &mut storage,
+            &chain,
+            &transfer,
+            next_epoch_time
+        ));
+
+        let token_instance = state::may_load_token_instance(&storage, chain, transfer.token_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            token_instance.flow,
+            TokenFlow {
+                epoch: TokenFlow::epoch_at(next_epoch_time),
+                flow_in: Uint256::zero(),
+                flow_out: Uint256::from(60u128),
+            }
+        );
+    }
+
+    #[test]
+    fn add_flow_exceeding_flow_limit_in_single_transfer_fails() {
+        let mut storage = MockStorage::new();
+        let chain: ChainNameRaw = "chain".try_into().unwrap();
+        let transfer = InterchainTransfer {
+            token_id: [1u8; 32].into(),
+            source_address: b"source_address".to_vec().try_into().unwrap(),
+            destination_address: b"destination_address".to_vec().try_into().unwrap(),
+            amount: Uint256::from(101u128).try_into().unwrap(),
+            data: None,
+        };
+
+        state::save_token_instance(
+            &mut storage,
+            chain.clone(),
+            transfer.token_id,
+            &TokenInstance {
+                flow_limit: Some(Uint256::from(100u128).try_into().unwrap()),
+                ..TokenInstance::new(&TokenDeploymentType::Trustless, 18)
+            },
+        )
+        .unwrap();
+
+        assert_err_contains!(
+            interceptors::add_flow_in(&mut storage, &chain, &transfer, Timestamp::from_seconds(0)),
+            Error,
+            Error::FlowLimitExceeded { .. }
+        );
+    }
 }
@@ -1,5 +1,11 @@
-use axelar_wasm_std::{killswitch, nonempty, FnExt, IntoContractError};
-use cosmwasm_std::{DepsMut, HexBinary, QuerierWrapper, Response, Storage};
+use std::str;
+
+use axelar_wasm_std::{address, killswitch, nonempty, FnExt, IntoContractError};
+use axelarnet_gateway::{AxelarExecutableClient, AxelarExecutableMsg};
+use cosmwasm_std::{
+    Addr, Api, Coin, DepsMut, Env, HexBinary, MessageInfo, QuerierWrapper, Response, Storage,
+    Timestamp, Uint128, Uint256,
+};
 use error_stack::{bail, ensure, report, Result, ResultExt};
 use interceptors::{deploy_token_to_destination_chain, deploy_token_to_source_chain};
 use itertools::Itertools;
@@ -9,13 +15,17 @@ use crate::events::Event;
 use crate::primitives::HubMessage;
 use crate::state::TokenDeploymentType;
 use crate::{
-    msg, state, DeployInterchainToken, InterchainTransfer, LinkToken, Message,
-    RegisterTokenMetadata, TokenId,
+    msg, state, token_factory, Codec, DeployInterchainToken, InterchainTransfer, LinkToken,
+    Message, RegisterTokenMetadata, TokenId,
 };
 
 mod interceptors;
+mod simulate;
 
-#[derive(thiserror::Error, Debug, IntoContractError)]
+pub use simulate::simulate_message;
+
+#[derive(thiserror::Error, Debug, IntoContractError, strum::AsRefStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum Error {
     #[error("chain not found {0}")]
     ChainNotFound(ChainNameRaw),
@@ -83,32 +93,80 @@ pub enum Error {
     TokenAlreadyRegistered(nonempty::HexBinary),
     #[error("failed to query axelarnet gateway for chain name")]
     FailedToQueryAxelarnetGateway,
+    #[error("token {0} is frozen")]
+    TokenFrozen(TokenId),
+    #[error("token {0} is not deployed as an interchain token on the axelar chain")]
+    TokenNotMintable(TokenId),
+    #[error("invalid destination address {0}")]
+    InvalidDestinationAddress(nonempty::HexBinary),
+    #[error("token {token_address} on chain {chain} is already linked")]
+    TokenAlreadyLinked {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+    },
+    #[error("transfer fee of {0} basis points must be less than 10000 basis points")]
+    InvalidTransferFee(u16),
+    #[error("no fees accrued for token {token_id} on chain {chain}")]
+    NoAccruedFees {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+    },
+    #[error("flow limit exceeded for token {token_id} on chain {chain}")]
+    FlowLimitExceeded {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+    },
+    #[error("exactly one token factory token must be attached")]
+    InvalidFunds,
+    #[error("denom {0} does not represent an interchain token")]
+    UnknownTokenFactoryDenom(String),
+    #[error("invalid destination chain {0}")]
+    InvalidDestinationChain(ChainNameRaw),
 }
 
 /// Executes an incoming ITS message.
 ///
 /// This function handles the execution of ITS (Interchain Token Service) messages received from
-/// its sources. It verifies the source address, decodes the message, applies various checks and transformations,
-/// and forwards the message to the destination chain.
+/// its sources. It verifies the source address, decodes the message with the source chain's payload codec,
+/// applies various checks and transformations, and forwards the message to the destination chain
+/// encoded with the destination chain's payload codec.
 pub fn execute_message(
-    deps: DepsMut,
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    env: &Env,
     cc_id: CrossChainId,
     source_address: Address,
     payload: HexBinary,
 ) -> Result<Response, Error> {
     ensure!(
-        killswitch::is_contract_active(deps.storage),
+        killswitch::is_contract_active(storage),
         Error::ExecutionDisabled
     );
-    ensure_is_its_source_address(deps.storage, &cc_id.source_chain, &source_address)?;
+    ensure_is_its_source_address(storage, &cc_id.source_chain, &source_address)?;
 
-    match HubMessage::abi_decode(&payload).change_context(Error::InvalidPayload)? {
+    let source_codec = state::load_chain_config(storage, &cc_id.source_chain)
+        .change_context(Error::State)?
+        .payload_codec;
+
+    match source_codec
+        .decode(&payload)
+        .change_context(Error::InvalidPayload)?
+    {
         HubMessage::SendToHub {
             destination_chain,
             message,
-        } => execute_message_on_hub(deps, cc_id, destination_chain, message),
+        } => execute_message_on_hub(
+            storage,
+            api,
+            querier,
+            env,
+            cc_id,
+            destination_chain,
+            message,
+        ),
         HubMessage::RegisterTokenMetadata(msg) => {
-            execute_register_token_metadata(deps.storage, cc_id.source_chain, msg)
+            execute_register_token_metadata(storage, cc_id.source_chain, msg)
         }
         _ => bail!(Error::InvalidMessageType),
     }
@@ -124,58 +182,80 @@ fn axelar_chain_name(storage: &dyn Storage, querier: QuerierWrapper) -> Result<C
 }
 
 fn execute_message_on_hub(
-    deps: DepsMut,
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    env: &Env,
     cc_id: CrossChainId,
     destination_chain: ChainNameRaw,
     message: Message,
 ) -> Result<Response, Error> {
-    let message = apply_to_hub(
-        deps.storage,
+    let (message, events) = apply_to_hub(
+        storage,
+        env.block.time,
         cc_id.source_chain.clone(),
         destination_chain.clone(),
         message,
     )?;
 
-    let destination_payload = HubMessage::ReceiveFromHub {
-        source_chain: cc_id.source_chain.clone(),
-        message: message.clone(),
-    }
-    .abi_encode();
+    let response = if destination_chain == axelar_chain_name(storage, querier)? {
+        execute_on_axelar(
+            storage,
+            api,
+            querier,
+            &env.contract.address,
+            cc_id.clone(),
+            &destination_chain,
+            message.clone(),
+        )?
+    } else {
+        send_to_destination(
+            storage,
+            querier,
+            &destination_chain,
+            HubMessage::ReceiveFromHub {
+                source_chain: cc_id.source_chain.clone(),
+                message: message.clone(),
+            },
+        )?
+    };
 
-    Ok(send_to_destination(
-        deps.storage,
-        deps.querier,
-        &destination_chain,
-        destination_payload,
-    )?
-    .add_event(Event::MessageReceived {
-        cc_id,
-        destination_chain,
-        message,
-    }))
+    Ok(response
+        .add_event(Event::MessageReceived {
+            cc_id,
+            destination_chain,
+            message,
+        })
+        .add_events(events))
 }
 
 fn apply_to_hub(
     storage: &mut dyn Storage,
+    block_time: Timestamp,
     source_chain: ChainNameRaw,
     destination_chain: ChainNameRaw,
     message: Message,
-) -> Result<Message, Error> {
+) -> Result<(Message, Vec<Event>), Error> {
     ensure_chain_not_frozen(storage, &source_chain)?;
     ensure_chain_not_frozen(storage, &destination_chain)?;
+    ensure_token_not_frozen(storage, message.token_id())?;
 
     match message {
-        Message::InterchainTransfer(transfer) => {
-            apply_to_transfer(storage, source_chain, destination_chain, transfer)
-                .map(Message::InterchainTransfer)?
-        }
+        Message::InterchainTransfer(transfer) => apply_to_transfer(
+            storage,
+            block_time,
+            source_chain,
+            destination_chain,
+            transfer,
+        )
+        .map(|(transfer, events)| (Message::InterchainTransfer(transfer), events))?,
         Message::DeployInterchainToken(deploy_token) => {
             apply_to_token_deployment(storage, &source_chain, &destination_chain, deploy_token)
-                .map(Message::DeployInterchainToken)?
+                .map(|deploy_token| (Message::DeployInterchainToken(deploy_token), vec![]))?
         }
         Message::LinkToken(link_token) => {
             apply_to_link_token(storage, source_chain, destination_chain, link_token)
-                .map(Message::LinkToken)?
+                .map(|link_token| (Message::LinkToken(link_token), vec![]))?
         }
     }
     .then(Result::Ok)
@@ -223,6 +303,12 @@ fn apply_to_link_token(
         link_token.token_id,
         source_token.decimals,
     )?;
+    state::mark_custom_token_linked(
+        storage,
+        source_chain,
+        link_token.source_token_address.clone(),
+    )
+    .change_context(Error::State)?;
 
     let destination_decimals = state::may_load_custom_token(
         storage,
@@ -242,17 +328,42 @@ fn apply_to_link_token(
         destination_decimals,
         TokenDeploymentType::CustomMinter,
     )?;
+    state::mark_custom_token_linked(
+        storage,
+        destination_chain,
+        link_token.destination_token_address.clone(),
+    )
+    .change_context(Error::State)?;
 
     Ok(link_token)
 }
 
 fn apply_to_transfer(
     storage: &mut dyn Storage,
+    block_time: Timestamp,
     source_chain: ChainNameRaw,
     destination_chain: ChainNameRaw,
     transfer: InterchainTransfer,
-) -> Result<InterchainTransfer, Error> {
+) -> Result<(InterchainTransfer, Vec<Event>), Error> {
     interceptors::subtract_supply_amount(storage, &source_chain, &transfer)?;
+    interceptors::add_flow_out(storage, &source_chain, &transfer, block_time)?;
+
+    let amount = transfer.amount;
+    let (transfer, fee) =
+        interceptors::deduct_transfer_fee(storage, &source_chain, &destination_chain, transfer)?;
+    let events = match nonempty::Uint256::try_from(fee) {
+        Ok(fee) => vec![Event::TransferFeeCharged {
+            token_id: transfer.token_id,
+            source_chain: source_chain.clone(),
+            destination_chain: destination_chain.clone(),
+            amount,
+            fee,
+            net_amount: transfer.amount,
+        }],
+        Err(_) => vec![],
+    };
+
+    interceptors::add_rounding_loss(storage, &source_chain, &destination_chain, &transfer)?;
     let transfer = interceptors::apply_scaling_factor_to_amount(
         storage,
         &source_chain,
@@ -260,8 +371,9 @@ fn apply_to_transfer(
         transfer,
     )?;
     interceptors::add_supply_amount(storage, &destination_chain, &transfer)?;
+    interceptors::add_flow_in(storage, &destination_chain, &transfer, block_time)?;
 
-    Ok(transfer)
+    Ok((transfer, events))
 }
 
 fn apply_to_token_deployment(
@@ -302,6 +414,16 @@ fn ensure_chain_not_frozen(storage: &dyn Storage, chain: &ChainNameRaw) -> Resul
     Ok(())
 }
 
+fn ensure_token_not_frozen(storage: &dyn Storage, token_id: TokenId) -> Result<(), Error> {
+    let frozen = state::may_load_token_config(storage, &token_id)
+        .change_context(Error::State)?
+        .is_some_and(|token_config| token_config.frozen);
+
+    ensure!(!frozen, Error::TokenFrozen(token_id));
+
+    Ok(())
+}
+
 /// Ensures that the source address of the cross-chain message is the registered ITS contract for the source chain.
 fn ensure_is_its_source_address(
     storage: &dyn Storage,
@@ -323,20 +445,9 @@ fn send_to_destination(
     storage: &dyn Storage,
     querier: QuerierWrapper,
     destination_chain: &ChainNameRaw,
-    payload: HexBinary,
+    message: HubMessage,
 ) -> Result<Response, Error> {
-    if *destination_chain == axelar_chain_name(storage, querier)? {
-        // right now, messages sent to the axelar chain are not forwarded on to
-        // any other contract (in contrast to every other message that moves through the hub)
-        // In the future, this may change, depending on the message type
-        // The main use case for this at the moment is the RegisterToken message,
-        // which simply informs the ITS hub of the decimals and token address of a
-        // custom token, and thus needs no forwarding.
-        return Ok(Response::new());
-    }
-
-    let destination_address = state::load_its_contract(storage, destination_chain)
-        .change_context_lazy(|| Error::ChainNotFound(destination_chain.clone()))?;
+    let (its_address, payload) = encode_for_destination(storage, destination_chain, message)?;
 
     let config = state::load_config(storage);
 
@@ -344,11 +455,183 @@ fn send_to_destination(
         client::ContractClient::new(querier, &config.axelarnet_gateway).into();
 
     let call_contract_msg =
-        gateway.call_contract(destination_chain.normalize(), destination_address, payload);
+        gateway.call_contract(destination_chain.normalize(), its_address, payload);
 
     Ok(Response::new().add_message(call_contract_msg))
 }
 
+/// Encodes the message with the payload codec of the destination chain.
+/// Returns the address of the destination chain's ITS edge contract together with the payload.
+fn encode_for_destination(
+    storage: &dyn Storage,
+    destination_chain: &ChainNameRaw,
+    message: HubMessage,
+) -> Result<(Address, HexBinary), Error> {
+    let destination_config = state::load_chain_config(storage, destination_chain)
+        .change_context_lazy(|| Error::ChainNotFound(destination_chain.clone()))?;
+
+    Ok((
+        destination_config.its_address,
+        destination_config.payload_codec.encode(message),
+    ))
+}
+
+/// Messages sent to the axelar chain are handled by the hub itself instead of being forwarded to an ITS edge contract.
+/// Interchain tokens deployed to Axelar are represented by token factory tokens created by the hub.
+fn execute_on_axelar(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    hub: &Addr,
+    cc_id: CrossChainId,
+    destination_chain: &ChainNameRaw,
+    message: Message,
+) -> Result<Response, Error> {
+    match message {
+        Message::DeployInterchainToken(DeployInterchainToken { token_id, .. }) => {
+            state::save_token_factory_denom(
+                storage,
+                token_id,
+                &token_factory::denom(hub, token_id),
+            )
+            .change_context(Error::State)?;
+
+            Ok(Response::new().add_message(token_factory::create_denom(hub, token_id)))
+        }
+        Message::InterchainTransfer(transfer) => transfer_to_axelar(
+            storage,
+            api,
+            querier,
+            hub,
+            cc_id,
+            destination_chain,
+            transfer,
+        ),
+        // linking a custom token only needs to be recorded by the hub
+        Message::LinkToken(_) => Ok(Response::new()),
+    }
+}
+
+/// Burns the attached token factory tokens and transfers the same amount of the interchain token
+/// from the axelar chain to the destination chain, like a transfer from an ITS edge contract.
+pub fn interchain_transfer_from_axelar(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    destination_chain: ChainNameRaw,
+    destination_address: nonempty::HexBinary,
+    data: Option<nonempty::HexBinary>,
+) -> Result<Response, Error> {
+    ensure!(
+        killswitch::is_contract_active(deps.storage),
+        Error::ExecutionDisabled
+    );
+
+    let token = match info.funds.as_slice() {
+        [token] => token.clone(),
+        _ => bail!(Error::InvalidFunds),
+    };
+    let token_id = state::may_load_token_factory_token_id(deps.storage, &token.denom)
+        .change_context(Error::State)?
+        .ok_or_else(|| report!(Error::UnknownTokenFactoryDenom(token.denom.clone())))?;
+    let amount = Uint256::from(token.amount)
+        .try_into()
+        .change_context(Error::InvalidFunds)?;
+
+    let axelar_chain: ChainNameRaw = axelar_chain_name(deps.storage, deps.querier)?.into();
+    ensure!(
+        destination_chain != axelar_chain,
+        Error::InvalidDestinationChain(destination_chain)
+    );
+
+    let transfer = InterchainTransfer {
+        token_id,
+        source_address: nonempty::HexBinary::try_from(info.sender.as_bytes().to_vec())
+            .expect("sender address must not be empty"),
+        destination_address,
+        amount,
+        data,
+    };
+    let (message, events) = apply_to_hub(
+        deps.storage,
+        env.block.time,
+        axelar_chain.clone(),
+        destination_chain.clone(),
+        transfer.into(),
+    )?;
+
+    let response = send_to_destination(
+        deps.storage,
+        deps.querier,
+        &destination_chain,
+        HubMessage::ReceiveFromHub {
+            source_chain: axelar_chain,
+            message: message.clone(),
+        },
+    )?;
+
+    Ok(response
+        .add_message(token_factory::burn(&env.contract.address, token))
+        .add_event(Event::TransferFromAxelar {
+            destination_chain,
+            message,
+        })
+        .add_events(events))
+}
+
+/// Mints the transferred amount to the recipient. If the transfer carries data, the recipient is a contract
+/// that gets executed with the data as payload and the minted tokens attached.
+fn transfer_to_axelar(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    hub: &Addr,
+    cc_id: CrossChainId,
+    destination_chain: &ChainNameRaw,
+    transfer: InterchainTransfer,
+) -> Result<Response, Error> {
+    let denom = state::may_load_token_factory_denom(storage, transfer.token_id)
+        .change_context(Error::State)?
+        .ok_or_else(|| report!(Error::TokenNotMintable(transfer.token_id)))?;
+    let amount = Uint128::try_from(Uint256::from(transfer.amount)).change_context_lazy(|| {
+        Error::InvalidTransferAmount {
+            source_chain: cc_id.source_chain.clone(),
+            destination_chain: destination_chain.clone(),
+            amount: transfer.amount,
+        }
+    })?;
+    let recipient = str::from_utf8(&transfer.destination_address)
+        .change_context(Error::InvalidDestinationAddress(
+            transfer.destination_address.clone(),
+        ))
+        .and_then(|recipient| {
+            address::validate_cosmwasm_address(api, recipient).change_context(
+                Error::InvalidDestinationAddress(transfer.destination_address.clone()),
+            )
+        })?;
+    let token = Coin { denom, amount };
+
+    let Some(data) = transfer.data else {
+        return Ok(Response::new().add_message(token_factory::mint(hub, token, &recipient)));
+    };
+
+    let executable_msg = AxelarExecutableMsg {
+        cc_id,
+        source_address: transfer
+            .source_address
+            .to_string()
+            .parse()
+            .expect("hex encoded address must be a valid address"),
+        payload: data.into(),
+    };
+
+    let executable: AxelarExecutableClient = AxelarExecutableClient::new(querier, &recipient);
+
+    Ok(Response::new()
+        .add_message(token_factory::mint(hub, token.clone(), hub))
+        .add_message(executable.execute_with_token(executable_msg, token)))
+}
+
 pub fn freeze_chain(deps: DepsMut, chain: ChainNameRaw) -> Result<Response, Error> {
     state::freeze_chain(deps.storage, &chain).change_context(Error::State)?;
 
@@ -361,6 +644,203 @@ pub fn unfreeze_chain(deps: DepsMut, chain: ChainNameRaw) -> Result<Response, Er
     Ok(Response::new())
 }
 
+pub fn freeze_token(deps: DepsMut, token_id: TokenId) -> Result<Response, Error> {
+    state::freeze_token(deps.storage, token_id).change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenFrozen { token_id }))
+}
+
+pub fn unfreeze_token(deps: DepsMut, token_id: TokenId) -> Result<Response, Error> {
+    state::unfreeze_token(deps.storage, token_id).change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenUnfrozen { token_id }))
+}
+
+pub fn update_custom_token_metadata(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+    decimals: u8,
+) -> Result<Response, Error> {
+    ensure_custom_token_not_linked(deps.storage, &chain, &token_address)?;
+
+    state::save_custom_token_metadata(
+        deps.storage,
+        chain.clone(),
+        RegisterTokenMetadata {
+            token_address: token_address.clone(),
+            decimals,
+        },
+    )
+    .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenMetadataUpdated {
+        chain,
+        token_address,
+        decimals,
+    }))
+}
+
+/// Removes the metadata of a custom token so it can be registered again from its chain
+pub fn remove_custom_token_metadata(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+) -> Result<Response, Error> {
+    ensure_custom_token_not_linked(deps.storage, &chain, &token_address)?;
+
+    state::remove_custom_token_metadata(deps.storage, chain.clone(), token_address.clone());
+
+    Ok(Response::new().add_event(Event::TokenMetadataRemoved {
+        chain,
+        token_address,
+    }))
+}
+
+/// Custom token metadata can only be changed as long as no token instance has been linked to it
+fn ensure_custom_token_not_linked(
+    storage: &dyn Storage,
+    chain: &ChainNameRaw,
+    token_address: &nonempty::HexBinary,
+) -> Result<(), Error> {
+    state::may_load_custom_token(storage, chain.clone(), token_address.clone())
+        .change_context(Error::State)?
+        .ok_or_else(|| report!(Error::TokenNotRegistered(token_address.clone())))?;
+
+    ensure!(
+        !state::is_custom_token_linked(storage, chain.clone(), token_address.clone()),
+        Error::TokenAlreadyLinked {
+            chain: chain.clone(),
+            token_address: token_address.clone(),
+        }
+    );
+
+    Ok(())
+}
+
+pub fn set_flow_limit(
+    deps: DepsMut,
+    token_id: TokenId,
+    chain: ChainNameRaw,
+    flow_limit: Option<nonempty::Uint256>,
+) -> Result<Response, Error> {
+    let mut token_instance = state::may_load_token_instance(deps.storage, chain.clone(), token_id)
+        .change_context(Error::State)?
+        .ok_or_else(|| {
+            report!(Error::TokenNotDeployed {
+                token_id,
+                chain: chain.clone()
+            })
+        })?;
+
+    token_instance.flow_limit = flow_limit;
+    state::save_token_instance(deps.storage, chain.clone(), token_id, &token_instance)
+        .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::FlowLimitSet {
+        token_id,
+        chain,
+        flow_limit,
+    }))
+}
+
+pub fn set_token_transfer_fee(
+    deps: DepsMut,
+    token_id: TokenId,
+    fee: Option<u16>,
+) -> Result<Response, Error> {
+    ensure_valid_transfer_fee(fee)?;
+
+    state::save_token_transfer_fee(deps.storage, token_id, fee).change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenTransferFeeSet { token_id, fee }))
+}
+
+pub fn set_chain_pair_transfer_fee(
+    deps: DepsMut,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    fee: Option<u16>,
+) -> Result<Response, Error> {
+    ensure_valid_transfer_fee(fee)?;
+
+    state::save_chain_pair_transfer_fee(
+        deps.storage,
+        source_chain.clone(),
+        destination_chain.clone(),
+        fee,
+    )
+    .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::ChainPairTransferFeeSet {
+        source_chain,
+        destination_chain,
+        fee,
+    }))
+}
+
+fn ensure_valid_transfer_fee(fee: Option<u16>) -> Result<(), Error> {
+    match fee {
+        Some(fee) if fee >= interceptors::BASIS_POINTS => bail!(Error::InvalidTransferFee(fee)),
+        _ => Ok(()),
+    }
+}
+
+/// Sends all fees accrued for the token on the chain to the recipient on that chain.
+/// The fees are transferred by the hub like any other interchain transfer, so the token's supply on the chain is restored.
+pub fn withdraw_fees(
+    deps: DepsMut,
+    env: Env,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    recipient: nonempty::HexBinary,
+) -> Result<Response, Error> {
+    ensure_chain_not_frozen(deps.storage, &chain)?;
+    ensure_token_not_frozen(deps.storage, token_id)?;
+
+    let axelar_chain_name = axelar_chain_name(deps.storage, deps.querier)?;
+    ensure!(
+        chain != axelar_chain_name,
+        Error::InvalidDestinationChain(chain)
+    );
+
+    let amount: nonempty::Uint256 = state::load_accrued_fees(deps.storage, chain.clone(), token_id)
+        .change_context(Error::State)?
+        .try_into()
+        .change_context_lazy(|| Error::NoAccruedFees {
+            token_id,
+            chain: chain.clone(),
+        })?;
+    state::remove_accrued_fees(deps.storage, chain.clone(), token_id);
+
+    let transfer = InterchainTransfer {
+        token_id,
+        source_address: nonempty::HexBinary::try_from(env.contract.address.as_bytes().to_vec())
+            .expect("contract address must not be empty"),
+        destination_address: recipient.clone(),
+        amount,
+        data: None,
+    };
+    interceptors::add_supply_amount(deps.storage, &chain, &transfer)?;
+
+    let response = send_to_destination(
+        deps.storage,
+        deps.querier,
+        &chain,
+        HubMessage::ReceiveFromHub {
+            source_chain: axelar_chain_name.into(),
+            message: transfer.into(),
+        },
+    )?;
+
+    Ok(response.add_event(Event::FeesWithdrawn {
+        token_id,
+        chain,
+        recipient,
+        amount,
+    }))
+}
+
 pub fn disable_execution(deps: DepsMut) -> Result<Response, Error> {
     killswitch::engage(deps.storage, Event::ExecutionDisabled).change_context(Error::State)
 }
@@ -415,9 +895,10 @@ mod tests {
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
     use axelar_wasm_std::{assert_err_contains, killswitch, nonempty, permission_control};
     use axelarnet_gateway::msg::QueryMsg;
-    use cosmwasm_std::testing::{mock_dependencies, MockApi, MockQuerier};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier};
     use cosmwasm_std::{
-        from_json, to_json_binary, HexBinary, MemoryStorage, OwnedDeps, Uint256, WasmQuery,
+        from_json, to_json_binary, HexBinary, MemoryStorage, OwnedDeps, QuerierWrapper, Uint256,
+        WasmQuery,
     };
     use router_api::{ChainName, ChainNameRaw, CrossChainId};
 
@@ -426,11 +907,12 @@ mod tests {
         disable_execution, enable_execution, execute_message, freeze_chain, register_chain,
         register_chains, unfreeze_chain, update_chain, Error,
     };
+    use crate::events::Event;
     use crate::msg::TruncationConfig;
     use crate::state::{self, Config};
     use crate::{
         msg, DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, Message,
-        RegisterTokenMetadata, TokenId,
+        PayloadCodec, RegisterTokenMetadata, TokenId,
     };
 
     const SOLANA: &str = "solana";
@@ -473,7 +955,10 @@ mod tests {
         };
 
         assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id.clone(),
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
@@ -494,7 +979,10 @@ mod tests {
         };
 
         let res = execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id.clone(),
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
@@ -515,7 +1003,10 @@ mod tests {
             .into(),
         };
         assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id.clone(),
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.abi_encode(),
@@ -544,7 +1035,10 @@ mod tests {
             .into(),
         };
         let res = execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             CrossChainId {
                 source_chain: source_chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -561,7 +1055,10 @@ mod tests {
         assert_ok!(unfreeze_chain(deps.as_mut(), source_chain.clone()));
 
         assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             CrossChainId {
                 source_chain,
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -604,7 +1101,10 @@ mod tests {
         };
 
         let res = execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id.clone(),
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
@@ -614,7 +1114,10 @@ mod tests {
         assert_ok!(unfreeze_chain(deps.as_mut(), destination_chain));
 
         assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id,
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
@@ -652,7 +1155,10 @@ mod tests {
         };
 
         assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             cc_id.clone(),
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
@@ -670,7 +1176,8 @@ mod tests {
                 truncation: TruncationConfig {
                     max_uint: Uint256::one().try_into().unwrap(),
                     max_decimals_when_truncating: 16u8
-                }
+                },
+                payload_codec: PayloadCodec::Abi,
             }
         ));
         assert_err_contains!(
@@ -682,7 +1189,8 @@ mod tests {
                     truncation: TruncationConfig {
                         max_uint: Uint256::one().try_into().unwrap(),
                         max_decimals_when_truncating: 16u8
-                    }
+                    },
+                    payload_codec: PayloadCodec::Abi,
                 }
             ),
             Error,
@@ -701,6 +1209,7 @@ mod tests {
                     max_uint: Uint256::MAX.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
             msg::ChainConfig {
                 chain: XRPL.parse().unwrap(),
@@ -709,6 +1218,7 @@ mod tests {
                     max_uint: Uint256::MAX.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8,
                 },
+                payload_codec: PayloadCodec::Abi,
             },
         ];
         assert_ok!(register_chains(deps.as_mut(), chains[0..1].to_vec()));
@@ -769,6 +1279,7 @@ mod tests {
 
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             source_chain.clone(),
             destination_chain.clone(),
             msg.clone()
@@ -788,6 +1299,7 @@ mod tests {
         // check the other direction
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             destination_chain,
             source_chain,
             msg
@@ -837,6 +1349,7 @@ mod tests {
 
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             source_chain.clone(),
             destination_chain.clone(),
             msg.clone()
@@ -849,6 +1362,7 @@ mod tests {
         // check the other direction
         let res = assert_ok!(apply_to_hub(
             deps.as_mut().storage,
+            mock_env().block.time,
             destination_chain,
             source_chain,
             msg
@@ -859,7 +1373,7 @@ mod tests {
         assert_eq!(Uint256::from(transfer.amount), transfer_amount,);
     }
 
-    fn get_transfer(message: Message) -> InterchainTransfer {
+    fn get_transfer((message, _): (Message, Vec<Event>)) -> InterchainTransfer {
         match message {
             Message::InterchainTransfer(transfer) => transfer,
             _ => panic!("wrong msg type returned"),
@@ -900,7 +1414,10 @@ mod tests {
 
         assert_err_contains!(
             execute_message(
-                deps.as_mut(),
+                &mut deps.storage,
+                &deps.api,
+                QuerierWrapper::new(&deps.querier),
+                &mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -945,7 +1462,10 @@ mod tests {
 
         assert_err_contains!(
             execute_message(
-                deps.as_mut(),
+                &mut deps.storage,
+                &deps.api,
+                QuerierWrapper::new(&deps.querier),
+                &mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -989,7 +1509,10 @@ mod tests {
 
         assert_err_contains!(
             execute_message(
-                deps.as_mut(),
+                &mut deps.storage,
+                &deps.api,
+                QuerierWrapper::new(&deps.querier),
+                &mock_env(),
                 CrossChainId {
                     source_chain: source_chain.clone(),
                     message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1017,7 +1540,10 @@ mod tests {
         });
 
         let res = assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             CrossChainId {
                 source_chain: chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1051,7 +1577,10 @@ mod tests {
         };
 
         let res = assert_ok!(execute_message(
-            deps.as_mut(),
+            &mut deps.storage,
+            &deps.api,
+            QuerierWrapper::new(&deps.querier),
+            &mock_env(),
             CrossChainId {
                 source_chain: source_chain.clone(),
                 message_id: HexTxHashAndEventIndex::new([1u8; 32], 0u32)
@@ -1128,7 +1657,8 @@ mod tests {
                     truncation: TruncationConfig {
                         max_uint: Uint256::MAX.try_into().unwrap(),
                         max_decimals_when_truncating: 16u8
-                    }
+                    },
+                    payload_codec: PayloadCodec::Abi,
                 }
             ));
         }
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use axelarnet_gateway::msg::ExecuteMsg as AxelarnetGatewayExecuteMsg;
+use cosmwasm_std::{
+    from_json, CosmosMsg, Deps, Env, HexBinary, Order, Record, Response, Storage, WasmMsg,
+};
+use error_stack::{FrameKind, Report};
+use router_api::{Address, ChainNameRaw, CrossChainId};
+
+use super::{execute_message, Error};
+use crate::msg::{SimulateMessageResponse, SimulationError};
+use crate::state;
+
+/// Messages are executed with a cross-chain id, but a simulated message doesn't have a message id yet
+const SIMULATED_MESSAGE_ID: &str = "simulated-message";
+
+/// Runs [super::execute_message] against the current state without persisting any changes,
+/// and returns how the message would be handled.
+pub fn simulate_message(
+    deps: Deps,
+    env: Env,
+    source_chain: ChainNameRaw,
+    source_address: Address,
+    payload: HexBinary,
+) -> SimulateMessageResponse {
+    let mut storage = SimulationStorage::new(deps.storage);
+    let cc_id = CrossChainId::new(source_chain, SIMULATED_MESSAGE_ID)
+        .expect("simulated message id must be valid");
+
+    match execute_message(
+        &mut storage,
+        deps.api,
+        deps.querier,
+        &env,
+        cc_id,
+        source_address,
+        payload,
+    ) {
+        Ok(response) => simulated_response(&storage, response),
+        Err(report) => SimulateMessageResponse::Rejected {
+            error: simulation_error(&report),
+        },
+    }
+}
+
+/// Messages for other chains leave the hub through a call to the axelarnet gateway,
+/// all other messages are handled by the hub itself.
+fn simulated_response(storage: &dyn Storage, response: Response) -> SimulateMessageResponse {
+    let gateway = state::load_config(storage).axelarnet_gateway;
+
+    response
+        .messages
+        .into_iter()
+        .find_map(|sub_msg| match sub_msg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) if contract_addr == gateway.as_str() => match from_json(msg) {
+                Ok(AxelarnetGatewayExecuteMsg::CallContract {
+                    destination_chain,
+                    payload,
+                    ..
+                }) => Some(SimulateMessageResponse::Forwarded {
+                    destination_chain: destination_chain.into(),
+                    payload,
+                }),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap_or(SimulateMessageResponse::HandledByHub)
+}
+
+fn simulation_error(report: &Report<Error>) -> SimulationError {
+    SimulationError {
+        code: report.current_context().as_ref().to_string(),
+        messages: report
+            .frames()
+            .filter_map(|frame| match frame.kind() {
+                FrameKind::Context(context) => Some(context.to_string()),
+                FrameKind::Attachment(_) => None,
+            })
+            .collect(),
+    }
+}
+
+/// Storage that reads through to the contract's storage and keeps all writes in memory,
+/// so the execution logic can run as part of a query.
+struct SimulationStorage<'a> {
+    base: &'a dyn Storage,
+    /// written values, `None` marks a removed key
+    changes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> SimulationStorage<'a> {
+    fn new(base: &'a dyn Storage) -> Self {
+        Self {
+            base,
+            changes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Storage for SimulationStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.changes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        if matches!((start, end), (Some(start), Some(end)) if start >= end) {
+            return Box::new(std::iter::empty());
+        }
+
+        let mut records: BTreeMap<_, _> = self.base.range(start, end, Order::Ascending).collect();
+
+        let bounds = (
+            start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+            end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        );
+        for (key, value) in self.changes.range(bounds) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.changes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.changes.insert(key.to_vec(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Order, Storage};
+
+    use super::SimulationStorage;
+
+    #[test]
+    fn simulation_storage_overlays_changes_without_writing_through() {
+        let mut base = MockStorage::new();
+        base.set(b"a", b"1");
+        base.set(b"b", b"2");
+        base.set(b"c", b"3");
+
+        let mut storage = SimulationStorage::new(&base);
+        storage.set(b"b", b"20");
+        storage.remove(b"c");
+        storage.set(b"d", b"4");
+
+        assert_eq!(storage.get(b"a"), Some(b"1".to_vec()));
+        assert_eq!(storage.get(b"b"), Some(b"20".to_vec()));
+        assert_eq!(storage.get(b"c"), None);
+        assert_eq!(
+            storage
+                .range(Some(b"b".as_slice()), None, Order::Descending)
+                .collect::<Vec<_>>(),
+            vec![
+                (b"d".to_vec(), b"4".to_vec()),
+                (b"b".to_vec(), b"20".to_vec())
+            ]
+        );
+        assert_eq!(
+            storage
+                .range(None, Some(b"d".as_slice()), Order::Ascending)
+                .collect::<Vec<_>>(),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"20".to_vec())
+            ]
+        );
+
+        assert_eq!(base.get(b"b"), Some(b"2".to_vec()));
+        assert_eq!(base.get(b"c"), Some(b"3".to_vec()));
+        assert_eq!(base.get(b"d"), None);
+    }
+}
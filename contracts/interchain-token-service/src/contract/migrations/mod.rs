@@ -0,0 +1 @@
+pub mod v1_0_0;
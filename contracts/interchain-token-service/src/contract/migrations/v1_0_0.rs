@@ -0,0 +1,255 @@
+use axelar_wasm_std::IntoContractError;
+use cosmwasm_std::Storage;
+use error_stack::{Result, ResultExt};
+
+use crate::contract::query;
+use crate::events::Event;
+use crate::{state, TokenConfig, TokenId};
+
+#[derive(thiserror::Error, Debug, IntoContractError)]
+pub enum Error {
+    #[error("failed to migrate the state")]
+    State,
+    #[error("failed to check the supply of token {0}")]
+    CheckSupply(TokenId),
+}
+
+/// Number of token configs loaded at once while seeding the origin outflow
+const TOKEN_CONFIGS_PAGE_SIZE: u32 = 100;
+
+pub fn migrate(storage: &mut dyn Storage) -> Result<Vec<Event>, Error> {
+    state::index_token_chains(storage).change_context(Error::State)?;
+    state::mark_all_custom_tokens_linked(storage).change_context(Error::State)?;
+    seed_origin_outflow(storage)
+}
+
+/// The flow of tokens out of their origin chain was not tracked before, so the amount locked on the origin chain
+/// is seeded with the amount of each token that is currently accounted for on the hub.
+/// Returns an event with the seeded amount of each token, so the migration can be audited.
+///
+/// Tokens are loaded page by page, so memory usage is bounded by the page size. The gas cost still grows linearly
+/// with the registry: every token costs one supply check, which reads each of its token instances once,
+/// plus one write of its origin instance. The migration proposal's gas limit must be sized for the number
+/// of registered tokens and token instances at the time of the upgrade.
+fn seed_origin_outflow(storage: &mut dyn Storage) -> Result<Vec<Event>, Error> {
+    let mut events = vec![];
+    let mut start_after = None;
+
+    loop {
+        let token_configs =
+            state::load_token_configs(storage, start_after, TOKEN_CONFIGS_PAGE_SIZE)
+                .change_context(Error::State)?;
+        let Some((last_token_id, _)) = token_configs.last() else {
+            return Ok(events);
+        };
+        start_after = Some(*last_token_id);
+
+        for (token_id, token_config) in token_configs {
+            if let Some(event) = seed_token_origin_outflow(storage, token_id, token_config)? {
+                events.push(event);
+            }
+        }
+    }
+}
+
+fn seed_token_origin_outflow(
+    storage: &mut dyn Storage,
+    token_id: TokenId,
+    token_config: TokenConfig,
+) -> Result<Option<Event>, Error> {
+    let Some(mut origin_instance) =
+        state::may_load_token_instance(storage, token_config.origin_chain.clone(), token_id)
+            .change_context(Error::State)?
+    else {
+        return Ok(None);
+    };
+
+    let check = query::check_supply(storage, token_id, token_config.clone())
+        .change_context(Error::CheckSupply(token_id))?;
+    origin_instance.total_outflow = check
+        .total_supply_on_origin
+        .saturating_add(check.total_accrued_fees_on_origin)
+        .saturating_add(check.total_rounding_loss_on_origin);
+
+    state::save_token_instance(
+        storage,
+        token_config.origin_chain.clone(),
+        token_id,
+        &origin_instance,
+    )
+    .change_context(Error::State)?;
+
+    Ok(Some(Event::OriginOutflowSeeded {
+        token_id,
+        origin_chain: token_config.origin_chain,
+        total_outflow: origin_instance.total_outflow,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use assert_ok::assert_ok;
+    use axelar_wasm_std::nonempty;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{HexBinary, Uint256};
+    use cw_storage_plus::Map;
+    use router_api::ChainNameRaw;
+
+    use super::{migrate, TOKEN_CONFIGS_PAGE_SIZE};
+    use crate::contract::query;
+    use crate::events::Event;
+    use crate::state::{self, TokenDeploymentType, TokenInstance};
+    use crate::{RegisterTokenMetadata, TokenConfig, TokenId, TokenSupply};
+
+    const TOKEN_INSTANCE: Map<(ChainNameRaw, TokenId), TokenInstance> = Map::new("token_instance");
+
+    #[test]
+    fn migrate_indexes_chains_of_existing_token_instances() {
+        let mut deps = mock_dependencies();
+        let token_id = TokenId::new([1; 32]);
+        let chains: Vec<ChainNameRaw> =
+            vec!["ethereum".parse().unwrap(), "avalanche".parse().unwrap()];
+
+        // token instances saved before the index existed
+        for chain in &chains {
+            TOKEN_INSTANCE
+                .save(
+                    deps.as_mut().storage,
+                    (chain.clone(), token_id),
+                    &TokenInstance::new_on_origin(18),
+                )
+                .unwrap();
+        }
+        assert!(assert_ok!(state::load_token_instances(&deps.storage, token_id)).is_empty());
+
+        assert_ok!(migrate(deps.as_mut().storage));
+
+        let token_instances = assert_ok!(state::load_token_instances(&deps.storage, token_id));
+        assert_eq!(token_instances.len(), 2);
+        assert!(chains
+            .iter()
+            .all(|chain| token_instances.contains_key(chain)));
+    }
+
+    #[test]
+    fn migrate_marks_existing_custom_tokens_as_linked() {
+        let mut deps = mock_dependencies();
+        let chain: ChainNameRaw = "ethereum".parse().unwrap();
+        let token_address: nonempty::HexBinary = HexBinary::from([1; 32]).try_into().unwrap();
+
+        state::save_custom_token_metadata(
+            deps.as_mut().storage,
+            chain.clone(),
+            RegisterTokenMetadata {
+                decimals: 18,
+                token_address: token_address.clone(),
+            },
+        )
+        .unwrap();
+        assert!(!state::is_custom_token_linked(
+            &deps.storage,
+            chain.clone(),
+            token_address.clone()
+        ));
+
+        assert_ok!(migrate(deps.as_mut().storage));
+
+        assert!(state::is_custom_token_linked(
+            &deps.storage,
+            chain,
+            token_address
+        ));
+    }
+
+    #[test]
+    fn migrate_seeds_outflow_of_origin_chain_with_supply_on_other_chains() {
+        let mut deps = mock_dependencies();
+        let token_id = TokenId::new([1; 32]);
+        let origin_chain: ChainNameRaw = "ethereum".parse().unwrap();
+        let other_chain: ChainNameRaw = "sui".parse().unwrap();
+
+        let token_config = TokenConfig {
+            origin_chain: origin_chain.clone(),
+            frozen: false,
+        };
+        state::save_token_config(deps.as_mut().storage, token_id, &token_config).unwrap();
+        state::save_token_instance(
+            deps.as_mut().storage,
+            origin_chain.clone(),
+            token_id,
+            &TokenInstance::new_on_origin(18),
+        )
+        .unwrap();
+        state::save_token_instance(
+            deps.as_mut().storage,
+            other_chain,
+            token_id,
+            &TokenInstance {
+                supply: TokenSupply::Tracked(Uint256::from(1_000u128)),
+                ..TokenInstance::new(&TokenDeploymentType::Trustless, 6)
+            },
+        )
+        .unwrap();
+
+        let check = query::check_supply(&deps.storage, token_id, token_config.clone()).unwrap();
+        assert!(!check.violations.is_empty());
+
+        let events = assert_ok!(migrate(deps.as_mut().storage));
+
+        let origin_instance =
+            state::may_load_token_instance(&deps.storage, origin_chain.clone(), token_id)
+                .unwrap()
+                .unwrap();
+        assert_eq!(
+            origin_instance.total_outflow,
+            Uint256::from(1_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            events
+                .into_iter()
+                .map(cosmwasm_std::Event::from)
+                .collect::<Vec<_>>(),
+            vec![cosmwasm_std::Event::from(Event::OriginOutflowSeeded {
+                token_id,
+                origin_chain,
+                total_outflow: origin_instance.total_outflow,
+            })]
+        );
+
+        let check = query::check_supply(&deps.storage, token_id, token_config).unwrap();
+        assert!(check.violations.is_empty());
+    }
+
+    #[test]
+    fn migrate_seeds_outflow_of_tokens_across_pages() {
+        let mut deps = mock_dependencies();
+        let origin_chain: ChainNameRaw = "ethereum".parse().unwrap();
+        let token_count = TOKEN_CONFIGS_PAGE_SIZE * 2 + 1;
+
+        for i in 0..token_count {
+            let mut token_id = [0; 32];
+            token_id[..4].copy_from_slice(&i.to_be_bytes());
+            let token_id = TokenId::new(token_id);
+
+            state::save_token_config(
+                deps.as_mut().storage,
+                token_id,
+                &TokenConfig {
+                    origin_chain: origin_chain.clone(),
+                    frozen: false,
+                },
+            )
+            .unwrap();
+            state::save_token_instance(
+                deps.as_mut().storage,
+                origin_chain.clone(),
+                token_id,
+                &TokenInstance::new_on_origin(18),
+            )
+            .unwrap();
+        }
+
+        let events = assert_ok!(migrate(deps.as_mut().storage));
+        assert_eq!(events.len(), token_count as usize);
+    }
+}
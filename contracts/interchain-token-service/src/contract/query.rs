@@ -1,12 +1,24 @@
-use axelar_wasm_std::IntoContractError;
-use cosmwasm_std::{to_json_binary, Binary, Deps};
-use error_stack::{Result, ResultExt};
-use router_api::ChainNameRaw;
+use std::collections::HashMap;
 
+use axelar_wasm_std::{nonempty, IntoContractError};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env, HexBinary, Storage, Timestamp, Uint256};
+use error_stack::{report, Result, ResultExt};
+use itertools::Itertools;
+use router_api::{Address, ChainNameRaw};
+
+use crate::msg::{
+    ChainSupply, InvariantCheckResponse, InvariantViolation, TokenConfigResponse,
+    TokenInstanceResponse,
+};
 use crate::state::{
-    load_all_its_contracts, may_load_its_contract, may_load_token_config, may_load_token_instance,
+    load_accrued_fees, load_all_its_contracts, load_chain_token_instances, load_token_configs,
+    load_token_instances, load_transfer_fee, may_load_custom_token, may_load_its_contract,
+    may_load_token_config, may_load_token_instance,
 };
-use crate::TokenId;
+use crate::{TokenConfig, TokenId, TokenInstance, TokenSupply};
+
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 1000;
 
 #[derive(thiserror::Error, Debug, IntoContractError)]
 pub enum Error {
@@ -14,6 +26,15 @@ pub enum Error {
     JsonSerialization,
     #[error("state error")]
     State,
+    #[error("token {0} is not deployed on its origin chain")]
+    OriginTokenInstanceNotFound(TokenId),
+    #[error("supply of token {token_id} on chain {chain} can't be converted to the decimals of the origin chain")]
+    SupplyConversion {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+    },
+    #[error("total supply of token {0} overflows")]
+    TotalSupplyOverflow(TokenId),
 }
 
 pub fn its_contract(deps: Deps, chain: ChainNameRaw) -> Result<Binary, Error> {
@@ -27,14 +48,272 @@ pub fn all_its_contracts(deps: Deps) -> Result<Binary, Error> {
     to_json_binary(&contract_addresses).change_context(Error::JsonSerialization)
 }
 
-pub fn token_instance(deps: Deps, chain: ChainNameRaw, token_id: TokenId) -> Result<Binary, Error> {
-    let token_instance =
-        may_load_token_instance(deps.storage, chain, token_id).change_context(Error::State)?;
+pub fn token_instance(
+    deps: Deps,
+    block_time: Timestamp,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Binary, Error> {
+    let token_instance = may_load_token_instance(deps.storage, chain, token_id)
+        .change_context(Error::State)?
+        .map(|token_instance| current_flow(token_instance, block_time));
     to_json_binary(&token_instance).change_context(Error::JsonSerialization)
 }
 
+pub fn custom_token_metadata(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+) -> Result<Binary, Error> {
+    let metadata =
+        may_load_custom_token(deps.storage, chain, token_address).change_context(Error::State)?;
+    to_json_binary(&metadata).change_context(Error::JsonSerialization)
+}
+
+pub fn transfer_fee(
+    deps: Deps,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Binary, Error> {
+    let fee = load_transfer_fee(deps.storage, source_chain, destination_chain, token_id)
+        .change_context(Error::State)?;
+    to_json_binary(&fee).change_context(Error::JsonSerialization)
+}
+
+pub fn accrued_fees(deps: Deps, chain: ChainNameRaw, token_id: TokenId) -> Result<Binary, Error> {
+    let fees = load_accrued_fees(deps.storage, chain, token_id).change_context(Error::State)?;
+    to_json_binary(&fees).change_context(Error::JsonSerialization)
+}
+
+pub fn simulate_message(
+    deps: Deps,
+    env: Env,
+    source_chain: ChainNameRaw,
+    source_address: Address,
+    payload: HexBinary,
+) -> Result<Binary, Error> {
+    let response =
+        super::execute::simulate_message(deps, env, source_chain, source_address, payload);
+    to_json_binary(&response).change_context(Error::JsonSerialization)
+}
+
+pub fn token_instances(
+    deps: Deps,
+    block_time: Timestamp,
+    token_id: TokenId,
+) -> Result<Binary, Error> {
+    let token_instances = load_token_instances(deps.storage, token_id)
+        .change_context(Error::State)?
+        .into_iter()
+        .map(|(chain, token_instance)| (chain, current_flow(token_instance, block_time)))
+        .collect::<HashMap<_, _>>();
+    to_json_binary(&token_instances).change_context(Error::JsonSerialization)
+}
+
+pub fn chain_tokens(
+    deps: Deps,
+    block_time: Timestamp,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: Option<u32>,
+) -> Result<Binary, Error> {
+    let token_instances =
+        load_chain_token_instances(deps.storage, chain, start_after, page_limit(limit))
+            .change_context(Error::State)?
+            .into_iter()
+            .map(|(token_id, token_instance)| TokenInstanceResponse {
+                token_id,
+                instance: current_flow(token_instance, block_time),
+            })
+            .collect::<Vec<_>>();
+    to_json_binary(&token_instances).change_context(Error::JsonSerialization)
+}
+
+pub fn tokens(
+    deps: Deps,
+    start_after: Option<TokenId>,
+    limit: Option<u32>,
+) -> Result<Binary, Error> {
+    let tokens = load_token_configs(deps.storage, start_after, page_limit(limit))
+        .change_context(Error::State)?
+        .into_iter()
+        .map(|(token_id, config)| TokenConfigResponse { token_id, config })
+        .collect::<Vec<_>>();
+    to_json_binary(&tokens).change_context(Error::JsonSerialization)
+}
+
+fn page_limit(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT)
+}
+
 pub fn token_config(deps: Deps, token_id: TokenId) -> Result<Binary, Error> {
     let token_config =
         may_load_token_config(deps.storage, &token_id).change_context(Error::State)?;
     to_json_binary(&token_config).change_context(Error::JsonSerialization)
 }
+
+pub fn invariant_check(deps: Deps, token_id: TokenId) -> Result<Binary, Error> {
+    let response = may_load_token_config(deps.storage, &token_id)
+        .change_context(Error::State)?
+        .map(|token_config| check_supply(deps.storage, token_id, token_config))
+        .transpose()?;
+    to_json_binary(&response).change_context(Error::JsonSerialization)
+}
+
+/// Compares the amount of the token that can be bridged back to its origin chain with the amount locked on it.
+/// All amounts are converted to the decimals of the origin chain.
+pub fn check_supply(
+    storage: &dyn Storage,
+    token_id: TokenId,
+    TokenConfig { origin_chain, .. }: TokenConfig,
+) -> Result<InvariantCheckResponse, Error> {
+    let token_instances = load_token_instances(storage, token_id).change_context(Error::State)?;
+    let origin_instance = token_instances
+        .get(&origin_chain)
+        .ok_or_else(|| report!(Error::OriginTokenInstanceNotFound(token_id)))?;
+    let origin_decimals = origin_instance.decimals;
+    let (outflow, inflow) = (origin_instance.total_outflow, origin_instance.total_inflow);
+
+    let chains = token_instances
+        .into_iter()
+        .sorted_by(|(chain_a, _), (chain_b, _)| chain_a.as_ref().cmp(chain_b.as_ref()))
+        .map(|(chain, token_instance)| {
+            let accrued_fees =
+                load_accrued_fees(storage, chain.clone(), token_id).change_context(Error::State)?;
+            chain_supply(
+                token_id,
+                origin_decimals,
+                chain,
+                token_instance,
+                accrued_fees,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total = |amounts: Vec<Uint256>| {
+        amounts
+            .into_iter()
+            .try_fold(Uint256::zero(), Uint256::checked_add)
+            .change_context(Error::TotalSupplyOverflow(token_id))
+    };
+    let total_supply_on_origin = total(
+        chains
+            .iter()
+            .filter_map(|chain_supply| chain_supply.supply_on_origin)
+            .collect(),
+    )?;
+    let total_rounding_loss_on_origin = total(
+        chains
+            .iter()
+            .map(|chain_supply| chain_supply.rounding_loss_on_origin)
+            .collect(),
+    )?;
+    let total_accrued_fees_on_origin = total(
+        chains
+            .iter()
+            .map(|chain_supply| chain_supply.accrued_fees_on_origin)
+            .collect(),
+    )?;
+
+    let locked_on_origin = outflow.saturating_sub(inflow);
+    let inflow_violation = (inflow > outflow)
+        .then_some(InvariantViolation::OriginInflowExceedsOutflow { outflow, inflow });
+
+    // the supply on chains that don't track it is unknown, so it can only be checked if all non-origin chains track it
+    let non_origin_supplies = chains
+        .iter()
+        .filter(|chain_supply| chain_supply.chain != origin_chain)
+        .map(|chain_supply| chain_supply.supply_on_origin)
+        .collect::<Option<Vec<_>>>();
+    let supply_violation = match non_origin_supplies {
+        Some(supplies) => {
+            let claimable = total(supplies)?
+                .checked_add(total_accrued_fees_on_origin)
+                .change_context(Error::TotalSupplyOverflow(token_id))?;
+
+            (claimable > locked_on_origin).then_some(InvariantViolation::UnbackedSupply {
+                claimable,
+                locked: locked_on_origin,
+            })
+        }
+        None => None,
+    };
+
+    Ok(InvariantCheckResponse {
+        token_id,
+        origin_chain,
+        origin_decimals,
+        chains,
+        total_supply_on_origin,
+        total_rounding_loss_on_origin,
+        total_accrued_fees_on_origin,
+        locked_on_origin,
+        violations: [inflow_violation, supply_violation]
+            .into_iter()
+            .flatten()
+            .collect(),
+    })
+}
+
+/// Converts the supply, rounding loss and accrued fees of a token on a chain to the decimals of the origin chain,
+/// the same way transfer amounts are scaled
+fn chain_supply(
+    token_id: TokenId,
+    origin_decimals: u8,
+    chain: ChainNameRaw,
+    TokenInstance {
+        supply,
+        decimals,
+        rounding_loss,
+        ..
+    }: TokenInstance,
+    accrued_fees: Uint256,
+) -> Result<ChainSupply, Error> {
+    let supply_conversion = || Error::SupplyConversion {
+        token_id,
+        chain: chain.clone(),
+    };
+
+    let scaling_factor = Uint256::from_u128(10)
+        .checked_pow(origin_decimals.abs_diff(decimals).into())
+        .change_context_lazy(supply_conversion)?;
+    let to_origin_decimals = |amount: Uint256| {
+        if decimals > origin_decimals {
+            Ok(amount
+                .checked_div(scaling_factor)
+                .expect("scaling_factor must be non-zero"))
+        } else {
+            amount
+                .checked_mul(scaling_factor)
+                .change_context_lazy(supply_conversion)
+        }
+    };
+
+    let supply_on_origin = match supply {
+        TokenSupply::Tracked(supply) => Some(to_origin_decimals(supply)?),
+        TokenSupply::Untracked => None,
+    };
+    let rounding_loss_on_origin = to_origin_decimals(rounding_loss)?;
+    let accrued_fees_on_origin = to_origin_decimals(accrued_fees)?;
+
+    Ok(ChainSupply {
+        chain,
+        decimals,
+        supply,
+        scaling_factor,
+        supply_on_origin,
+        rounding_loss,
+        rounding_loss_on_origin,
+        accrued_fees,
+        accrued_fees_on_origin,
+    })
+}
+
+/// The stored flow might be from a previous epoch, so it is reset if necessary
+fn current_flow(token_instance: TokenInstance, block_time: Timestamp) -> TokenInstance {
+    TokenInstance {
+        flow: token_instance.flow.at(block_time),
+        ..token_instance
+    }
+}
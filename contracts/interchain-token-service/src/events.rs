@@ -1,7 +1,9 @@
 use axelar_wasm_std::{nonempty, IntoEvent};
+use cosmwasm_std::Uint256;
 use router_api::{Address, ChainNameRaw, CrossChainId};
 
 use crate::primitives::Message;
+use crate::TokenId;
 
 #[derive(IntoEvent)]
 pub enum Event {
@@ -15,6 +17,15 @@ pub enum Event {
         token_address: nonempty::HexBinary,
         decimals: u8,
     },
+    TokenMetadataUpdated {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+        decimals: u8,
+    },
+    TokenMetadataRemoved {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+    },
     ItsContractRegistered {
         chain: ChainNameRaw,
         address: Address,
@@ -22,8 +33,53 @@ pub enum Event {
     ItsContractDeregistered {
         chain: ChainNameRaw,
     },
+    TokenFrozen {
+        token_id: TokenId,
+    },
+    TokenUnfrozen {
+        token_id: TokenId,
+    },
+    FlowLimitSet {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        flow_limit: Option<nonempty::Uint256>,
+    },
+    /// The amounts are denominated in the token's decimals on the source chain
+    TransferFeeCharged {
+        token_id: TokenId,
+        source_chain: ChainNameRaw,
+        destination_chain: ChainNameRaw,
+        amount: nonempty::Uint256,
+        fee: nonempty::Uint256,
+        net_amount: nonempty::Uint256,
+    },
+    TokenTransferFeeSet {
+        token_id: TokenId,
+        fee: Option<u16>,
+    },
+    ChainPairTransferFeeSet {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainNameRaw,
+        fee: Option<u16>,
+    },
+    TransferFromAxelar {
+        destination_chain: ChainNameRaw,
+        message: Message,
+    },
+    FeesWithdrawn {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        recipient: nonempty::HexBinary,
+        amount: nonempty::Uint256,
+    },
     ExecutionDisabled,
     ExecutionEnabled,
+    /// Emitted during migration with the amount of the token that is locked on its origin chain
+    OriginOutflowSeeded {
+        token_id: TokenId,
+        origin_chain: ChainNameRaw,
+        total_outflow: Uint256,
+    },
 }
 
 #[cfg(test)]
@@ -2,8 +2,12 @@ mod primitives;
 pub use primitives::*;
 
 mod abi;
+mod bcs;
+mod codec;
+pub use codec::{Codec, PayloadCodec};
 pub mod contract;
 pub mod events;
 pub mod msg;
 mod state;
-pub use state::{TokenConfig, TokenInstance, TokenSupply};
+mod token_factory;
+pub use state::{CustomTokenMetadata, TokenConfig, TokenFlow, TokenInstance, TokenSupply};
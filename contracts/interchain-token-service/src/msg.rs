@@ -3,11 +3,12 @@ use std::collections::HashMap;
 use axelar_wasm_std::nonempty;
 use axelarnet_gateway::AxelarExecutableMsg;
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{HexBinary, Uint256};
 use msgs_derive::EnsurePermissions;
 use router_api::{Address, ChainNameRaw};
 
-use crate::state::{TokenConfig, TokenInstance};
-use crate::TokenId;
+use crate::state::{CustomTokenMetadata, TokenConfig, TokenInstance, TokenSupply};
+use crate::{PayloadCodec, TokenId};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -46,6 +47,72 @@ pub enum ExecuteMsg {
     #[permission(Elevated)]
     UnfreezeChain { chain: ChainNameRaw },
 
+    /// Freeze a token on all chains. Transfers, deployments and links of the token are rejected while it is frozen
+    #[permission(Elevated)]
+    FreezeToken { token_id: TokenId },
+
+    /// Unfreeze a token on all chains
+    #[permission(Elevated)]
+    UnfreezeToken { token_id: TokenId },
+
+    /// Set the maximum net amount of a token that can flow into or out of a chain per epoch.
+    /// The flow limit is removed if `flow_limit` is `None`.
+    #[permission(Elevated)]
+    SetFlowLimit {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        flow_limit: Option<nonempty::Uint256>,
+    },
+
+    /// Correct the metadata of a custom token registered from an edge chain.
+    /// Only allowed as long as the token has not been linked yet.
+    #[permission(Governance)]
+    UpdateCustomTokenMetadata {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+        decimals: u8,
+    },
+
+    /// Remove the metadata of a custom token registered from an edge chain, so it can be registered again.
+    /// Only allowed as long as the token has not been linked yet.
+    #[permission(Governance)]
+    RemoveCustomTokenMetadata {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+    },
+
+    /// Set the fee in basis points that is deducted from interchain transfers of a token.
+    /// It takes precedence over the fee of the chain pair. The token's fee is removed if `fee` is `None`.
+    #[permission(Governance)]
+    SetTokenTransferFee { token_id: TokenId, fee: Option<u16> },
+
+    /// Set the fee in basis points that is deducted from interchain transfers from `source_chain` to `destination_chain`.
+    /// The fee is removed if `fee` is `None`.
+    #[permission(Governance)]
+    SetChainPairTransferFee {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainNameRaw,
+        fee: Option<u16>,
+    },
+
+    /// Send all fees accrued for a token on a chain to the recipient on that chain.
+    /// Fails if the chain or token is frozen, or if the chain is the axelar chain.
+    #[permission(Governance)]
+    WithdrawFees {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+        recipient: nonempty::HexBinary,
+    },
+
+    /// Transfer token factory tokens that represent an interchain token on Axelar to another chain.
+    /// The attached tokens are burnt, and the transfer is sent from the axelar chain to the destination chain.
+    #[permission(Any)]
+    InterchainTransfer {
+        destination_chain: ChainNameRaw,
+        destination_address: nonempty::HexBinary,
+        data: Option<nonempty::HexBinary>,
+    },
+
     #[permission(Elevated)]
     DisableExecution,
 
@@ -58,6 +125,9 @@ pub struct ChainConfig {
     pub chain: ChainNameRaw,
     pub its_edge_contract: Address,
     pub truncation: TruncationConfig,
+    /// The encoding the chain's ITS edge contract uses for message payloads
+    #[serde(default)]
+    pub payload_codec: PayloadCodec,
 }
 
 #[cw_serde]
@@ -75,13 +145,146 @@ pub enum QueryMsg {
     /// Query all registered ITS contract addresses
     #[returns(HashMap<ChainNameRaw, Address>)]
     AllItsContracts,
-    /// Query a token instance on a specific chain
+    /// Query a token instance on a specific chain, including its flow in the current epoch
     #[returns(Option<TokenInstance>)]
     TokenInstance {
         chain: ChainNameRaw,
         token_id: TokenId,
     },
-    /// Query the configuration parameters for a token
+    /// Query the configuration parameters for a token, including whether it is frozen
     #[returns(Option<TokenConfig>)]
     TokenConfig { token_id: TokenId },
+    /// Query all registered tokens with their configuration, ordered by token id.
+    /// Returns at most 100 tokens by default, and at most 1000 tokens per page.
+    #[returns(Vec<TokenConfigResponse>)]
+    Tokens {
+        start_after: Option<TokenId>,
+        limit: Option<u32>,
+    },
+    /// Query the instances of a token on all chains it is deployed to
+    #[returns(HashMap<ChainNameRaw, TokenInstance>)]
+    TokenInstances { token_id: TokenId },
+    /// Query the instances of all tokens deployed to a chain, ordered by token id.
+    /// Returns at most 100 tokens by default, and at most 1000 tokens per page.
+    #[returns(Vec<TokenInstanceResponse>)]
+    ChainTokens {
+        chain: ChainNameRaw,
+        start_after: Option<TokenId>,
+        limit: Option<u32>,
+    },
+    /// Check that the supply of a token on its non-origin chains is backed by the amount locked on its origin chain.
+    /// Returns `None` if the token is not registered.
+    #[returns(Option<InvariantCheckResponse>)]
+    InvariantCheck { token_id: TokenId },
+    /// Query the metadata of a custom token registered from an edge chain
+    #[returns(Option<CustomTokenMetadata>)]
+    CustomTokenMetadata {
+        chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+    },
+    /// Query the fee in basis points that is deducted from transfers of a token between two chains
+    #[returns(u16)]
+    TransferFee {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+    /// Query the fees accrued for a token on a chain, denominated in the token's decimals on that chain
+    #[returns(Uint256)]
+    AccruedFees {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+    /// Dry-run a message from an ITS edge contract against the current state of the hub, as if it was executed
+    /// by the axelarnet gateway. No state is changed.
+    #[returns(SimulateMessageResponse)]
+    SimulateMessage {
+        source_chain: ChainNameRaw,
+        source_address: Address,
+        payload: HexBinary,
+    },
+}
+
+#[cw_serde]
+pub enum SimulateMessageResponse {
+    /// The message would be forwarded to the ITS edge contract of the destination chain with the given payload
+    Forwarded {
+        destination_chain: ChainNameRaw,
+        payload: HexBinary,
+    },
+    /// The message would be handled by the hub itself, e.g. a token metadata registration or a transfer to Axelar
+    HandledByHub,
+    /// The message would be rejected with the given error
+    Rejected { error: SimulationError },
+}
+
+#[cw_serde]
+pub struct SimulationError {
+    /// Identifies why the message would be rejected, e.g. `token_frozen`.
+    /// Codes are the snake case names of the hub's execution errors, so they are stable across error message changes.
+    pub code: String,
+    /// The error messages, ordered from the outermost context to the root cause
+    pub messages: Vec<String>,
+}
+
+#[cw_serde]
+pub struct TokenConfigResponse {
+    pub token_id: TokenId,
+    pub config: TokenConfig,
+}
+
+#[cw_serde]
+pub struct TokenInstanceResponse {
+    pub token_id: TokenId,
+    pub instance: TokenInstance,
+}
+
+#[cw_serde]
+pub struct InvariantCheckResponse {
+    pub token_id: TokenId,
+    pub origin_chain: ChainNameRaw,
+    pub origin_decimals: u8,
+    /// The supply of the token on each chain it is deployed to, including the origin chain
+    pub chains: Vec<ChainSupply>,
+    /// The sum of the tracked supplies, converted to the decimals of the origin chain
+    pub total_supply_on_origin: Uint256,
+    /// The sum of the rounding losses, converted to the decimals of the origin chain
+    pub total_rounding_loss_on_origin: Uint256,
+    /// The sum of the transfer fees that have accrued on the hub and not been withdrawn yet,
+    /// converted to the decimals of the origin chain
+    pub total_accrued_fees_on_origin: Uint256,
+    /// The amount of the token that left the origin chain through the hub minus the amount that returned to it
+    pub locked_on_origin: Uint256,
+    /// The violated invariants, empty if the token's supply is consistent
+    pub violations: Vec<InvariantViolation>,
+}
+
+#[cw_serde]
+pub enum InvariantViolation {
+    /// More of the token returned to the origin chain than left it
+    OriginInflowExceedsOutflow { outflow: Uint256, inflow: Uint256 },
+    /// More of the token can be bridged back to the origin chain than is locked on it.
+    /// The claimable amount is the tracked supply on all non-origin chains plus the accrued fees.
+    /// It is only checked if the supply is tracked on all non-origin chains.
+    UnbackedSupply { claimable: Uint256, locked: Uint256 },
+}
+
+#[cw_serde]
+pub struct ChainSupply {
+    pub chain: ChainNameRaw,
+    pub decimals: u8,
+    pub supply: TokenSupply,
+    /// The factor that amounts are scaled by when transferred between this chain and the origin chain,
+    /// i.e. 10 to the power of the difference in decimals
+    pub scaling_factor: Uint256,
+    /// The tracked supply converted to the decimals of the origin chain
+    pub supply_on_origin: Option<Uint256>,
+    /// The amount of the token truncated from transfers leaving this chain
+    pub rounding_loss: Uint256,
+    /// The rounding loss converted to the decimals of the origin chain
+    pub rounding_loss_on_origin: Uint256,
+    /// The transfer fees accrued on this chain that have not been withdrawn yet
+    pub accrued_fees: Uint256,
+    /// The accrued fees converted to the decimals of the origin chain
+    pub accrued_fees_on_origin: Uint256,
 }
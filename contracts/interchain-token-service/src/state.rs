@@ -2,12 +2,12 @@ use std::collections::HashMap;
 
 use axelar_wasm_std::{nonempty, FnExt, IntoContractError};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, OverflowError, StdError, Storage, Uint256};
-use cw_storage_plus::{Item, Map};
-use error_stack::{report, Result, ResultExt};
+use cosmwasm_std::{Addr, Order, OverflowError, StdError, Storage, Timestamp, Uint256};
+use cw_storage_plus::{Bound, Item, Map};
+use error_stack::{bail, report, Result, ResultExt};
 use router_api::{Address, ChainNameRaw};
 
-use crate::{msg, RegisterTokenMetadata, TokenId};
+use crate::{msg, PayloadCodec, RegisterTokenMetadata, TokenId};
 
 #[derive(thiserror::Error, Debug, IntoContractError)]
 pub enum Error {
@@ -21,6 +21,12 @@ pub enum Error {
     ChainNotFound(ChainNameRaw),
     #[error("chain config for chain {0} not found")]
     ChainConfigNotFound(ChainNameRaw),
+    #[error("token {0} not found")]
+    TokenNotFound(TokenId),
+    #[error("token factory denom for token {0} already exists")]
+    TokenFactoryDenomAlreadyExists(TokenId),
+    #[error("token factory denom {denom} is already used by token {token_id}")]
+    TokenFactoryDenomCollision { denom: String, token_id: TokenId },
     // This is a generic error to use when cw_storage_plus returns an error that is unexpected and
     // should never happen, such as an error encountered when saving data.
     #[error("storage error")]
@@ -37,6 +43,9 @@ pub struct ChainConfig {
     pub truncation: TruncationConfig,
     pub its_address: Address,
     frozen: bool,
+    // chains registered before payload codecs were introduced use ABI encoding
+    #[serde(default)]
+    pub payload_codec: PayloadCodec,
 }
 
 #[cw_serde]
@@ -54,6 +63,7 @@ impl From<msg::ChainConfig> for ChainConfig {
             },
             its_address: value.its_edge_contract,
             frozen: false,
+            payload_codec: value.payload_codec,
         }
     }
 }
@@ -93,6 +103,24 @@ impl TokenSupply {
 pub struct TokenInstance {
     pub supply: TokenSupply,
     pub decimals: u8,
+    /// The maximum net amount of the token that can flow into or out of this chain per epoch.
+    /// The flow is not limited if no limit is set.
+    #[serde(default)]
+    pub flow_limit: Option<nonempty::Uint256>,
+    /// The amounts of the token that flowed into and out of this chain during the current epoch.
+    #[serde(default)]
+    pub flow: TokenFlow,
+    /// The total amount of the token that was truncated from transfers leaving this chain,
+    /// because the destination chain supports fewer decimals. This amount can't be bridged anymore.
+    #[serde(default)]
+    pub rounding_loss: Uint256,
+    /// The total amount of the token that left this chain through the hub, including fees and rounding losses.
+    /// On the origin chain, the total outflow minus the total inflow is the amount expected to be locked.
+    #[serde(default)]
+    pub total_outflow: Uint256,
+    /// The total amount of the token that entered this chain through the hub, including withdrawn fees
+    #[serde(default)]
+    pub total_inflow: Uint256,
 }
 
 impl TokenInstance {
@@ -100,6 +128,11 @@ impl TokenInstance {
         Self {
             supply: TokenSupply::Untracked,
             decimals,
+            flow_limit: None,
+            flow: TokenFlow::default(),
+            rounding_loss: Uint256::zero(),
+            total_outflow: Uint256::zero(),
+            total_inflow: Uint256::zero(),
         }
     }
 
@@ -109,7 +142,49 @@ impl TokenInstance {
             _ => TokenSupply::Untracked,
         };
 
-        Self { supply, decimals }
+        Self {
+            supply,
+            decimals,
+            flow_limit: None,
+            flow: TokenFlow::default(),
+            rounding_loss: Uint256::zero(),
+            total_outflow: Uint256::zero(),
+            total_inflow: Uint256::zero(),
+        }
+    }
+}
+
+/// Duration of a flow limit epoch in seconds (6 hours), matching the flow limit epoch of the ITS edge contracts on EVM chains
+const FLOW_LIMIT_EPOCH_DURATION: u64 = 21_600;
+
+/// The flow of a token on a specific chain during one epoch, in the token's units on that chain.
+#[cw_serde]
+#[derive(Default)]
+pub struct TokenFlow {
+    pub epoch: u64,
+    pub flow_in: Uint256,
+    pub flow_out: Uint256,
+}
+
+impl TokenFlow {
+    pub fn epoch_at(time: Timestamp) -> u64 {
+        time.seconds()
+            .checked_div(FLOW_LIMIT_EPOCH_DURATION)
+            .expect("flow limit epoch duration must be non-zero")
+    }
+
+    /// Returns the flow during the epoch at the given time. The flow resets with every new epoch.
+    pub fn at(self, time: Timestamp) -> Self {
+        let epoch = Self::epoch_at(time);
+
+        if self.epoch == epoch {
+            self
+        } else {
+            Self {
+                epoch,
+                ..Self::default()
+            }
+        }
     }
 }
 
@@ -124,6 +199,9 @@ pub enum TokenDeploymentType {
 #[cw_serde]
 pub struct TokenConfig {
     pub origin_chain: ChainNameRaw,
+    /// Messages for a frozen token are rejected on all chains
+    #[serde(default)]
+    pub frozen: bool,
 }
 
 type TokenAddress = nonempty::HexBinary;
@@ -137,10 +215,26 @@ pub struct CustomTokenMetadata {
 
 const CONFIG: Item<Config> = Item::new("config");
 const CHAIN_CONFIGS: Map<&ChainNameRaw, ChainConfig> = Map::new("chain_configs");
-const TOKEN_INSTANCE: Map<&(ChainNameRaw, TokenId), TokenInstance> = Map::new("token_instance");
-const TOKEN_CONFIGS: Map<&TokenId, TokenConfig> = Map::new("token_configs");
+// owned keys are used so the token registry can be iterated, their encoding is the same as for references
+const TOKEN_INSTANCE: Map<(ChainNameRaw, TokenId), TokenInstance> = Map::new("token_instance");
+/// chains each token is deployed to, so the instances of a token can be loaded without scanning all chains
+const TOKEN_CHAINS: Map<(TokenId, ChainNameRaw), ()> = Map::new("token_chains");
+const TOKEN_CONFIGS: Map<TokenId, TokenConfig> = Map::new("token_configs");
 const CUSTOM_TOKEN_METADATA: Map<&(ChainNameRaw, TokenAddress), CustomTokenMetadata> =
     Map::new("custom_tokens");
+/// denoms of the token factory tokens that represent interchain tokens deployed to Axelar
+const TOKEN_FACTORY_DENOMS: Map<TokenId, String> = Map::new("token_factory_denoms");
+/// the reverse of `TOKEN_FACTORY_DENOMS`, denoms only contain a prefix of the token id
+const TOKEN_FACTORY_DENOM_TOKEN_IDS: Map<&str, TokenId> = Map::new("token_factory_denom_token_ids");
+/// custom tokens that have been linked, their metadata can't be changed anymore
+const LINKED_CUSTOM_TOKENS: Map<&(ChainNameRaw, TokenAddress), ()> =
+    Map::new("linked_custom_tokens");
+/// transfer fees in basis points, a token's fee takes precedence over the fee of the chain pair
+const TOKEN_TRANSFER_FEES: Map<TokenId, u16> = Map::new("token_transfer_fees");
+const CHAIN_PAIR_TRANSFER_FEES: Map<&(ChainNameRaw, ChainNameRaw), u16> =
+    Map::new("chain_pair_transfer_fees");
+/// fees collected from transfers, denominated in the token's decimals on the source chain of the transfers
+const ACCRUED_FEES: Map<&(ChainNameRaw, TokenId), Uint256> = Map::new("accrued_fees");
 
 pub fn load_config(storage: &dyn Storage) -> Config {
     CONFIG
@@ -258,8 +352,11 @@ pub fn save_token_instance(
     token_id: TokenId,
     token_instance: &TokenInstance,
 ) -> Result<(), Error> {
+    TOKEN_CHAINS
+        .save(storage, (token_id, chain.clone()), &())
+        .change_context(Error::Storage)?;
     TOKEN_INSTANCE
-        .save(storage, &(chain, token_id), token_instance)
+        .save(storage, (chain, token_id), token_instance)
         .change_context(Error::Storage)
 }
 
@@ -269,7 +366,7 @@ pub fn may_load_token_instance(
     token_id: TokenId,
 ) -> Result<Option<TokenInstance>, Error> {
     TOKEN_INSTANCE
-        .may_load(storage, &(chain, token_id))
+        .may_load(storage, (chain, token_id))
         .change_context(Error::Storage)
 }
 
@@ -278,7 +375,7 @@ pub fn may_load_token_config(
     token_id: &TokenId,
 ) -> Result<Option<TokenConfig>, Error> {
     TOKEN_CONFIGS
-        .may_load(storage, token_id)
+        .may_load(storage, *token_id)
         .change_context(Error::Storage)
 }
 
@@ -288,10 +385,97 @@ pub fn save_token_config(
     token_config: &TokenConfig,
 ) -> Result<(), Error> {
     TOKEN_CONFIGS
-        .save(storage, &token_id, token_config)
+        .save(storage, token_id, token_config)
         .change_context(Error::Storage)
 }
 
+/// Loads the token instances of the given token on all chains it is deployed to
+pub fn load_token_instances(
+    storage: &dyn Storage,
+    token_id: TokenId,
+) -> Result<HashMap<ChainNameRaw, TokenInstance>, Error> {
+    TOKEN_CHAINS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .map(|chain| {
+            let chain = chain.change_context(Error::Storage)?;
+            let instance = TOKEN_INSTANCE
+                .load(storage, (chain.clone(), token_id))
+                .change_context(Error::Storage)?;
+
+            Ok((chain, instance))
+        })
+        .collect()
+}
+
+/// Indexes the chains of all token instances that were saved before the index existed
+pub fn index_token_chains(storage: &mut dyn Storage) -> Result<(), Error> {
+    let keys = TOKEN_INSTANCE
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .change_context(Error::Storage)?;
+
+    keys.into_iter().try_for_each(|(chain, token_id)| {
+        TOKEN_CHAINS
+            .save(storage, (token_id, chain), &())
+            .change_context(Error::Storage)
+    })
+}
+
+pub fn load_chain_token_instances(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: u32,
+) -> Result<Vec<(TokenId, TokenInstance)>, Error> {
+    TOKEN_INSTANCE
+        .prefix(chain)
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|res| res.change_context(Error::Storage))
+        .collect()
+}
+
+pub fn load_token_configs(
+    storage: &dyn Storage,
+    start_after: Option<TokenId>,
+    limit: u32,
+) -> Result<Vec<(TokenId, TokenConfig)>, Error> {
+    TOKEN_CONFIGS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|res| res.change_context(Error::Storage))
+        .collect()
+}
+
+pub fn freeze_token(storage: &mut dyn Storage, token_id: TokenId) -> Result<TokenConfig, Error> {
+    TOKEN_CONFIGS
+        .update(storage, token_id, |elt| match elt {
+            Some(x) => Ok(TokenConfig { frozen: true, ..x }),
+            None => Err(StdError::not_found("token not found".to_string())),
+        })
+        .change_context(Error::TokenNotFound(token_id))
+}
+
+pub fn unfreeze_token(storage: &mut dyn Storage, token_id: TokenId) -> Result<TokenConfig, Error> {
+    TOKEN_CONFIGS
+        .update(storage, token_id, |elt| match elt {
+            Some(x) => Ok(TokenConfig { frozen: false, ..x }),
+            None => Err(StdError::not_found("token not found".to_string())),
+        })
+        .change_context(Error::TokenNotFound(token_id))
+}
+
 pub fn save_custom_token_metadata(
     storage: &mut dyn Storage,
     chain: ChainNameRaw,
@@ -314,7 +498,7 @@ pub fn save_custom_token_metadata(
 }
 
 pub fn may_load_custom_token(
-    storage: &mut dyn Storage,
+    storage: &dyn Storage,
     source_chain: ChainNameRaw,
     token_address: TokenAddress,
 ) -> Result<Option<CustomTokenMetadata>, Error> {
@@ -323,6 +507,170 @@ pub fn may_load_custom_token(
         .change_context(Error::Storage)
 }
 
+pub fn save_token_factory_denom(
+    storage: &mut dyn Storage,
+    token_id: TokenId,
+    denom: &String,
+) -> Result<(), Error> {
+    if TOKEN_FACTORY_DENOMS.has(storage, token_id) {
+        bail!(Error::TokenFactoryDenomAlreadyExists(token_id));
+    }
+
+    if let Some(existing_token_id) = may_load_token_factory_token_id(storage, denom)? {
+        bail!(Error::TokenFactoryDenomCollision {
+            denom: denom.clone(),
+            token_id: existing_token_id,
+        });
+    }
+
+    TOKEN_FACTORY_DENOM_TOKEN_IDS
+        .save(storage, denom, &token_id)
+        .change_context(Error::Storage)?;
+    TOKEN_FACTORY_DENOMS
+        .save(storage, token_id, denom)
+        .change_context(Error::Storage)
+}
+
+pub fn may_load_token_factory_token_id(
+    storage: &dyn Storage,
+    denom: &str,
+) -> Result<Option<TokenId>, Error> {
+    TOKEN_FACTORY_DENOM_TOKEN_IDS
+        .may_load(storage, denom)
+        .change_context(Error::Storage)
+}
+
+pub fn may_load_token_factory_denom(
+    storage: &dyn Storage,
+    token_id: TokenId,
+) -> Result<Option<String>, Error> {
+    TOKEN_FACTORY_DENOMS
+        .may_load(storage, token_id)
+        .change_context(Error::Storage)
+}
+
+pub fn remove_custom_token_metadata(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_address: TokenAddress,
+) {
+    CUSTOM_TOKEN_METADATA.remove(storage, &(chain, token_address))
+}
+
+pub fn mark_custom_token_linked(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_address: TokenAddress,
+) -> Result<(), Error> {
+    LINKED_CUSTOM_TOKENS
+        .save(storage, &(chain, token_address), &())
+        .change_context(Error::Storage)
+}
+
+/// Links of custom tokens were not recorded before, so any custom token registered until then might be linked already.
+/// All of them are marked as linked to prevent changing the metadata of a linked token.
+pub fn mark_all_custom_tokens_linked(storage: &mut dyn Storage) -> Result<(), Error> {
+    let custom_tokens = CUSTOM_TOKEN_METADATA
+        .range_raw(storage, None, None, Order::Ascending)
+        .map(|res| res.map(|(_, metadata)| metadata))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .change_context(Error::Storage)?;
+
+    custom_tokens.into_iter().try_for_each(|metadata| {
+        mark_custom_token_linked(storage, metadata.chain, metadata.token_address)
+    })
+}
+
+pub fn is_custom_token_linked(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_address: TokenAddress,
+) -> bool {
+    LINKED_CUSTOM_TOKENS.has(storage, &(chain, token_address))
+}
+
+pub fn save_token_transfer_fee(
+    storage: &mut dyn Storage,
+    token_id: TokenId,
+    fee: Option<u16>,
+) -> Result<(), Error> {
+    match fee {
+        Some(fee) => TOKEN_TRANSFER_FEES
+            .save(storage, token_id, &fee)
+            .change_context(Error::Storage),
+        None => {
+            TOKEN_TRANSFER_FEES.remove(storage, token_id);
+            Ok(())
+        }
+    }
+}
+
+pub fn save_chain_pair_transfer_fee(
+    storage: &mut dyn Storage,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    fee: Option<u16>,
+) -> Result<(), Error> {
+    let key = (source_chain, destination_chain);
+
+    match fee {
+        Some(fee) => CHAIN_PAIR_TRANSFER_FEES
+            .save(storage, &key, &fee)
+            .change_context(Error::Storage),
+        None => {
+            CHAIN_PAIR_TRANSFER_FEES.remove(storage, &key);
+            Ok(())
+        }
+    }
+}
+
+/// Returns the fee in basis points for transfers of the token from the source to the destination chain.
+/// Transfers are free if neither a token nor a chain pair fee is set.
+pub fn load_transfer_fee(
+    storage: &dyn Storage,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<u16, Error> {
+    if let Some(fee) = TOKEN_TRANSFER_FEES
+        .may_load(storage, token_id)
+        .change_context(Error::Storage)?
+    {
+        return Ok(fee);
+    }
+
+    CHAIN_PAIR_TRANSFER_FEES
+        .may_load(storage, &(source_chain, destination_chain))
+        .change_context(Error::Storage)
+        .map(Option::unwrap_or_default)
+}
+
+pub fn load_accrued_fees(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Uint256, Error> {
+    ACCRUED_FEES
+        .may_load(storage, &(chain, token_id))
+        .change_context(Error::Storage)
+        .map(Option::unwrap_or_default)
+}
+
+pub fn save_accrued_fees(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    fees: &Uint256,
+) -> Result<(), Error> {
+    ACCRUED_FEES
+        .save(storage, &(chain, token_id), fees)
+        .change_context(Error::Storage)
+}
+
+pub fn remove_accrued_fees(storage: &mut dyn Storage, chain: ChainNameRaw, token_id: TokenId) {
+    ACCRUED_FEES.remove(storage, &(chain, token_id))
+}
+
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
@@ -350,6 +698,120 @@ mod tests {
         load_config(deps.as_ref().storage);
     }
 
+    #[test]
+    fn token_instance_saved_before_upgrade_can_be_loaded() {
+        #[cw_serde]
+        struct TokenInstanceBeforeUpgrade {
+            supply: TokenSupply,
+            decimals: u8,
+        }
+
+        const TOKEN_INSTANCE_BEFORE_UPGRADE: Map<
+            (ChainNameRaw, TokenId),
+            TokenInstanceBeforeUpgrade,
+        > = Map::new("token_instance");
+
+        let mut deps = mock_dependencies();
+        let chain: ChainNameRaw = "chain".parse().unwrap();
+        let token_id = TokenId::new([1; 32]);
+
+        TOKEN_INSTANCE_BEFORE_UPGRADE
+            .save(
+                deps.as_mut().storage,
+                (chain.clone(), token_id),
+                &TokenInstanceBeforeUpgrade {
+                    supply: TokenSupply::Tracked(Uint256::from(100u128)),
+                    decimals: 6,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            assert_ok!(may_load_token_instance(
+                deps.as_ref().storage,
+                chain,
+                token_id
+            )),
+            Some(TokenInstance {
+                supply: TokenSupply::Tracked(Uint256::from(100u128)),
+                decimals: 6,
+                flow_limit: None,
+                flow: TokenFlow::default(),
+                rounding_loss: Uint256::zero(),
+                total_outflow: Uint256::zero(),
+                total_inflow: Uint256::zero(),
+            })
+        );
+    }
+
+    #[test]
+    fn token_config_saved_before_upgrade_can_be_loaded() {
+        #[cw_serde]
+        struct TokenConfigBeforeUpgrade {
+            origin_chain: ChainNameRaw,
+        }
+
+        const TOKEN_CONFIGS_BEFORE_UPGRADE: Map<TokenId, TokenConfigBeforeUpgrade> =
+            Map::new("token_configs");
+
+        let mut deps = mock_dependencies();
+        let origin_chain: ChainNameRaw = "chain".parse().unwrap();
+        let token_id = TokenId::new([1; 32]);
+
+        TOKEN_CONFIGS_BEFORE_UPGRADE
+            .save(
+                deps.as_mut().storage,
+                token_id,
+                &TokenConfigBeforeUpgrade {
+                    origin_chain: origin_chain.clone(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            assert_ok!(may_load_token_config(deps.as_ref().storage, &token_id)),
+            Some(TokenConfig {
+                origin_chain,
+                frozen: false,
+            })
+        );
+    }
+
+    #[test]
+    fn save_token_factory_denom_fails_on_existing_or_colliding_denom() {
+        let mut deps = mock_dependencies();
+        let token_id = TokenId::new([1; 32]);
+        let denom = "factory/hub/0101".to_string();
+
+        assert_ok!(save_token_factory_denom(
+            deps.as_mut().storage,
+            token_id,
+            &denom
+        ));
+        assert_eq!(
+            assert_ok!(may_load_token_factory_token_id(
+                deps.as_ref().storage,
+                &denom
+            )),
+            Some(token_id)
+        );
+
+        assert_err_contains!(
+            save_token_factory_denom(
+                deps.as_mut().storage,
+                token_id,
+                &"factory/hub/02".to_string()
+            ),
+            Error,
+            Error::TokenFactoryDenomAlreadyExists(..)
+        );
+        assert_err_contains!(
+            save_token_factory_denom(deps.as_mut().storage, TokenId::new([2; 32]), &denom),
+            Error,
+            Error::TokenFactoryDenomCollision { .. }
+        );
+    }
+
     #[test]
     fn save_and_load_its_contract_succeeds() {
         let mut deps = mock_dependencies();
@@ -378,7 +840,8 @@ mod tests {
                 truncation: msg::TruncationConfig {
                     max_uint: Uint256::MAX.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8
-                }
+                },
+                payload_codec: PayloadCodec::Abi,
             }
         ));
         assert_ok!(save_chain_config(
@@ -390,7 +853,8 @@ mod tests {
                 truncation: msg::TruncationConfig {
                     max_uint: Uint256::MAX.try_into().unwrap(),
                     max_decimals_when_truncating: 16u8
-                }
+                },
+                payload_codec: PayloadCodec::Abi,
             }
         ));
         assert_eq!(
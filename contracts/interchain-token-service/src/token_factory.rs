@@ -0,0 +1,128 @@
+use cosmwasm_std::{Addr, AnyMsg, Coin, CosmosMsg};
+use prost::Message;
+
+use crate::TokenId;
+
+const MSG_CREATE_DENOM_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom";
+const MSG_MINT_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgMint";
+const MSG_BURN_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgBurn";
+
+/// Token factory subdenoms are limited to 44 characters, so only the first 20 bytes of the token id are used
+const SUBDENOM_TOKEN_ID_BYTES: usize = 20;
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(string, tag = "2")]
+    subdenom: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgMint {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(message, optional, tag = "2")]
+    amount: Option<ProtoCoin>,
+    #[prost(string, tag = "3")]
+    mint_to_address: String,
+}
+
+/// Burns from the sender's own balance
+#[derive(Clone, PartialEq, Message)]
+struct MsgBurn {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(message, optional, tag = "2")]
+    amount: Option<ProtoCoin>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    denom: String,
+    #[prost(string, tag = "2")]
+    amount: String,
+}
+
+/// The denom of the token factory token that represents the interchain token on Axelar
+pub fn denom(creator: &Addr, token_id: TokenId) -> String {
+    format!("factory/{}/{}", creator, subdenom(token_id))
+}
+
+pub fn create_denom(creator: &Addr, token_id: TokenId) -> CosmosMsg {
+    any_msg(
+        MSG_CREATE_DENOM_TYPE_URL,
+        MsgCreateDenom {
+            sender: creator.to_string(),
+            subdenom: subdenom(token_id),
+        },
+    )
+}
+
+pub fn mint(creator: &Addr, token: Coin, recipient: &Addr) -> CosmosMsg {
+    any_msg(
+        MSG_MINT_TYPE_URL,
+        MsgMint {
+            sender: creator.to_string(),
+            amount: Some(token.into()),
+            mint_to_address: recipient.to_string(),
+        },
+    )
+}
+
+/// Burns tokens held by the creator
+pub fn burn(creator: &Addr, token: Coin) -> CosmosMsg {
+    any_msg(
+        MSG_BURN_TYPE_URL,
+        MsgBurn {
+            sender: creator.to_string(),
+            amount: Some(token.into()),
+        },
+    )
+}
+
+impl From<Coin> for ProtoCoin {
+    fn from(coin: Coin) -> Self {
+        Self {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+fn subdenom(token_id: TokenId) -> String {
+    hex::encode(&<[u8; 32]>::from(token_id)[..SUBDENOM_TOKEN_ID_BYTES])
+}
+
+fn any_msg(type_url: &str, msg: impl Message) -> CosmosMsg {
+    CosmosMsg::Any(AnyMsg {
+        type_url: type_url.to_string(),
+        value: msg.encode_to_vec().into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+
+    use super::*;
+
+    #[test]
+    fn denom_fits_into_token_factory_limits() {
+        let creator = MockApi::default().addr_make("its-hub");
+        let token_id = TokenId::new([255; 32]);
+
+        let denom = denom(&creator, token_id);
+
+        assert_eq!(
+            denom,
+            format!(
+                "factory/{}/{}",
+                creator,
+                "ff".repeat(SUBDENOM_TOKEN_ID_BYTES)
+            )
+        );
+        assert!(subdenom(token_id).len() <= 44);
+    }
+}
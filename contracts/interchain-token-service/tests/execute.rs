@@ -4,14 +4,14 @@ use assert_ok::assert_ok;
 use axelar_wasm_std::response::inspect_response_msg;
 use axelar_wasm_std::{assert_err_contains, nonempty, permission_control};
 use axelarnet_gateway::msg::ExecuteMsg as AxelarnetGatewayExecuteMsg;
-use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-use cosmwasm_std::{HexBinary, Uint256};
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+use cosmwasm_std::{coins, from_json, AnyMsg, CosmosMsg, HexBinary, Uint256, WasmMsg};
 use interchain_token_service::contract::{self, ExecuteError};
 use interchain_token_service::events::Event;
 use interchain_token_service::msg::{self, ExecuteMsg, TruncationConfig};
 use interchain_token_service::{
-    DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, RegisterTokenMetadata,
-    TokenId, TokenSupply,
+    DeployInterchainToken, HubMessage, InterchainTransfer, LinkToken, PayloadCodec,
+    RegisterTokenMetadata, TokenId, TokenSupply,
 };
 use router_api::{Address, ChainName, ChainNameRaw, CrossChainId};
 use serde_json::json;
@@ -116,6 +116,7 @@ fn register_multiple_chains_succeeds() {
                 max_decimals_when_truncating: 18u8,
                 max_uint: Uint256::MAX.try_into().unwrap(),
             },
+            payload_codec: PayloadCodec::Abi,
         })
         .collect();
     assert_ok!(register_chains(deps.as_mut(), chains.clone()));
@@ -141,6 +142,7 @@ fn register_multiple_chains_fails_if_one_invalid() {
                 max_decimals_when_truncating: 18u8,
                 max_uint: Uint256::MAX.try_into().unwrap(),
             },
+            payload_codec: PayloadCodec::Abi,
         })
         .collect();
     assert_ok!(register_chains(deps.as_mut(), chains[0..1].to_vec()));
@@ -229,6 +231,99 @@ fn execute_hub_message_succeeds() {
     goldie::assert_json!(responses);
 }
 
+#[test]
+fn execute_hub_message_translates_between_payload_codecs() {
+    let mut deps = make_deps();
+    utils::instantiate_contract(deps.as_mut()).unwrap();
+
+    let TestMessage {
+        router_message,
+        source_its_chain,
+        source_its_contract,
+        destination_its_chain,
+        destination_its_contract,
+        ..
+    } = TestMessage::dummy();
+
+    let chain_config =
+        |chain: &ChainNameRaw, its_edge_contract: &Address, payload_codec| msg::ChainConfig {
+            chain: chain.clone(),
+            its_edge_contract: its_edge_contract.clone(),
+            truncation: TruncationConfig {
+                max_uint: Uint256::MAX.try_into().unwrap(),
+                max_decimals_when_truncating: 18u8,
+            },
+            payload_codec,
+        };
+    assert_ok!(register_chains(
+        deps.as_mut(),
+        vec![
+            chain_config(&source_its_chain, &source_its_contract, PayloadCodec::Bcs),
+            chain_config(
+                &destination_its_chain,
+                &destination_its_contract,
+                PayloadCodec::Abi
+            ),
+        ]
+    ));
+
+    let message = utils::dummy_message();
+    let response = assert_ok!(utils::execute(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: destination_its_chain.clone(),
+            message: message.clone(),
+        }
+        .bcs_encode(),
+    ));
+    let msg: AxelarnetGatewayExecuteMsg = assert_ok!(inspect_response_msg(response));
+    assert_eq!(
+        msg,
+        AxelarnetGatewayExecuteMsg::CallContract {
+            destination_chain: ChainName::try_from(destination_its_chain.to_string()).unwrap(),
+            destination_address: destination_its_contract.clone(),
+            payload: HubMessage::ReceiveFromHub {
+                source_chain: source_its_chain.clone(),
+                message,
+            }
+            .abi_encode(),
+        }
+    );
+
+    let message: interchain_token_service::Message = DeployInterchainToken {
+        token_id: TokenId::new([3; 32]),
+        name: "Test".try_into().unwrap(),
+        symbol: "TST".try_into().unwrap(),
+        decimals: 18,
+        minter: None,
+    }
+    .into();
+    let response = assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId::new(destination_its_chain.clone(), "message-id").unwrap(),
+        destination_its_contract,
+        HubMessage::SendToHub {
+            destination_chain: source_its_chain.clone(),
+            message: message.clone(),
+        },
+    ));
+    let msg: AxelarnetGatewayExecuteMsg = assert_ok!(inspect_response_msg(response));
+    assert_eq!(
+        msg,
+        AxelarnetGatewayExecuteMsg::CallContract {
+            destination_chain: ChainName::try_from(source_its_chain.to_string()).unwrap(),
+            destination_address: source_its_contract,
+            payload: HubMessage::ReceiveFromHub {
+                source_chain: destination_its_chain,
+                message,
+            }
+            .bcs_encode(),
+        }
+    );
+}
+
 #[test]
 fn execute_message_interchain_transfer_should_scale_custom_tokens_when_decimals_are_different() {
     let (
@@ -1588,6 +1683,933 @@ fn interchain_transfer_exceeds_supply_fails() {
     );
 }
 
+#[test]
+fn freeze_token_when_not_admin_fails() {
+    let (mut deps, _) = utils::setup();
+    let api = deps.api;
+
+    assert_err_contains!(
+        contract::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("not-admin"), &[]),
+            ExecuteMsg::FreezeToken {
+                token_id: TokenId::new([1; 32]),
+            },
+        ),
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+}
+
+#[test]
+fn freeze_unknown_token_fails() {
+    let (mut deps, _) = utils::setup();
+
+    assert_err_contains!(
+        utils::freeze_token(deps.as_mut(), TokenId::new([1; 32])),
+        ExecuteError,
+        ExecuteError::State
+    );
+}
+
+#[test]
+fn frozen_token_rejects_messages_until_unfrozen() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    let response = assert_ok!(utils::freeze_token(deps.as_mut(), token_id));
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "token_frozen"));
+    assert!(
+        assert_ok!(utils::query_token_config(deps.as_ref(), token_id))
+            .unwrap()
+            .frozen
+    );
+
+    let msg = HubMessage::SendToHub {
+        destination_chain: destination_its_chain.clone(),
+        message: InterchainTransfer {
+            token_id,
+            source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+            destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+            amount: 100u64.try_into().unwrap(),
+            data: None,
+        }
+        .into(),
+    };
+    assert_err_contains!(
+        utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id.clone(),
+            source_its_contract.clone(),
+            msg.clone(),
+        ),
+        ExecuteError,
+        ExecuteError::TokenFrozen(..)
+    );
+
+    let deploy_msg = HubMessage::SendToHub {
+        destination_chain: destination_its_chain.clone(),
+        message: DeployInterchainToken {
+            token_id,
+            name: "Test".try_into().unwrap(),
+            symbol: "TST".try_into().unwrap(),
+            decimals: 18,
+            minter: None,
+        }
+        .into(),
+    };
+    assert_err_contains!(
+        utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id.clone(),
+            source_its_contract.clone(),
+            deploy_msg,
+        ),
+        ExecuteError,
+        ExecuteError::TokenFrozen(..)
+    );
+
+    let response = assert_ok!(utils::unfreeze_token(deps.as_mut(), token_id));
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "token_unfrozen"));
+    assert!(
+        !assert_ok!(utils::query_token_config(deps.as_ref(), token_id))
+            .unwrap()
+            .frozen
+    );
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id,
+        source_its_contract,
+        msg,
+    ));
+}
+
+#[test]
+fn set_transfer_fee_when_not_governance_fails() {
+    let mut deps = mock_dependencies();
+    utils::instantiate_contract(deps.as_mut()).unwrap();
+
+    assert_err_contains!(
+        contract::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(params::ADMIN), &[]),
+            ExecuteMsg::SetTokenTransferFee {
+                token_id: TokenId::new([1; 32]),
+                fee: Some(10),
+            },
+        ),
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+}
+
+#[test]
+fn set_transfer_fee_of_whole_amount_fails() {
+    let mut deps = mock_dependencies();
+    utils::instantiate_contract(deps.as_mut()).unwrap();
+
+    assert_err_contains!(
+        utils::set_token_transfer_fee(deps.as_mut(), TokenId::new([1; 32]), Some(10_000)),
+        ExecuteError,
+        ExecuteError::InvalidTransferFee(10_000)
+    );
+    assert_err_contains!(
+        utils::set_chain_pair_transfer_fee(
+            deps.as_mut(),
+            "ethereum".parse().unwrap(),
+            "solana".parse().unwrap(),
+            Some(u16::MAX)
+        ),
+        ExecuteError,
+        ExecuteError::InvalidTransferFee(u16::MAX)
+    );
+}
+
+#[test]
+fn interchain_transfer_fees_accrue_until_withdrawn() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            destination_its_contract,
+            hub_message,
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    // 1% for transfers from the source chain, 10% for all transfers of the token
+    assert_ok!(utils::set_chain_pair_transfer_fee(
+        deps.as_mut(),
+        source_its_chain.clone(),
+        destination_its_chain.clone(),
+        Some(100),
+    ));
+    let transfer = |amount: u64| InterchainTransfer {
+        token_id,
+        source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+        destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+        amount: amount.try_into().unwrap(),
+        data: None,
+    };
+
+    let response = assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: destination_its_chain.clone(),
+            message: transfer(400).into(),
+        },
+    ));
+    assert!(response
+        .events
+        .contains(&cosmwasm_std::Event::from(Event::TransferFeeCharged {
+            token_id,
+            source_chain: source_its_chain.clone(),
+            destination_chain: destination_its_chain.clone(),
+            amount: 400u64.try_into().unwrap(),
+            fee: 4u64.try_into().unwrap(),
+            net_amount: 396u64.try_into().unwrap(),
+        })));
+
+    assert_ok!(utils::set_token_transfer_fee(
+        deps.as_mut(),
+        token_id,
+        Some(1_000)
+    ));
+    assert_eq!(
+        assert_ok!(utils::query_transfer_fee(
+            deps.as_ref(),
+            destination_its_chain.clone(),
+            source_its_chain.clone(),
+            token_id
+        )),
+        1_000
+    );
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId::new(
+            destination_its_chain.clone(),
+            router_message.cc_id.message_id.clone()
+        )
+        .unwrap(),
+        destination_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: source_its_chain.clone(),
+            message: transfer(100).into(),
+        },
+    ));
+
+    assert_eq!(
+        assert_ok!(utils::query_accrued_fees(
+            deps.as_ref(),
+            source_its_chain.clone(),
+            token_id
+        )),
+        Uint256::from(4u64)
+    );
+    assert_eq!(
+        assert_ok!(utils::query_accrued_fees(
+            deps.as_ref(),
+            destination_its_chain.clone(),
+            token_id
+        )),
+        Uint256::from(10u64)
+    );
+    assert_eq!(
+        assert_ok!(utils::query_token_instance(
+            deps.as_ref(),
+            destination_its_chain.clone(),
+            token_id
+        ))
+        .unwrap()
+        .supply,
+        TokenSupply::Tracked(Uint256::from(296u64))
+    );
+
+    let recipient: nonempty::HexBinary = HexBinary::from([3; 32]).try_into().unwrap();
+    assert_ok!(utils::freeze_token(deps.as_mut(), token_id));
+    assert_err_contains!(
+        utils::withdraw_fees(
+            deps.as_mut(),
+            destination_its_chain.clone(),
+            token_id,
+            recipient.clone()
+        ),
+        ExecuteError,
+        ExecuteError::TokenFrozen(..)
+    );
+    assert_ok!(utils::unfreeze_token(deps.as_mut(), token_id));
+
+    let axelar_chain = utils::register_axelar_chain(deps.as_mut());
+    assert_err_contains!(
+        utils::withdraw_fees(deps.as_mut(), axelar_chain, token_id, recipient.clone()),
+        ExecuteError,
+        ExecuteError::InvalidDestinationChain(..)
+    );
+
+    let response = assert_ok!(utils::withdraw_fees(
+        deps.as_mut(),
+        destination_its_chain.clone(),
+        token_id,
+        recipient.clone(),
+    ));
+    let msg: AxelarnetGatewayExecuteMsg = assert_ok!(inspect_response_msg(response.clone()));
+    assert_eq!(
+        msg,
+        AxelarnetGatewayExecuteMsg::CallContract {
+            destination_chain: ChainName::try_from(destination_its_chain.to_string()).unwrap(),
+            destination_address: destination_its_contract,
+            payload: HubMessage::ReceiveFromHub {
+                source_chain: "axelar".parse().unwrap(),
+                message: InterchainTransfer {
+                    token_id,
+                    source_address: HexBinary::from(mock_env().contract.address.as_bytes())
+                        .try_into()
+                        .unwrap(),
+                    destination_address: recipient.clone(),
+                    amount: 10u64.try_into().unwrap(),
+                    data: None,
+                }
+                .into(),
+            }
+            .abi_encode(),
+        }
+    );
+    assert_eq!(
+        response.events,
+        vec![cosmwasm_std::Event::from(Event::FeesWithdrawn {
+            token_id,
+            chain: destination_its_chain.clone(),
+            recipient: recipient.clone(),
+            amount: 10u64.try_into().unwrap(),
+        })]
+    );
+
+    assert_eq!(
+        assert_ok!(utils::query_token_instance(
+            deps.as_ref(),
+            destination_its_chain.clone(),
+            token_id
+        ))
+        .unwrap()
+        .supply,
+        TokenSupply::Tracked(Uint256::from(306u64))
+    );
+    assert_err_contains!(
+        utils::withdraw_fees(deps.as_mut(), destination_its_chain, token_id, recipient),
+        ExecuteError,
+        ExecuteError::NoAccruedFees { .. }
+    );
+}
+
+#[test]
+fn update_custom_token_metadata_when_not_governance_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            source_its_chain, ..
+        },
+    ) = utils::setup();
+    let api = deps.api;
+
+    assert_err_contains!(
+        contract::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(params::ADMIN), &[]),
+            ExecuteMsg::UpdateCustomTokenMetadata {
+                chain: source_its_chain,
+                token_address: HexBinary::from([1; 32]).try_into().unwrap(),
+                decimals: 6,
+            },
+        ),
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+}
+
+#[test]
+fn update_unregistered_custom_token_metadata_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            source_its_chain, ..
+        },
+    ) = utils::setup();
+
+    assert_err_contains!(
+        utils::update_custom_token_metadata(
+            deps.as_mut(),
+            source_its_chain,
+            HexBinary::from([1; 32]).try_into().unwrap(),
+            6,
+        ),
+        ExecuteError,
+        ExecuteError::TokenNotRegistered(..)
+    );
+}
+
+#[test]
+fn custom_token_metadata_can_be_corrected_until_linked() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            destination_its_contract,
+            ..
+        },
+    ) = utils::setup();
+    let token_address: nonempty::HexBinary = HexBinary::from([1; 32]).try_into().unwrap();
+
+    for (chain, its_contract) in [
+        (source_its_chain.clone(), source_its_contract.clone()),
+        (
+            destination_its_chain.clone(),
+            destination_its_contract.clone(),
+        ),
+    ] {
+        assert_ok!(utils::execute_hub_message(
+            deps.as_mut(),
+            CrossChainId {
+                source_chain: chain,
+                message_id: router_message.cc_id.message_id.clone(),
+            },
+            its_contract,
+            HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+                decimals: 18,
+                token_address: token_address.clone(),
+            }),
+        ));
+    }
+
+    let response = assert_ok!(utils::update_custom_token_metadata(
+        deps.as_mut(),
+        source_its_chain.clone(),
+        token_address.clone(),
+        6,
+    ));
+    assert_eq!(
+        response.events,
+        vec![cosmwasm_std::Event::from(Event::TokenMetadataUpdated {
+            chain: source_its_chain.clone(),
+            token_address: token_address.clone(),
+            decimals: 6,
+        })]
+    );
+    let metadata = assert_ok!(utils::query_custom_token_metadata(
+        deps.as_ref(),
+        source_its_chain.clone(),
+        token_address.clone(),
+    ))
+    .unwrap();
+    assert_eq!(metadata.decimals, 6);
+
+    let response = assert_ok!(utils::remove_custom_token_metadata(
+        deps.as_mut(),
+        destination_its_chain.clone(),
+        token_address.clone(),
+    ));
+    assert_eq!(
+        response.events,
+        vec![cosmwasm_std::Event::from(Event::TokenMetadataRemoved {
+            chain: destination_its_chain.clone(),
+            token_address: token_address.clone(),
+        })]
+    );
+    assert_eq!(
+        assert_ok!(utils::query_custom_token_metadata(
+            deps.as_ref(),
+            destination_its_chain.clone(),
+            token_address.clone(),
+        )),
+        None
+    );
+
+    // the removed token can be registered again from its chain
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId {
+            source_chain: destination_its_chain.clone(),
+            message_id: router_message.cc_id.message_id.clone(),
+        },
+        destination_its_contract,
+        HubMessage::RegisterTokenMetadata(RegisterTokenMetadata {
+            decimals: 18,
+            token_address: token_address.clone(),
+        }),
+    ));
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract,
+        HubMessage::SendToHub {
+            destination_chain: destination_its_chain.clone(),
+            message: LinkToken {
+                token_id: TokenId::new([1; 32]),
+                token_manager_type: Uint256::zero(),
+                source_token_address: token_address.clone(),
+                destination_token_address: token_address.clone(),
+                params: None,
+            }
+            .into(),
+        },
+    ));
+
+    for chain in [source_its_chain, destination_its_chain] {
+        assert_err_contains!(
+            utils::update_custom_token_metadata(
+                deps.as_mut(),
+                chain.clone(),
+                token_address.clone(),
+                8,
+            ),
+            ExecuteError,
+            ExecuteError::TokenAlreadyLinked { .. }
+        );
+        assert_err_contains!(
+            utils::remove_custom_token_metadata(deps.as_mut(), chain, token_address.clone()),
+            ExecuteError,
+            ExecuteError::TokenAlreadyLinked { .. }
+        );
+    }
+}
+
+#[test]
+fn interchain_transfer_to_axelar_mints_token_factory_token() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            ..
+        },
+    ) = utils::setup();
+    let axelar_chain = utils::register_axelar_chain(deps.as_mut());
+    let token_id = TokenId::new([1; 32]);
+    let hub = mock_env().contract.address;
+    let denom = format!("factory/{}/{}", hub, "01".repeat(20));
+    let recipient = MockApi::default().addr_make("recipient");
+
+    let response = assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: DeployInterchainToken {
+                token_id,
+                name: "Test".try_into().unwrap(),
+                symbol: "TST".try_into().unwrap(),
+                decimals: 18,
+                minter: None,
+            }
+            .into(),
+        },
+    ));
+    assert_eq!(response.messages.len(), 1);
+    assert!(matches!(
+        &response.messages[0].msg,
+        CosmosMsg::Any(AnyMsg { type_url, .. }) if type_url == "/osmosis.tokenfactory.v1beta1.MsgCreateDenom"
+    ));
+
+    let transfer = InterchainTransfer {
+        token_id,
+        source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+        destination_address: HexBinary::from(recipient.as_str().as_bytes())
+            .try_into()
+            .unwrap(),
+        amount: Uint256::from(1_000_000u128).try_into().unwrap(),
+        data: None,
+    };
+    let response = assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: transfer.clone().into(),
+        },
+    ));
+    assert_eq!(response.messages.len(), 1);
+    assert!(matches!(
+        &response.messages[0].msg,
+        CosmosMsg::Any(AnyMsg { type_url, .. }) if type_url == "/osmosis.tokenfactory.v1beta1.MsgMint"
+    ));
+
+    // with data, the recipient is executed as a contract with the minted tokens attached
+    let data: nonempty::HexBinary = HexBinary::from([2; 32]).try_into().unwrap();
+    let response = assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract,
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: InterchainTransfer {
+                data: Some(data.clone()),
+                ..transfer
+            }
+            .into(),
+        },
+    ));
+    assert_eq!(response.messages.len(), 2);
+    match &response.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            assert_eq!(*contract_addr, recipient.to_string());
+            assert_eq!(*funds, coins(1_000_000, denom));
+            assert_eq!(
+                from_json::<serde_json::Value>(msg).unwrap(),
+                json!({
+                    "execute": axelarnet_gateway::AxelarExecutableMsg {
+                        cc_id: router_message.cc_id,
+                        source_address: HexBinary::from([1; 32]).to_hex().parse().unwrap(),
+                        payload: data.into(),
+                    }
+                })
+            );
+        }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+
+    let axelar_token = assert_ok!(utils::query_token_instance(
+        deps.as_ref(),
+        axelar_chain,
+        token_id
+    ))
+    .unwrap();
+    assert_eq!(
+        axelar_token.supply,
+        TokenSupply::Tracked(Uint256::from(2_000_000u128))
+    );
+}
+
+#[test]
+fn interchain_transfer_to_axelar_with_invalid_recipient_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            ..
+        },
+    ) = utils::setup();
+    let axelar_chain = utils::register_axelar_chain(deps.as_mut());
+    let token_id = TokenId::new([1; 32]);
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: DeployInterchainToken {
+                token_id,
+                name: "Test".try_into().unwrap(),
+                symbol: "TST".try_into().unwrap(),
+                decimals: 18,
+                minter: None,
+            }
+            .into(),
+        },
+    ));
+
+    assert_err_contains!(
+        utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id,
+            source_its_contract,
+            HubMessage::SendToHub {
+                destination_chain: axelar_chain,
+                message: InterchainTransfer {
+                    token_id,
+                    source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+                    destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+                    amount: Uint256::from(1_000_000u128).try_into().unwrap(),
+                    data: None,
+                }
+                .into(),
+            },
+        ),
+        ExecuteError,
+        ExecuteError::InvalidDestinationAddress(..)
+    );
+}
+
+#[test]
+fn interchain_transfer_from_axelar_burns_token_factory_token() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_contract,
+            ..
+        },
+    ) = utils::setup();
+    let axelar_chain = utils::register_axelar_chain(deps.as_mut());
+    let source_chain = router_message.cc_id.source_chain.clone();
+    let token_id = TokenId::new([1; 32]);
+    let hub = mock_env().contract.address;
+    let denom = format!("factory/{}/{}", hub, "01".repeat(20));
+    let recipient = MockApi::default().addr_make("recipient");
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: DeployInterchainToken {
+                token_id,
+                name: "Test".try_into().unwrap(),
+                symbol: "TST".try_into().unwrap(),
+                decimals: 18,
+                minter: None,
+            }
+            .into(),
+        },
+    ));
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id,
+        source_its_contract,
+        HubMessage::SendToHub {
+            destination_chain: axelar_chain.clone(),
+            message: InterchainTransfer {
+                token_id,
+                source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+                destination_address: HexBinary::from(recipient.as_str().as_bytes())
+                    .try_into()
+                    .unwrap(),
+                amount: Uint256::from(1_000_000u128).try_into().unwrap(),
+                data: None,
+            }
+            .into(),
+        },
+    ));
+
+    let destination_address: nonempty::HexBinary = HexBinary::from([2; 32]).try_into().unwrap();
+    let response = assert_ok!(utils::interchain_transfer(
+        deps.as_mut(),
+        "recipient",
+        &coins(400_000, denom.clone()),
+        source_chain.clone(),
+        destination_address.clone(),
+    ));
+    assert_eq!(response.messages.len(), 2);
+    assert!(matches!(
+        &response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. })
+            if matches!(from_json::<AxelarnetGatewayExecuteMsg>(msg).unwrap(), AxelarnetGatewayExecuteMsg::CallContract { .. })
+    ));
+    assert!(matches!(
+        &response.messages[1].msg,
+        CosmosMsg::Any(AnyMsg { type_url, .. }) if type_url == "/osmosis.tokenfactory.v1beta1.MsgBurn"
+    ));
+
+    let axelar_token = assert_ok!(utils::query_token_instance(
+        deps.as_ref(),
+        axelar_chain.clone(),
+        token_id
+    ))
+    .unwrap();
+    assert_eq!(
+        axelar_token.supply,
+        TokenSupply::Tracked(Uint256::from(600_000u128))
+    );
+
+    assert_err_contains!(
+        utils::interchain_transfer(
+            deps.as_mut(),
+            "recipient",
+            &coins(400_000, "uaxl"),
+            source_chain,
+            destination_address.clone(),
+        ),
+        ExecuteError,
+        ExecuteError::UnknownTokenFactoryDenom(..)
+    );
+    assert_err_contains!(
+        utils::interchain_transfer(
+            deps.as_mut(),
+            "recipient",
+            &coins(400_000, denom),
+            axelar_chain,
+            destination_address,
+        ),
+        ExecuteError,
+        ExecuteError::InvalidDestinationChain(..)
+    );
+}
+
+#[test]
+fn set_flow_limit_when_not_admin_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            source_its_chain, ..
+        },
+    ) = utils::setup();
+    let api = deps.api;
+
+    assert_err_contains!(
+        contract::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("not-admin"), &[]),
+            ExecuteMsg::SetFlowLimit {
+                token_id: TokenId::new([1; 32]),
+                chain: source_its_chain,
+                flow_limit: Some(100u64.try_into().unwrap()),
+            },
+        ),
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+}
+
+#[test]
+fn set_flow_limit_for_undeployed_token_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            source_its_chain, ..
+        },
+    ) = utils::setup();
+
+    assert_err_contains!(
+        utils::set_flow_limit(
+            deps.as_mut(),
+            TokenId::new([1; 32]),
+            source_its_chain,
+            Some(100u64.try_into().unwrap()),
+        ),
+        ExecuteError,
+        ExecuteError::TokenNotDeployed { .. }
+    );
+}
+
+#[test]
+fn interchain_transfer_exceeding_flow_limit_fails() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            hub_message,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_id = hub_message.token_id();
+    let flow_limit = nonempty::Uint256::try_from(500u64).unwrap();
+    let amount = nonempty::Uint256::try_from(400u64).unwrap();
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+    assert_ok!(utils::set_flow_limit(
+        deps.as_mut(),
+        token_id,
+        destination_its_chain.clone(),
+        Some(flow_limit),
+    ));
+
+    let msg = HubMessage::SendToHub {
+        destination_chain: destination_its_chain.clone(),
+        message: InterchainTransfer {
+            token_id,
+            source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+            destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+            amount,
+            data: None,
+        }
+        .into(),
+    };
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        msg.clone(),
+    ));
+
+    let source_token_instance = assert_ok!(utils::query_token_instance(
+        deps.as_ref(),
+        source_its_chain,
+        token_id
+    ))
+    .unwrap();
+    assert_eq!(source_token_instance.flow_limit, None);
+    assert_eq!(source_token_instance.flow.flow_out, amount.into());
+
+    assert_err_contains!(
+        utils::execute_hub_message(
+            deps.as_mut(),
+            router_message.cc_id.clone(),
+            source_its_contract.clone(),
+            msg,
+        ),
+        ExecuteError,
+        ExecuteError::FlowLimitExceeded { .. }
+    );
+
+    let destination_token_instance = assert_ok!(utils::query_token_instance(
+        deps.as_ref(),
+        destination_its_chain,
+        token_id
+    ))
+    .unwrap();
+    assert_eq!(destination_token_instance.flow_limit, Some(flow_limit));
+    assert_eq!(destination_token_instance.flow.flow_in, amount.into());
+}
+
 #[test]
 fn deploy_interchain_token_submitted_twice_fails() {
     let (
@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use assert_ok::assert_ok;
 use cosmwasm_std::testing::mock_dependencies;
-use cosmwasm_std::Uint256;
-use interchain_token_service::TokenId;
-use router_api::{Address, ChainNameRaw};
+use cosmwasm_std::{HexBinary, Uint256};
+use interchain_token_service::msg::{ChainSupply, SimulateMessageResponse, SimulationError};
+use interchain_token_service::{
+    DeployInterchainToken, HubMessage, InterchainTransfer, TokenId, TokenSupply,
+};
+use router_api::{Address, ChainNameRaw, CrossChainId};
+use utils::TestMessage;
 
 mod utils;
 
@@ -102,3 +106,370 @@ fn query_token_chain_config() {
     let config = utils::query_token_instance(deps.as_ref(), chain, token_id).unwrap();
     assert_eq!(config, None);
 }
+
+#[test]
+fn query_token_registry() {
+    let (
+        mut deps,
+        TestMessage {
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            destination_its_contract,
+            ..
+        },
+    ) = utils::setup();
+
+    let token_ids = [
+        TokenId::new([1; 32]),
+        TokenId::new([2; 32]),
+        TokenId::new([3; 32]),
+    ];
+
+    // the first two tokens originate from the source chain, the last one from the destination chain
+    for (token_id, (source_chain, source_contract, destination_chain)) in token_ids.iter().zip([
+        (
+            &source_its_chain,
+            &source_its_contract,
+            &destination_its_chain,
+        ),
+        (
+            &source_its_chain,
+            &source_its_contract,
+            &destination_its_chain,
+        ),
+        (
+            &destination_its_chain,
+            &destination_its_contract,
+            &source_its_chain,
+        ),
+    ]) {
+        let msg = HubMessage::SendToHub {
+            destination_chain: destination_chain.clone(),
+            message: DeployInterchainToken {
+                token_id: *token_id,
+                name: "Test".try_into().unwrap(),
+                symbol: "TST".try_into().unwrap(),
+                decimals: 18,
+                minter: None,
+            }
+            .into(),
+        };
+        assert_ok!(utils::execute_hub_message(
+            deps.as_mut(),
+            CrossChainId::new(
+                source_chain.clone(),
+                router_message.cc_id.message_id.clone()
+            )
+            .unwrap(),
+            source_contract.clone(),
+            msg,
+        ));
+    }
+
+    let tokens = assert_ok!(utils::query_tokens(deps.as_ref(), None, None));
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|token| token.token_id)
+            .collect::<Vec<_>>(),
+        token_ids
+    );
+    assert_eq!(tokens[2].config.origin_chain, destination_its_chain);
+
+    let tokens = assert_ok!(utils::query_tokens(
+        deps.as_ref(),
+        Some(token_ids[0]),
+        Some(1)
+    ));
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|token| token.token_id)
+            .collect::<Vec<_>>(),
+        vec![token_ids[1]]
+    );
+
+    let token_instances = assert_ok!(utils::query_token_instances(deps.as_ref(), token_ids[0]));
+    assert_eq!(token_instances.len(), 2);
+    assert!(token_instances.contains_key(&source_its_chain));
+    assert!(token_instances.contains_key(&destination_its_chain));
+    assert!(assert_ok!(utils::query_token_instances(
+        deps.as_ref(),
+        TokenId::new([4; 32])
+    ))
+    .is_empty());
+
+    let chain_tokens = assert_ok!(utils::query_chain_tokens(
+        deps.as_ref(),
+        source_its_chain.clone(),
+        None,
+        None
+    ));
+    assert_eq!(
+        chain_tokens
+            .iter()
+            .map(|token| token.token_id)
+            .collect::<Vec<_>>(),
+        token_ids
+    );
+
+    let chain_tokens = assert_ok!(utils::query_chain_tokens(
+        deps.as_ref(),
+        source_its_chain,
+        Some(token_ids[0]),
+        Some(1)
+    ));
+    assert_eq!(chain_tokens.len(), 1);
+    assert_eq!(chain_tokens[0].token_id, token_ids[1]);
+}
+
+#[test]
+fn query_invariant_check() {
+    let TestMessage {
+        source_its_contract,
+        ..
+    } = TestMessage::dummy();
+    let chains: Vec<ChainNameRaw> = vec![
+        "ethereum".parse().unwrap(),
+        "stellar".parse().unwrap(),
+        "sui".parse().unwrap(),
+    ];
+    let (mut deps, TestMessage { router_message, .. }) = utils::setup_multiple_chains(vec![
+        (
+            chains[0].clone(),
+            source_its_contract.clone(),
+            Uint256::MAX.try_into().unwrap(),
+            u8::MAX,
+        ),
+        (
+            chains[1].clone(),
+            source_its_contract.clone(),
+            Uint256::from(u128::MAX).try_into().unwrap(),
+            12,
+        ),
+        (
+            chains[2].clone(),
+            source_its_contract.clone(),
+            Uint256::from(u64::MAX).try_into().unwrap(),
+            6,
+        ),
+    ]);
+    let token_id = TokenId::new([1; 32]);
+    let cc_id = |source_chain: &ChainNameRaw| CrossChainId {
+        source_chain: source_chain.clone(),
+        message_id: router_message.cc_id.message_id.clone(),
+    };
+
+    assert_eq!(
+        assert_ok!(utils::query_invariant_check(deps.as_ref(), token_id)),
+        None
+    );
+
+    for destination_chain in [&chains[1], &chains[2]] {
+        assert_ok!(utils::execute_hub_message(
+            deps.as_mut(),
+            cc_id(&chains[0]),
+            source_its_contract.clone(),
+            HubMessage::SendToHub {
+                destination_chain: destination_chain.clone(),
+                message: DeployInterchainToken {
+                    token_id,
+                    name: "Test".try_into().unwrap(),
+                    symbol: "TST".try_into().unwrap(),
+                    decimals: 18,
+                    minter: None,
+                }
+                .into(),
+            },
+        ));
+    }
+
+    // 1 unit is truncated when scaling from 18 to 12 decimals, and 10 units when scaling from 12 to 6 decimals
+    for (source_chain, destination_chain, amount) in [
+        (&chains[0], &chains[1], 1_000_000_000_010_000_001u128),
+        (&chains[1], &chains[2], 1_000_000_000_010u128),
+    ] {
+        assert_ok!(utils::execute_hub_message(
+            deps.as_mut(),
+            cc_id(source_chain),
+            source_its_contract.clone(),
+            HubMessage::SendToHub {
+                destination_chain: destination_chain.clone(),
+                message: InterchainTransfer {
+                    token_id,
+                    source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+                    destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+                    amount: Uint256::from(amount).try_into().unwrap(),
+                    data: None,
+                }
+                .into(),
+            },
+        ));
+    }
+
+    let response = assert_ok!(utils::query_invariant_check(deps.as_ref(), token_id)).unwrap();
+    assert_eq!(response.origin_chain, chains[0]);
+    assert_eq!(response.origin_decimals, 18);
+    assert_eq!(
+        response.chains,
+        vec![
+            ChainSupply {
+                chain: chains[0].clone(),
+                decimals: 18,
+                supply: TokenSupply::Untracked,
+                scaling_factor: Uint256::one(),
+                supply_on_origin: None,
+                rounding_loss: Uint256::one(),
+                rounding_loss_on_origin: Uint256::one(),
+                accrued_fees: Uint256::zero(),
+                accrued_fees_on_origin: Uint256::zero(),
+            },
+            ChainSupply {
+                chain: chains[1].clone(),
+                decimals: 12,
+                supply: TokenSupply::Tracked(Uint256::zero()),
+                scaling_factor: Uint256::from(1_000_000u128),
+                supply_on_origin: Some(Uint256::zero()),
+                rounding_loss: Uint256::from(10u128),
+                rounding_loss_on_origin: Uint256::from(10_000_000u128),
+                accrued_fees: Uint256::zero(),
+                accrued_fees_on_origin: Uint256::zero(),
+            },
+            ChainSupply {
+                chain: chains[2].clone(),
+                decimals: 6,
+                supply: TokenSupply::Tracked(Uint256::from(1_000_000u128)),
+                scaling_factor: Uint256::from(1_000_000_000_000u128),
+                supply_on_origin: Some(Uint256::from(1_000_000_000_000_000_000u128)),
+                rounding_loss: Uint256::zero(),
+                rounding_loss_on_origin: Uint256::zero(),
+                accrued_fees: Uint256::zero(),
+                accrued_fees_on_origin: Uint256::zero(),
+            },
+        ]
+    );
+    // everything that left the origin chain is accounted for
+    assert_eq!(
+        response
+            .total_supply_on_origin
+            .checked_add(response.total_rounding_loss_on_origin)
+            .unwrap(),
+        Uint256::from(1_000_000_000_010_000_001u128)
+    );
+    assert_eq!(
+        response.locked_on_origin,
+        Uint256::from(1_000_000_000_010_000_001u128)
+    );
+    assert!(response.violations.is_empty());
+}
+
+#[test]
+fn query_simulate_message() {
+    let (
+        mut deps,
+        TestMessage {
+            hub_message,
+            router_message,
+            source_its_chain,
+            source_its_contract,
+            destination_its_chain,
+            destination_its_contract,
+        },
+    ) = utils::setup();
+    let token_id = hub_message.token_id();
+
+    assert_eq!(
+        assert_ok!(utils::query_simulate_message(
+            deps.as_ref(),
+            source_its_chain.clone(),
+            source_its_contract.clone(),
+            hub_message.clone().abi_encode(),
+        )),
+        SimulateMessageResponse::Forwarded {
+            destination_chain: destination_its_chain.clone(),
+            payload: HubMessage::ReceiveFromHub {
+                source_chain: source_its_chain.clone(),
+                message: hub_message.message().clone(),
+            }
+            .abi_encode(),
+        }
+    );
+    // the simulated deployment is not persisted
+    assert_eq!(
+        assert_ok!(utils::query_token_config(deps.as_ref(), token_id)),
+        None
+    );
+
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        router_message.cc_id.clone(),
+        source_its_contract.clone(),
+        hub_message,
+    ));
+
+    let transfer =
+        |source_chain: &ChainNameRaw, destination_chain: &ChainNameRaw| HubMessage::SendToHub {
+            destination_chain: destination_chain.clone(),
+            message: InterchainTransfer {
+                token_id,
+                source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+                destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+                amount: Uint256::from(100u128).try_into().unwrap(),
+                data: None,
+            }
+            .into(),
+        };
+
+    assert_eq!(
+        assert_ok!(utils::query_simulate_message(
+            deps.as_ref(),
+            source_its_chain.clone(),
+            source_its_contract.clone(),
+            transfer(&source_its_chain, &destination_its_chain).abi_encode(),
+        )),
+        SimulateMessageResponse::Forwarded {
+            destination_chain: destination_its_chain.clone(),
+            payload: HubMessage::ReceiveFromHub {
+                source_chain: source_its_chain.clone(),
+                message: transfer(&source_its_chain, &destination_its_chain)
+                    .message()
+                    .clone(),
+            }
+            .abi_encode(),
+        }
+    );
+    // the simulated transfer didn't increase the supply, so transferring the amount back is rejected
+    let SimulateMessageResponse::Rejected { error } = assert_ok!(utils::query_simulate_message(
+        deps.as_ref(),
+        destination_its_chain.clone(),
+        destination_its_contract.clone(),
+        transfer(&destination_its_chain, &source_its_chain).abi_encode(),
+    )) else {
+        panic!("transfer exceeding the supply should be rejected");
+    };
+    assert_eq!(error.code, "token_supply_invariant_violated");
+    assert_eq!(
+        error.messages[0],
+        format!(
+            "token supply invariant violated for token {} on chain {}",
+            token_id, destination_its_chain
+        )
+    );
+
+    assert_eq!(
+        assert_ok!(utils::query_simulate_message(
+            deps.as_ref(),
+            source_its_chain.clone(),
+            destination_its_contract.clone(),
+            transfer(&source_its_chain, &destination_its_chain).abi_encode(),
+        )),
+        SimulateMessageResponse::Rejected {
+            error: SimulationError {
+                code: "unknown_its_contract".to_string(),
+                messages: vec![format!("unknown its address {}", destination_its_contract)],
+            },
+        }
+    );
+}
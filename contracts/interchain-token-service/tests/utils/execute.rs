@@ -7,11 +7,11 @@ use axelar_wasm_std::error::ContractError;
 use axelar_wasm_std::nonempty;
 use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_json, to_json_binary, DepsMut, HexBinary, MemoryStorage, OwnedDeps, Response, Uint256,
-    WasmQuery,
+    from_json, to_json_binary, Coin, DepsMut, HexBinary, MemoryStorage, OwnedDeps, Response,
+    Uint256, WasmQuery,
 };
 use interchain_token_service::msg::{self, ExecuteMsg, TruncationConfig};
-use interchain_token_service::{contract, HubMessage};
+use interchain_token_service::{contract, HubMessage, PayloadCodec, TokenId};
 use router_api::{Address, ChainName, ChainNameRaw, CrossChainId};
 
 use super::{instantiate_contract, TestMessage};
@@ -99,10 +99,26 @@ pub fn register_chain(
                 max_uint,
                 max_decimals_when_truncating,
             },
+            payload_codec: PayloadCodec::Abi,
         }],
     )
 }
 
+/// Registers the axelar chain with the hub, so messages can be sent to Axelar itself
+pub fn register_axelar_chain(deps: DepsMut) -> ChainNameRaw {
+    let axelar_chain: ChainNameRaw = "axelar".parse().unwrap();
+    register_chain(
+        deps,
+        axelar_chain.clone(),
+        "axelar-its-hub".parse().unwrap(),
+        Uint256::from(u128::MAX).try_into().unwrap(),
+        18,
+    )
+    .unwrap();
+
+    axelar_chain
+}
+
 pub fn register_chains(
     deps: DepsMut,
     chains: Vec<msg::ChainConfig>,
@@ -131,6 +147,144 @@ pub fn update_chain(
     )
 }
 
+pub fn freeze_token(deps: DepsMut, token_id: TokenId) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::ADMIN), &[]),
+        ExecuteMsg::FreezeToken { token_id },
+    )
+}
+
+pub fn unfreeze_token(deps: DepsMut, token_id: TokenId) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::UnfreezeToken { token_id },
+    )
+}
+
+pub fn set_flow_limit(
+    deps: DepsMut,
+    token_id: TokenId,
+    chain: ChainNameRaw,
+    flow_limit: Option<nonempty::Uint256>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::ADMIN), &[]),
+        ExecuteMsg::SetFlowLimit {
+            token_id,
+            chain,
+            flow_limit,
+        },
+    )
+}
+
+pub fn update_custom_token_metadata(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+    decimals: u8,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::UpdateCustomTokenMetadata {
+            chain,
+            token_address,
+            decimals,
+        },
+    )
+}
+
+pub fn remove_custom_token_metadata(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::RemoveCustomTokenMetadata {
+            chain,
+            token_address,
+        },
+    )
+}
+
+pub fn set_token_transfer_fee(
+    deps: DepsMut,
+    token_id: TokenId,
+    fee: Option<u16>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::SetTokenTransferFee { token_id, fee },
+    )
+}
+
+pub fn set_chain_pair_transfer_fee(
+    deps: DepsMut,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    fee: Option<u16>,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::SetChainPairTransferFee {
+            source_chain,
+            destination_chain,
+            fee,
+        },
+    )
+}
+
+pub fn withdraw_fees(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    recipient: nonempty::HexBinary,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::WithdrawFees {
+            chain,
+            token_id,
+            recipient,
+        },
+    )
+}
+
+pub fn interchain_transfer(
+    deps: DepsMut,
+    sender: &str,
+    funds: &[Coin],
+    destination_chain: ChainNameRaw,
+    destination_address: nonempty::HexBinary,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(sender), funds),
+        ExecuteMsg::InterchainTransfer {
+            destination_chain,
+            destination_address,
+            data: None,
+        },
+    )
+}
+
 pub fn setup_multiple_chains(
     configs: Vec<(ChainNameRaw, Address, nonempty::Uint256, u8)>,
 ) -> (
@@ -1,11 +1,15 @@
 use std::collections::HashMap;
 
 use axelar_wasm_std::error::ContractError;
+use axelar_wasm_std::nonempty;
 use cosmwasm_std::testing::mock_env;
-use cosmwasm_std::{from_json, Deps};
+use cosmwasm_std::{from_json, Deps, HexBinary, Uint256};
 use interchain_token_service::contract::query;
-use interchain_token_service::msg::QueryMsg;
-use interchain_token_service::{TokenConfig, TokenId, TokenInstance};
+use interchain_token_service::msg::{
+    InvariantCheckResponse, QueryMsg, SimulateMessageResponse, TokenConfigResponse,
+    TokenInstanceResponse,
+};
+use interchain_token_service::{CustomTokenMetadata, TokenConfig, TokenId, TokenInstance};
 use router_api::{Address, ChainNameRaw};
 
 pub fn query_its_contract(
@@ -43,3 +47,107 @@ pub fn query_token_config(
     let bin = query(deps, mock_env(), QueryMsg::TokenConfig { token_id })?;
     Ok(from_json(bin)?)
 }
+
+pub fn query_tokens(
+    deps: Deps,
+    start_after: Option<TokenId>,
+    limit: Option<u32>,
+) -> Result<Vec<TokenConfigResponse>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::Tokens { start_after, limit })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_token_instances(
+    deps: Deps,
+    token_id: TokenId,
+) -> Result<HashMap<ChainNameRaw, TokenInstance>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::TokenInstances { token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_chain_tokens(
+    deps: Deps,
+    chain: ChainNameRaw,
+    start_after: Option<TokenId>,
+    limit: Option<u32>,
+) -> Result<Vec<TokenInstanceResponse>, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::ChainTokens {
+            chain,
+            start_after,
+            limit,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_invariant_check(
+    deps: Deps,
+    token_id: TokenId,
+) -> Result<Option<InvariantCheckResponse>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::InvariantCheck { token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_custom_token_metadata(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_address: nonempty::HexBinary,
+) -> Result<Option<CustomTokenMetadata>, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::CustomTokenMetadata {
+            chain,
+            token_address,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_transfer_fee(
+    deps: Deps,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<u16, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::TransferFee {
+            source_chain,
+            destination_chain,
+            token_id,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_accrued_fees(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Uint256, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::AccruedFees { chain, token_id })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_simulate_message(
+    deps: Deps,
+    source_chain: ChainNameRaw,
+    source_address: Address,
+    payload: HexBinary,
+) -> Result<SimulateMessageResponse, ContractError> {
+    let bin = query(
+        deps,
+        mock_env(),
+        QueryMsg::SimulateMessage {
+            source_chain,
+            source_address,
+            payload,
+        },
+    )?;
+    Ok(from_json(bin)?)
+}
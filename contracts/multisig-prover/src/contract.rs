@@ -140,8 +140,10 @@ mod tests {
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
     use cosmwasm_std::{
-        from_json, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128, Uint64,
+        from_json, to_json_binary, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult,
+        Uint128, Uint64, WasmQuery,
     };
+    use gateway_api::msg::OutgoingMessageStatus;
     use multisig::msg::Signer;
     use multisig::verifier_set::VerifierSet;
     use prost::Message;
@@ -717,6 +719,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn construct_proof_for_cancelled_message_should_fail() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let default_handler = mock_querier_handler(
+            test_data::operators(),
+            VerificationStatus::SucceededOnSourceChain,
+        );
+        deps.querier.update_wasm(move |wq| match wq {
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == MockApi::default().addr_make(GATEWAY_ADDRESS).as_str()
+                    && matches!(
+                        from_json::<gateway_api::msg::QueryMsg>(msg).unwrap(),
+                        gateway_api::msg::QueryMsg::OutgoingMessageStatuses(_)
+                    ) =>
+            {
+                Ok(to_json_binary(&vec![OutgoingMessageStatus::Cancelled]).into()).into()
+            }
+            _ => default_handler(wq),
+        });
+
+        let res = execute_construct_proof(deps.as_mut(), None);
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::MessageNotRelayable {
+                cc_id: test_data::messages()[0].cc_id.clone(),
+                status: OutgoingMessageStatus::Cancelled,
+            })
+            .to_string()
+        );
+    }
+
     #[test]
     fn test_construct_proof_no_verifier_set() {
         let mut deps = setup_test_case();
@@ -7,6 +7,7 @@ use axelar_wasm_std::{
 };
 use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, QuerierWrapper, Response, Storage, SubMsg};
 use error_stack::{report, Result, ResultExt};
+use gateway_api::msg::OutgoingMessageStatus;
 use itertools::Itertools;
 use multisig::msg::Signer;
 use multisig::verifier_set::VerifierSet;
@@ -90,6 +91,22 @@ fn messages(
 
     let gateway: gateway_api::Client = client::ContractClient::new(querier, &gateway).into();
 
+    let statuses = gateway
+        .outgoing_message_statuses(message_ids.clone())
+        .change_context(ContractError::FailedToGetMessages)?;
+
+    if let Some((cc_id, status)) = message_ids
+        .iter()
+        .zip(statuses)
+        .find(|(_, status)| !matches!(status, OutgoingMessageStatus::Pending { .. }))
+    {
+        return Err(ContractError::MessageNotRelayable {
+            cc_id: cc_id.clone(),
+            status,
+        }
+        .into());
+    }
+
     let messages = gateway
         .outgoing_messages(message_ids)
         .change_context(ContractError::FailedToGetMessages)?;
@@ -1,7 +1,8 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_std::StdError;
 use cw_utils::ParseReplyError;
-use router_api::ChainName;
+use gateway_api::msg::OutgoingMessageStatus;
+use router_api::{ChainName, CrossChainId};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, IntoContractError)]
@@ -85,6 +86,12 @@ pub enum ContractError {
     #[error("failed to get outgoing messages from gateway")]
     FailedToGetMessages,
 
+    #[error("message {cc_id} can no longer be relayed, its status is {status:?}")]
+    MessageNotRelayable {
+        cc_id: CrossChainId,
+        status: OutgoingMessageStatus,
+    },
+
     #[error("failed to build verifier set")]
     FailedToBuildVerifierSet,
 
@@ -1,6 +1,7 @@
 use axelar_wasm_std::VerificationStatus;
 use cosmwasm_std::testing::MockApi;
 use cosmwasm_std::{from_json, to_json_binary, QuerierResult, Uint128, WasmQuery};
+use gateway_api::msg::OutgoingMessageStatus;
 use multisig::msg::Signer;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
@@ -24,10 +25,10 @@ pub fn mock_querier_handler(
     verifier_set_status: VerificationStatus,
 ) -> impl Fn(&WasmQuery) -> QuerierResult {
     move |wq: &WasmQuery| match wq {
-        WasmQuery::Smart { contract_addr, .. }
+        WasmQuery::Smart { contract_addr, msg }
             if contract_addr == MockApi::default().addr_make(GATEWAY_ADDRESS).as_str() =>
         {
-            gateway_mock_querier_handler()
+            gateway_mock_querier_handler(from_json(msg).unwrap())
         }
         WasmQuery::Smart { contract_addr, msg }
             if contract_addr == MockApi::default().addr_make(MULTISIG_ADDRESS).as_str() =>
@@ -54,8 +55,18 @@ pub fn mock_querier_handler(
     }
 }
 
-fn gateway_mock_querier_handler() -> QuerierResult {
-    Ok(to_json_binary(&test_data::messages()).into()).into()
+fn gateway_mock_querier_handler(msg: gateway_api::msg::QueryMsg) -> QuerierResult {
+    let result = match msg {
+        gateway_api::msg::QueryMsg::OutgoingMessages(_) => to_json_binary(&test_data::messages()),
+        gateway_api::msg::QueryMsg::OutgoingMessageStatuses(ids) => to_json_binary(
+            &ids.iter()
+                .map(|_| OutgoingMessageStatus::Pending { expires_at: None })
+                .collect::<Vec<_>>(),
+        ),
+        _ => panic!("unexpected query: {:?}", msg),
+    };
+
+    Ok(result.into()).into()
 }
 
 fn multisig_mock_querier_handler(
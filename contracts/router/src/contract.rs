@@ -23,7 +23,7 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     let old_version = Version::parse(&cw2::get_contract_version(deps.storage)?.version)?;
@@ -31,6 +31,7 @@ pub fn migrate(
     assert!(version_requirement.matches(&old_version));
 
     v1_1_1::migrate(deps.storage, msg.chains_to_remove)?;
+    v1_1_1::seed_gateway_history(deps.storage, env.block.height)?;
 
     // this needs to be the last thing to do during migration,
     // because previous migration steps should check the old version
@@ -72,7 +73,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
@@ -90,6 +91,7 @@ pub fn execute(
             Ok(execute::register_chain(
                 deps.storage,
                 deps.querier,
+                env.block.height,
                 chain,
                 gateway_address,
                 msg_id_format,
@@ -100,13 +102,58 @@ pub fn execute(
             contract_address,
         } => {
             let contract_address = address::validate_cosmwasm_address(deps.api, &contract_address)?;
-            execute::upgrade_gateway(deps.storage, chain, contract_address)
+            Ok(execute::upgrade_gateway(
+                deps.storage,
+                env.block.height,
+                chain,
+                contract_address,
+            )?)
         }
+        ExecuteMsg::DeregisterChain { chain } => Ok(execute::deregister_chain(
+            deps.storage,
+            env.block.height,
+            chain,
+        )?),
         ExecuteMsg::FreezeChains { chains } => execute::freeze_chains(deps.storage, chains),
-        ExecuteMsg::UnfreezeChains { chains } => execute::unfreeze_chains(deps.storage, chains),
+        ExecuteMsg::UnfreezeChains { chains } => Ok(execute::unfreeze_chains(
+            deps.storage,
+            env.block.height,
+            chains,
+        )?),
+        ExecuteMsg::SetRoutePolicy {
+            source_chain,
+            policy,
+        } => Ok(execute::set_route_policy(
+            deps.storage,
+            source_chain,
+            policy,
+        )?),
+        ExecuteMsg::RemoveRoutePolicy { source_chain } => {
+            Ok(execute::remove_route_policy(deps.storage, source_chain)?)
+        }
+        ExecuteMsg::SetRoutedMessageHorizon { blocks } => {
+            Ok(execute::set_routed_message_horizon(deps.storage, blocks)?)
+        }
+        ExecuteMsg::FlushQueuedMessages { chain, limit } => Ok(execute::flush_queued_messages(
+            deps.storage,
+            env.block.height,
+            chain,
+            limit,
+        )?),
+        ExecuteMsg::SetRateLimit {
+            chain,
+            direction,
+            limit,
+        } => Ok(execute::set_rate_limit(
+            deps.storage,
+            chain,
+            direction,
+            limit,
+        )?),
         ExecuteMsg::RouteMessages(msgs) => Ok(execute::route_messages(
             deps.storage,
             deps.querier,
+            env.block.height,
             info.sender,
             msgs,
         )?),
@@ -135,7 +182,7 @@ fn find_gateway_address(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -144,13 +191,38 @@ pub fn query(
             to_json_binary(&query::chains(deps.storage, start_after, limit)?)
         }
         QueryMsg::IsEnabled => to_json_binary(&killswitch::is_contract_active(deps.storage)),
+        QueryMsg::GatewayHistory { chain } => {
+            to_json_binary(&query::gateway_history(deps.storage, chain)?)
+        }
+        QueryMsg::RoutePolicy { source_chain } => {
+            to_json_binary(&query::route_policy(deps.storage, source_chain)?)
+        }
+        QueryMsg::RoutePolicies { start_after, limit } => {
+            to_json_binary(&query::route_policies(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::RoutedMessage { cc_id } => {
+            to_json_binary(&query::routed_message(deps.storage, cc_id)?)
+        }
+        QueryMsg::QueuedMessages {
+            chain,
+            start_after,
+            limit,
+        } => to_json_binary(&query::queued_messages(
+            deps.storage,
+            chain,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::RateLimits { chain } => {
+            to_json_binary(&query::rate_limits(deps.storage, chain, env.block.height)?)
+        }
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
 }
 
 #[cfg(test)]
 mod test {
-    use std::collections::HashMap;
+    use std::collections::{BTreeSet, HashMap};
     use std::str::FromStr;
 
     use axelar_core_std::nexus::test_utils::reply_with_is_chain_registered;
@@ -164,7 +236,9 @@ mod test {
     use permission_control::Permission;
     use router_api::error::Error;
     use router_api::{
-        ChainEndpoint, ChainName, CrossChainId, GatewayDirection, Message, FIELD_DELIMITER,
+        ChainEndpoint, ChainName, ChainRateLimits, ChainRoutePolicy, CrossChainId,
+        GatewayDirection, GatewayHistoryEntry, Message, QueuedMessage, RateLimit, RateLimitStatus,
+        RoutePolicy, RoutedMessage, RoutingStatus, FIELD_DELIMITER,
     };
 
     use super::*;
@@ -298,15 +372,22 @@ mod test {
         assert_eq!(res.messages.len(), 1);
         assert_messages_in_cosmos_msg(polygon.gateway, messages.clone(), &res.messages[0].msg);
 
-        // try to route twice
+        // routing twice skips the already routed messages
         let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            res.events,
+            messages
+                .into_iter()
+                .map(|msg| events::MessageAlreadyRouted { msg }.into())
+                .collect::<Vec<cosmwasm_std::Event>>()
         );
-
-        assert!(res.is_ok(), "{:?}", res);
     }
 
     #[test]
@@ -941,12 +1022,102 @@ mod test {
 
         // can still send to the chain, messages will queue up
         let messages = &generate_messages(&eth, &polygon, &mut 0, 1);
-        let err = execute(
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+
+        // queued messages are released on unfreeze
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::UnfreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages.clone(),
+            &res.messages[0].msg,
+        );
+        assert!(query_queued_messages(deps.as_ref(), &polygon, None, None).is_empty());
+
+        let messages = &generate_messages(&eth, &polygon, &mut 1, 1);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_messages_in_cosmos_msg(polygon.gateway, messages.clone(), &res.messages[0].msg);
+    }
+
+    #[test]
+    fn queued_messages_can_be_queried_and_flushed() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::FreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 5);
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(messages.clone()),
         )
+        .unwrap();
+
+        let queued = query_queued_messages(deps.as_ref(), &polygon, None, Some(3));
+        assert_eq!(
+            queued,
+            messages[..3]
+                .iter()
+                .enumerate()
+                .map(|(id, msg)| QueuedMessage {
+                    id: id as u64,
+                    msg: msg.clone(),
+                })
+                .collect::<Vec<_>>()
+        );
+        let queued = query_queued_messages(deps.as_ref(), &polygon, Some(2), None);
+        assert_eq!(
+            queued.into_iter().map(|q| q.msg).collect::<Vec<_>>(),
+            messages[3..].to_vec()
+        );
+
+        // can't flush while the chain is frozen
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("anyone"), &[]),
+            ExecuteMsg::FlushQueuedMessages {
+                chain: polygon.chain_name.clone(),
+                limit: 2,
+            },
+        )
         .unwrap_err();
         assert_contract_err_string_contains(
             err,
@@ -955,6 +1126,76 @@ mod test {
             },
         );
 
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::UnfreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Incoming)]),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            query_queued_messages(deps.as_ref(), &polygon, None, None).len(),
+            5
+        );
+
+        // unfreezing the outgoing direction releases the queue
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::UnfreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_messages_in_cosmos_msg(polygon.gateway.clone(), messages, &res.messages[0].msg);
+        assert!(query_queued_messages(deps.as_ref(), &polygon, None, None).is_empty());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("anyone"), &[]),
+            ExecuteMsg::FlushQueuedMessages {
+                chain: polygon.chain_name.clone(),
+                limit: 2,
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn flush_queued_messages_releases_messages_in_batches() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::FreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 105);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // only a limited number of messages is released on unfreeze
         let res = execute(
             deps.as_mut(),
             mock_env(),
@@ -962,19 +1203,164 @@ mod test {
             ExecuteMsg::UnfreezeChains {
                 chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
             },
+        )
+        .unwrap();
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages[..100].to_vec(),
+            &res.messages[0].msg,
         );
-        assert!(res.is_ok());
 
         let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("anyone"), &[]),
+            ExecuteMsg::FlushQueuedMessages {
+                chain: polygon.chain_name.clone(),
+                limit: 3,
+            },
+        )
+        .unwrap();
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages[100..103].to_vec(),
+            &res.messages[0].msg,
+        );
+        assert_eq!(
+            query_queued_messages(deps.as_ref(), &polygon, None, None)
+                .into_iter()
+                .map(|queued_msg| queued_msg.msg)
+                .collect::<Vec<_>>(),
+            messages[103..].to_vec()
+        );
+    }
+
+    #[test]
+    fn released_messages_are_subject_to_route_policies_and_rate_limits() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::FreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
+
+        let nonce = &mut 0;
+        let denied_messages = generate_messages(&eth, &polygon, nonce, 2);
+        let messages = generate_messages(&avalanche, &polygon, nonce, 5);
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(denied_messages.clone()),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&avalanche.gateway, &[]),
             ExecuteMsg::RouteMessages(messages.clone()),
         )
         .unwrap();
 
+        set_route_policy(
+            deps.as_mut(),
+            &eth,
+            RoutePolicy::Denylist(BTreeSet::from([polygon.chain_name.clone()])),
+        )
+        .unwrap();
+        set_rate_limit(
+            deps.as_mut(),
+            &polygon,
+            GatewayDirection::Outgoing,
+            Some(RateLimit {
+                max_messages: 3,
+                window: 100u64.try_into().unwrap(),
+            }),
+        )
+        .unwrap();
+
+        // denied messages are dropped, and only as many messages as the rate limit allows are released
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::UnfreezeChains {
+                chains: HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]),
+            },
+        )
+        .unwrap();
         assert_eq!(res.messages.len(), 1);
-        assert_messages_in_cosmos_msg(polygon.gateway, messages.clone(), &res.messages[0].msg);
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages[..3].to_vec(),
+            &res.messages[0].msg,
+        );
+        let dropped: Vec<_> = res
+            .events
+            .iter()
+            .filter(|event| event.ty == "message_dropped")
+            .collect();
+        assert_eq!(dropped.len(), 2);
+        assert!(dropped
+            .iter()
+            .all(
+                |event| event.attributes.iter().any(|attr| attr.key == "reason"
+                    && attr.value
+                        == Error::RouteNotAllowed {
+                            source_chain: eth.chain_name.clone(),
+                            destination_chain: polygon.chain_name.clone(),
+                        }
+                        .to_string())
+            ));
+        assert_eq!(
+            query_queued_messages(deps.as_ref(), &polygon, None, None)
+                .into_iter()
+                .map(|queued_msg| queued_msg.msg)
+                .collect::<Vec<_>>(),
+            messages[3..].to_vec()
+        );
+
+        let flush = |deps: DepsMut, env: Env, limit| {
+            execute(
+                deps,
+                env,
+                message_info(&api.addr_make("anyone"), &[]),
+                ExecuteMsg::FlushQueuedMessages {
+                    chain: polygon.chain_name.clone(),
+                    limit,
+                },
+            )
+        };
+
+        // the rate limit is used up for the current window
+        let res = flush(deps.as_mut(), mock_env(), 2).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            query_queued_messages(deps.as_ref(), &polygon, None, None).len(),
+            2
+        );
+
+        let mut env = mock_env();
+        env.block.height += 100;
+        let res = flush(deps.as_mut(), env, 2).unwrap();
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages[3..].to_vec(),
+            &res.messages[0].msg,
+        );
+        assert!(query_queued_messages(deps.as_ref(), &polygon, None, None).is_empty());
     }
 
     #[test]
@@ -1012,20 +1398,15 @@ mod test {
         assert!(res.is_ok());
 
         let msg = &generate_messages(&eth, &polygon, nonce, 1)[0];
-        let err = execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(vec![msg.clone()]),
         )
-        .unwrap_err();
-        // can't route to frozen chain
-        assert_contract_err_string_contains(
-            err,
-            Error::ChainFrozen {
-                chain: polygon.chain_name.clone(),
-            },
-        );
+        .unwrap();
+        // messages to frozen chain are queued
+        assert!(res.messages.is_empty());
 
         // can't route from frozen chain
         let message = &generate_messages(&polygon, &eth, nonce, 1)[0];
@@ -1233,20 +1614,15 @@ mod test {
         assert!(res.is_ok());
 
         let message = &generate_messages(&eth, &polygon, nonce, 1)[0];
-        let err = execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(vec![message.clone()]),
         )
-        .unwrap_err();
+        .unwrap();
         // can't route to the chain
-        assert_contract_err_string_contains(
-            err,
-            Error::ChainFrozen {
-                chain: polygon.chain_name.clone(),
-            },
-        );
+        assert!(res.messages.is_empty());
     }
 
     #[test]
@@ -1341,21 +1717,16 @@ mod test {
         .unwrap();
 
         let nonce = &mut 0;
-        // can't route to frozen chain
+        // messages to frozen chain are queued
         let message = &generate_messages(&eth, &polygon, nonce, 1)[0];
-        let err = execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(vec![message.clone()]),
         )
-        .unwrap_err();
-        assert_contract_err_string_contains(
-            err,
-            Error::ChainFrozen {
-                chain: polygon.chain_name.clone(),
-            },
-        );
+        .unwrap();
+        assert!(res.messages.is_empty());
 
         // can't route from frozen chain
         let message = &generate_messages(&polygon, &eth, nonce, 1)[0];
@@ -1404,21 +1775,16 @@ mod test {
         .unwrap();
 
         let nonce = &mut 0;
-        // can't route to frozen chain
+        // messages to frozen chain are queued
         let message = &generate_messages(&eth, &polygon, nonce, 1)[0];
-        let err = execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(vec![message.clone()]),
         )
-        .unwrap_err();
-        assert_contract_err_string_contains(
-            err,
-            Error::ChainFrozen {
-                chain: polygon.chain_name.clone(),
-            },
-        );
+        .unwrap();
+        assert!(res.messages.is_empty());
 
         // can't route from frozen chain
         let message = &generate_messages(&polygon, &eth, nonce, 1)[0];
@@ -1604,19 +1970,14 @@ mod test {
 
         let nonce = &mut 0;
         let message = &generate_messages(&eth, &polygon, nonce, 1)[0];
-        let err = execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&eth.gateway, &[]),
             ExecuteMsg::RouteMessages(vec![message.clone()]),
         )
-        .unwrap_err();
-        assert_contract_err_string_contains(
-            err,
-            Error::ChainFrozen {
-                chain: polygon.chain_name.clone(),
-            },
-        );
+        .unwrap();
+        assert!(res.messages.is_empty());
 
         // can't route from frozen chain
         let message = &generate_messages(&polygon, &eth, nonce, 1)[0];
@@ -1681,6 +2042,7 @@ mod test {
         )
         .unwrap();
 
+        let messages = &generate_messages(&eth, &polygon, nonce, 1);
         let res = execute(
             deps.as_mut(),
             mock_env(),
@@ -1824,6 +2186,241 @@ mod test {
         .is_ok());
     }
 
+    fn set_rate_limit(
+        deps: DepsMut,
+        chain: &Chain,
+        direction: GatewayDirection,
+        limit: Option<RateLimit>,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetRateLimit {
+                chain: chain.chain_name.clone(),
+                direction,
+                limit,
+            },
+        )
+    }
+
+    fn query_queued_messages(
+        deps: Deps,
+        chain: &Chain,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<QueuedMessage> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::QueuedMessages {
+                    chain: chain.chain_name.clone(),
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn query_rate_limits(deps: Deps, env: Env, chain: &Chain) -> ChainRateLimits {
+        from_json(
+            query(
+                deps,
+                env,
+                QueryMsg::RateLimits {
+                    chain: chain.chain_name.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn set_rate_limit_requires_governance() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        register_chain(deps.as_mut(), &eth);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::SetRateLimit {
+                chain: eth.chain_name.clone(),
+                direction: GatewayDirection::Incoming,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn set_rate_limit_of_unknown_chain_fails() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+
+        let err =
+            set_rate_limit(deps.as_mut(), &eth, GatewayDirection::Incoming, None).unwrap_err();
+        assert_contract_err_string_contains(err, Error::ChainNotFound);
+    }
+
+    #[test]
+    fn rate_limit_incoming_messages() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let limit = RateLimit {
+            max_messages: 10,
+            window: 100u64.try_into().unwrap(),
+        };
+        assert!(
+            set_rate_limit(deps.as_mut(), &eth, GatewayDirection::Incoming, Some(limit)).is_ok()
+        );
+
+        let nonce = &mut 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 8)),
+        )
+        .is_ok());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 3)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RateLimitExceeded {
+                chain: eth.chain_name.clone(),
+            },
+        );
+
+        // the outgoing direction of the chain is not limited
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, nonce, 20)),
+        )
+        .is_ok());
+
+        let rate_limits = query_rate_limits(deps.as_ref(), mock_env(), &eth);
+        assert_eq!(
+            rate_limits,
+            ChainRateLimits {
+                incoming: Some(RateLimitStatus {
+                    limit,
+                    window_start: 12300,
+                    routed_messages: 8,
+                }),
+                outgoing: None,
+            }
+        );
+
+        // the usage is reset in the next window
+        let mut env = mock_env();
+        env.block.height = 12400;
+        assert!(execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 10)),
+        )
+        .is_ok());
+        assert_eq!(
+            query_rate_limits(deps.as_ref(), env, &eth).incoming,
+            Some(RateLimitStatus {
+                limit,
+                window_start: 12400,
+                routed_messages: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn rate_limit_outgoing_messages() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        assert!(set_rate_limit(
+            deps.as_mut(),
+            &polygon,
+            GatewayDirection::Bidirectional,
+            Some(RateLimit {
+                max_messages: 5,
+                window: 10u64.try_into().unwrap(),
+            })
+        )
+        .is_ok());
+
+        let nonce = &mut 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 5)),
+        )
+        .is_ok());
+
+        // messages from all sources count against the limit of the destination
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&avalanche.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&avalanche, &polygon, nonce, 1)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RateLimitExceeded {
+                chain: polygon.chain_name.clone(),
+            },
+        );
+
+        let rate_limits = query_rate_limits(deps.as_ref(), mock_env(), &polygon);
+        assert_eq!(rate_limits.outgoing.unwrap().routed_messages, 5);
+        assert_eq!(rate_limits.incoming.unwrap().routed_messages, 0);
+
+        // removing the limit allows routing again
+        assert!(set_rate_limit(deps.as_mut(), &polygon, GatewayDirection::Outgoing, None).is_ok());
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&avalanche.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&avalanche, &polygon, nonce, 1)),
+        )
+        .is_ok());
+
+        let rate_limits = query_rate_limits(deps.as_ref(), mock_env(), &polygon);
+        assert!(rate_limits.outgoing.is_none());
+        assert!(rate_limits.incoming.is_some());
+    }
+
     #[test]
     fn chain_info_fails_on_unregistered_chain() {
         let deps = setup();
@@ -1838,4 +2435,705 @@ mod test {
         .unwrap_err();
         goldie::assert!(err.to_string());
     }
+
+    fn set_route_policy(
+        deps: DepsMut,
+        source_chain: &Chain,
+        policy: RoutePolicy,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetRoutePolicy {
+                source_chain: source_chain.chain_name.clone(),
+                policy,
+            },
+        )
+    }
+
+    fn query_route_policy(deps: Deps, source_chain: &Chain) -> Option<RoutePolicy> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::RoutePolicy {
+                    source_chain: source_chain.chain_name.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn set_route_policy_requires_governance() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        register_chain(deps.as_mut(), &eth);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::SetRoutePolicy {
+                source_chain: eth.chain_name.clone(),
+                policy: RoutePolicy::Denylist(BTreeSet::new()),
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::RemoveRoutePolicy {
+                source_chain: eth.chain_name.clone(),
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn allowlist_restricts_destination_chains() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        let policy = RoutePolicy::Allowlist(BTreeSet::from([eth.chain_name.clone()]));
+        assert!(set_route_policy(deps.as_mut(), &polygon, policy.clone()).is_ok());
+        assert_eq!(query_route_policy(deps.as_ref(), &polygon), Some(policy));
+
+        let nonce = &mut 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, nonce, 1)),
+        )
+        .is_ok());
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &avalanche, nonce, 1)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RouteNotAllowed {
+                source_chain: polygon.chain_name.clone(),
+                destination_chain: avalanche.chain_name.clone(),
+            },
+        );
+
+        // the policy only applies to messages from the restricted chain
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &avalanche, nonce, 1)),
+        )
+        .is_ok());
+
+        // removing the policy allows all destinations again
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RemoveRoutePolicy {
+                source_chain: polygon.chain_name.clone(),
+            },
+        )
+        .is_ok());
+        assert_eq!(query_route_policy(deps.as_ref(), &polygon), None);
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &avalanche, nonce, 1)),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn denylist_rejects_listed_destination_chains() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        let avalanche = make_chain("avalanche");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_chain(deps.as_mut(), &avalanche);
+
+        assert!(set_route_policy(
+            deps.as_mut(),
+            &eth,
+            RoutePolicy::Denylist(BTreeSet::from([polygon.chain_name.clone()]))
+        )
+        .is_ok());
+
+        let nonce = &mut 0;
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &avalanche, nonce, 1)),
+        )
+        .is_ok());
+
+        // a single denied message rejects the whole batch
+        let mut msgs = generate_messages(&eth, &avalanche, nonce, 1);
+        msgs.extend(generate_messages(&eth, &polygon, nonce, 1));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(msgs),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::RouteNotAllowed {
+                source_chain: eth.chain_name.clone(),
+                destination_chain: polygon.chain_name.clone(),
+            },
+        );
+    }
+
+    #[test]
+    fn route_policies_can_be_listed() {
+        let mut deps = setup();
+        let chains: Vec<_> = ["avalanche", "ethereum", "polygon"]
+            .into_iter()
+            .map(make_chain)
+            .collect();
+        for chain in chains.iter() {
+            register_chain(deps.as_mut(), chain);
+        }
+
+        let policy = RoutePolicy::Denylist(BTreeSet::from([chains[0].chain_name.clone()]));
+        for chain in chains[1..].iter() {
+            assert!(set_route_policy(deps.as_mut(), chain, policy.clone()).is_ok());
+        }
+
+        let policies: Vec<ChainRoutePolicy> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::RoutePolicies {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            policies,
+            chains[1..]
+                .iter()
+                .map(|chain| ChainRoutePolicy {
+                    source_chain: chain.chain_name.clone(),
+                    policy: policy.clone(),
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let policies: Vec<ChainRoutePolicy> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::RoutePolicies {
+                    start_after: Some(chains[1].chain_name.clone()),
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].source_chain, chains[2].chain_name);
+    }
+
+    fn deregister_chain(deps: DepsMut, chain: &Chain) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::DeregisterChain {
+                chain: chain.chain_name.clone(),
+            },
+        )
+    }
+
+    fn freeze_chain_in_direction(deps: DepsMut, chain: &Chain, direction: GatewayDirection) {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&MockApi::default().addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::FreezeChains {
+                chains: HashMap::from([(chain.chain_name.clone(), direction)]),
+            },
+        )
+        .unwrap();
+    }
+
+    fn query_gateway_history(deps: Deps, chain: &Chain) -> Vec<GatewayHistoryEntry> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::GatewayHistory {
+                    chain: chain.chain_name.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn deregister_chain_requires_governance() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        register_chain(deps.as_mut(), &eth);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::DeregisterChain {
+                chain: eth.chain_name.clone(),
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn deregister_chain_fails_if_chain_is_not_frozen() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        register_chain(deps.as_mut(), &eth);
+
+        let err = deregister_chain(deps.as_mut(), &make_chain("polygon")).unwrap_err();
+        assert_contract_err_string_contains(err, Error::ChainNotFound);
+
+        freeze_chain_in_direction(deps.as_mut(), &eth, GatewayDirection::Incoming);
+        let err = deregister_chain(deps.as_mut(), &eth).unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::ChainNotFrozen {
+                chain: eth.chain_name.clone(),
+            },
+        );
+    }
+
+    #[test]
+    fn deregister_chain_fails_if_messages_are_queued() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        freeze_chain_in_direction(deps.as_mut(), &polygon, GatewayDirection::Bidirectional);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut 0, 1)),
+        )
+        .unwrap();
+
+        let err = deregister_chain(deps.as_mut(), &polygon).unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::ChainHasQueuedMessages {
+                chain: polygon.chain_name.clone(),
+            },
+        );
+    }
+
+    #[test]
+    fn deregister_chain_removes_chain_and_keeps_gateway_history() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        freeze_chain_in_direction(deps.as_mut(), &polygon, GatewayDirection::Bidirectional);
+        let res = deregister_chain(deps.as_mut(), &polygon).unwrap();
+        assert!(res.events.contains(
+            &events::ChainDeregistered {
+                name: polygon.chain_name.clone(),
+                gateway: polygon.gateway.clone(),
+            }
+            .into()
+        ));
+
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ChainInfo(polygon.chain_name.clone())
+        )
+        .is_err());
+
+        // the gateway of the deregistered chain can't route messages anymore
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, &mut 0, 1)),
+        )
+        .is_err());
+
+        let height = mock_env().block.height;
+        assert_eq!(
+            query_gateway_history(deps.as_ref(), &polygon),
+            vec![GatewayHistoryEntry {
+                address: polygon.gateway.clone(),
+                active_from: height,
+                active_until: Some(height),
+            }]
+        );
+
+        // the chain can be registered again afterwards
+        register_chain(deps.as_mut(), &polygon);
+        assert_eq!(query_gateway_history(deps.as_ref(), &polygon).len(), 2);
+    }
+
+    #[test]
+    fn upgrade_gateway_records_gateway_history() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        register_chain(deps.as_mut(), &eth);
+
+        let new_gateway = MockApi::default().addr_make("new gateway");
+        let mut env = mock_env();
+        env.block.height = mock_env().block.height.checked_add(100).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UpgradeGateway {
+                chain: eth.chain_name.clone(),
+                contract_address: new_gateway.to_string().try_into().unwrap(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_gateway_history(deps.as_ref(), &eth),
+            vec![
+                GatewayHistoryEntry {
+                    address: eth.gateway.clone(),
+                    active_from: mock_env().block.height,
+                    active_until: Some(env.block.height),
+                },
+                GatewayHistoryEntry {
+                    address: new_gateway,
+                    active_from: env.block.height,
+                    active_until: None,
+                },
+            ]
+        );
+    }
+
+    fn query_routed_message(deps: Deps, cc_id: &CrossChainId) -> Option<RoutedMessage> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::RoutedMessage {
+                    cc_id: cc_id.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn set_routed_message_horizon_requires_governance() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            ExecuteMsg::SetRoutedMessageHorizon {
+                blocks: 10u64.try_into().unwrap(),
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::Admin.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn routed_messages_are_indexed() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 2);
+        assert_eq!(
+            query_routed_message(deps.as_ref(), &messages[0].cc_id),
+            None
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        for msg in messages.iter() {
+            assert_eq!(
+                query_routed_message(deps.as_ref(), &msg.cc_id),
+                Some(RoutedMessage {
+                    destination_chain: polygon.chain_name.clone(),
+                    block_height: mock_env().block.height,
+                    status: RoutingStatus::Routed,
+                })
+            );
+        }
+
+        // duplicates within the same batch are only routed once
+        let message = generate_messages(&eth, &polygon, &mut 2, 1).remove(0);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(vec![message.clone(), message.clone()]),
+        )
+        .unwrap();
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            vec![message.clone()],
+            &res.messages[0].msg,
+        );
+        assert!(res
+            .events
+            .contains(&events::MessageAlreadyRouted { msg: message }.into()));
+    }
+
+    #[test]
+    fn queued_messages_are_recorded_as_routed_when_released() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let freeze_msg = |unfreeze| {
+            let chains = HashMap::from([(polygon.chain_name.clone(), GatewayDirection::Outgoing)]);
+            if unfreeze {
+                ExecuteMsg::UnfreezeChains { chains }
+            } else {
+                ExecuteMsg::FreezeChains { chains }
+            }
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            freeze_msg(false),
+        )
+        .unwrap();
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 2);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            query_routed_message(deps.as_ref(), &messages[0].cc_id),
+            Some(RoutedMessage {
+                destination_chain: polygon.chain_name.clone(),
+                block_height: mock_env().block.height,
+                status: RoutingStatus::Queued,
+            })
+        );
+
+        // retries of queued messages are not queued again
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events
+                .iter()
+                .filter(|event| event.ty == "message_already_routed")
+                .count(),
+            2
+        );
+        assert_eq!(
+            query_queued_messages(deps.as_ref(), &polygon, None, None).len(),
+            2
+        );
+
+        // messages whose route is no longer allowed are dropped and forgotten
+        set_route_policy(
+            deps.as_mut(),
+            &eth,
+            RoutePolicy::Denylist(BTreeSet::from([polygon.chain_name.clone()])),
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height = env.block.height.checked_add(5).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            freeze_msg(true),
+        )
+        .unwrap();
+        for msg in messages.iter() {
+            assert_eq!(query_routed_message(deps.as_ref(), &msg.cc_id), None);
+        }
+
+        // released messages are recorded as routed at the height of their release
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            freeze_msg(false),
+        )
+        .unwrap();
+        let messages = generate_messages(&eth, &polygon, &mut 2, 1);
+        set_route_policy(deps.as_mut(), &eth, RoutePolicy::Denylist(BTreeSet::new())).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&api.addr_make(ADMIN_ADDRESS), &[]),
+            freeze_msg(true),
+        )
+        .unwrap();
+        assert_eq!(
+            query_routed_message(deps.as_ref(), &messages[0].cc_id),
+            Some(RoutedMessage {
+                destination_chain: polygon.chain_name.clone(),
+                block_height: env.block.height,
+                status: RoutingStatus::Routed,
+            })
+        );
+    }
+
+    #[test]
+    fn routed_messages_expire_after_horizon() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::SetRoutedMessageHorizon {
+                blocks: 10u64.try_into().unwrap(),
+            },
+        )
+        .unwrap();
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 1);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // still within the horizon
+        let mut env = mock_env();
+        env.block.height = mock_env().block.height.checked_add(10).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+
+        // routing any message after the horizon prunes the expired entries
+        env.block.height = env.block.height.checked_add(1).unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut 1, 1)),
+        )
+        .unwrap();
+        assert_eq!(
+            query_routed_message(deps.as_ref(), &messages[0].cc_id),
+            None
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_messages_in_cosmos_msg(polygon.gateway, messages, &res.messages[0].msg);
+    }
 }
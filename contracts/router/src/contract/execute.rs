@@ -3,25 +3,38 @@ use std::vec;
 
 use axelar_core_std::nexus;
 use axelar_wasm_std::flagset::FlagSet;
-use axelar_wasm_std::killswitch;
 use axelar_wasm_std::msg_id::{self, MessageIdFormat};
+use axelar_wasm_std::{killswitch, nonempty};
 use cosmwasm_std::{
     to_json_binary, Addr, Event, QuerierWrapper, Response, StdResult, Storage, WasmMsg,
 };
 use error_stack::{bail, ensure, report, Report, ResultExt};
 use itertools::Itertools;
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName, Gateway, GatewayDirection, Message};
+use router_api::{
+    ChainEndpoint, ChainName, Gateway, GatewayDirection, Message, QueuedMessage, RateLimit,
+    RoutePolicy, RoutingStatus,
+};
 
 use crate::events::{
-    ChainFrozen, ChainRegistered, ChainUnfrozen, GatewayInfo, GatewayUpgraded, MessageRouted,
+    ChainDeregistered, ChainFrozen, ChainRegistered, ChainUnfrozen, GatewayInfo, GatewayUpgraded,
+    MessageAlreadyRouted, MessageDropped, MessageQueued, MessageRouted, RateLimitSet,
+    RoutePolicySet, RoutedMessageHorizonSet,
 };
 use crate::state::{chain_endpoints, Config};
 use crate::{events, state};
 
+/// Maximum number of queued messages that are released automatically when a chain is unfrozen,
+/// so unfreezing a chain with a long queue doesn't run out of gas
+const MAX_RELEASED_MESSAGES_ON_UNFREEZE: u32 = 100;
+
+/// Maximum number of expired routed messages that are pruned per routing call, to bound its gas cost
+const MAX_PRUNED_ROUTED_MESSAGES: usize = 50;
+
 pub fn register_chain(
     storage: &mut dyn Storage,
     querier: QuerierWrapper,
+    block_height: u64,
     name: ChainName,
     gateway: Addr,
     msg_id_format: MessageIdFormat,
@@ -53,9 +66,44 @@ pub fn register_chain(
             msg_id_format,
         }),
     })?;
+    state::record_gateway_change(storage, &name, Some(gateway.clone()), block_height)?;
+
     Ok(Response::new().add_event(ChainRegistered { name, gateway }))
 }
 
+pub fn deregister_chain(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    chain: ChainName,
+) -> error_stack::Result<Response, Error> {
+    let endpoint =
+        state::load_chain_by_chain_name(storage, &chain)?.ok_or(report!(Error::ChainNotFound))?;
+
+    // deregistering a chain that can still send or receive messages would drop messages in flight
+    ensure!(
+        endpoint.incoming_frozen() && endpoint.outgoing_frozen(),
+        Error::ChainNotFrozen { chain }
+    );
+    ensure!(
+        state::load_queued_messages(storage, &chain, None, 1)?.is_empty(),
+        Error::ChainHasQueuedMessages { chain }
+    );
+
+    chain_endpoints()
+        .remove(storage, chain.clone())
+        .change_context(Error::StoreFailure)?;
+    state::remove_rate_limit(storage, &chain, GatewayDirection::Incoming);
+    state::remove_rate_limit(storage, &chain, GatewayDirection::Outgoing);
+    state::remove_route_policy(storage, &chain);
+    state::remove_message_queue(storage, &chain);
+    state::record_gateway_change(storage, &chain, None, block_height)?;
+
+    Ok(Response::new().add_event(ChainDeregistered {
+        name: chain,
+        gateway: endpoint.gateway.address,
+    }))
+}
+
 pub fn find_chain_for_gateway(
     storage: &dyn Storage,
     contract_address: &Addr,
@@ -68,11 +116,15 @@ pub fn find_chain_for_gateway(
 
 pub fn upgrade_gateway(
     storage: &mut dyn Storage,
+    block_height: u64,
     chain: ChainName,
     contract_address: Addr,
-) -> Result<Response, Error> {
-    if find_chain_for_gateway(storage, &contract_address)?.is_some() {
-        return Err(Error::GatewayAlreadyRegistered);
+) -> error_stack::Result<Response, Error> {
+    if find_chain_for_gateway(storage, &contract_address)
+        .change_context(Error::StoreFailure)?
+        .is_some()
+    {
+        bail!(Error::GatewayAlreadyRegistered);
     }
     chain_endpoints().update(storage, chain.clone(), |chain| match chain {
         None => Err(Error::ChainNotFound),
@@ -81,6 +133,13 @@ pub fn upgrade_gateway(
             Ok(chain)
         }
     })?;
+    state::record_gateway_change(
+        storage,
+        &chain,
+        Some(contract_address.clone()),
+        block_height,
+    )?;
+
     Ok(Response::new().add_event(GatewayUpgraded {
         gateway: GatewayInfo {
             chain,
@@ -141,17 +200,204 @@ fn unfreeze_specific_chain(
     })
 }
 
+/// Unfreezes the chains and releases up to [MAX_RELEASED_MESSAGES_ON_UNFREEZE] queued messages
+/// of each chain that can receive messages again, see [release_queued_messages].
+/// Remaining messages can be released with [flush_queued_messages].
 pub fn unfreeze_chains(
     storage: &mut dyn Storage,
+    block_height: u64,
     chains: HashMap<ChainName, GatewayDirection>,
-) -> Result<Response, Error> {
-    let events: Vec<_> = chains
+) -> error_stack::Result<Response, Error> {
+    let mut response = Response::new();
+
+    for (chain, direction) in chains {
+        response = response.add_event(unfreeze_specific_chain(storage, chain.clone(), direction)?);
+
+        let endpoint = state::load_chain_by_chain_name(storage, &chain)?
+            .ok_or(report!(Error::ChainNotFound))?;
+        if endpoint.outgoing_frozen() || !killswitch::is_contract_active(storage) {
+            continue;
+        }
+
+        let (released, dropped) = release_queued_messages(
+            storage,
+            block_height,
+            &endpoint,
+            MAX_RELEASED_MESSAGES_ON_UNFREEZE,
+        )?;
+        response = add_released_messages(response, &endpoint, released, dropped);
+    }
+
+    Ok(response)
+}
+
+pub fn flush_queued_messages(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    chain: ChainName,
+    limit: u32,
+) -> error_stack::Result<Response, Error> {
+    ensure!(
+        killswitch::is_contract_active(storage),
+        Error::RoutingDisabled
+    );
+
+    let endpoint =
+        state::load_chain_by_chain_name(storage, &chain)?.ok_or(report!(Error::ChainNotFound))?;
+    ensure!(!endpoint.outgoing_frozen(), Error::ChainFrozen { chain });
+
+    let (released, dropped) = release_queued_messages(storage, block_height, &endpoint, limit)?;
+
+    Ok(add_released_messages(
+        Response::new(),
+        &endpoint,
+        released,
+        dropped,
+    ))
+}
+
+/// Removes up to `limit` messages from the front of the destination chain's queue. Messages are released
+/// until the outgoing rate limit of the destination chain is used up, the rest stay queued.
+/// Messages whose route is no longer allowed by the route policy of their source chain are dropped,
+/// so they can't block the queue. Returns the released and the dropped messages.
+fn release_queued_messages(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    destination: &ChainEndpoint,
+    limit: u32,
+) -> error_stack::Result<(Vec<Message>, Vec<Message>), Error> {
+    let budget = state::remaining_rate_limit(
+        storage,
+        &destination.name,
+        GatewayDirection::Outgoing,
+        block_height,
+    )?
+    .map_or(usize::MAX, |remaining| remaining as usize);
+
+    let mut released = vec![];
+    let mut dropped = vec![];
+
+    for QueuedMessage { id, msg } in
+        state::load_queued_messages(storage, &destination.name, None, limit)?
+    {
+        if !is_route_allowed(
+            storage,
+            &msg.cc_id.source_chain.normalize(),
+            &msg.destination_chain,
+        )? {
+            state::remove_queued_message_record(storage, &msg.cc_id);
+            dropped.push(msg);
+        } else if released.len() < budget {
+            state::record_released_message(storage, &msg, block_height)?;
+            released.push(msg);
+        } else {
+            break;
+        }
+
+        state::remove_queued_message(storage, &destination.name, id);
+    }
+
+    enforce_rate_limits(storage, &released, GatewayDirection::Outgoing, block_height)?;
+
+    Ok((released, dropped))
+}
+
+fn add_released_messages(
+    response: Response,
+    destination: &ChainEndpoint,
+    released: Vec<Message>,
+    dropped: Vec<Message>,
+) -> Response {
+    let response = response.add_events(dropped.into_iter().map(|msg| {
+        MessageDropped {
+            reason: Error::RouteNotAllowed {
+                source_chain: msg.cc_id.source_chain.normalize(),
+                destination_chain: msg.destination_chain.clone(),
+            }
+            .to_string(),
+            msg,
+        }
+    }));
+
+    if released.is_empty() {
+        return response;
+    }
+
+    response
+        .add_message(route_to_gateway(
+            &destination.gateway.address,
+            released.clone(),
+        ))
+        .add_events(released.into_iter().map(|msg| MessageRouted { msg }))
+}
+
+fn route_to_gateway(gateway: &Addr, msgs: Vec<Message>) -> WasmMsg {
+    WasmMsg::Execute {
+        contract_addr: gateway.to_string(),
+        msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(msgs))
+            .expect("must serialize message"),
+        funds: vec![],
+    }
+}
+
+pub fn set_rate_limit(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    direction: GatewayDirection,
+    limit: Option<RateLimit>,
+) -> error_stack::Result<Response, Error> {
+    state::load_chain_by_chain_name(storage, &chain)?.ok_or(report!(Error::ChainNotFound))?;
+
+    for direction in [GatewayDirection::Incoming, GatewayDirection::Outgoing]
         .into_iter()
-        .map(|(chain, direction)| unfreeze_specific_chain(storage, chain, direction))
-        .map_ok(Event::from)
-        .try_collect()?;
+        .filter(|flag| FlagSet::from(direction).contains(*flag))
+    {
+        match limit {
+            Some(limit) => state::save_rate_limit(storage, &chain, direction, limit)?,
+            None => state::remove_rate_limit(storage, &chain, direction),
+        }
+    }
 
-    Ok(Response::new().add_events(events))
+    Ok(Response::new().add_event(RateLimitSet {
+        chain,
+        direction,
+        limit,
+    }))
+}
+
+pub fn set_route_policy(
+    storage: &mut dyn Storage,
+    source_chain: ChainName,
+    policy: RoutePolicy,
+) -> error_stack::Result<Response, Error> {
+    state::save_route_policy(storage, &source_chain, &policy)?;
+
+    Ok(Response::new().add_event(RoutePolicySet {
+        source_chain,
+        policy: Some(policy),
+    }))
+}
+
+pub fn remove_route_policy(
+    storage: &mut dyn Storage,
+    source_chain: ChainName,
+) -> error_stack::Result<Response, Error> {
+    state::remove_route_policy(storage, &source_chain);
+
+    Ok(Response::new().add_event(RoutePolicySet {
+        source_chain,
+        policy: None,
+    }))
+}
+
+pub fn set_routed_message_horizon(
+    storage: &mut dyn Storage,
+    blocks: nonempty::Uint64,
+) -> error_stack::Result<Response, Error> {
+    let blocks = u64::from(blocks);
+    state::save_routed_message_horizon(storage, blocks)?;
+
+    Ok(Response::new().add_event(RoutedMessageHorizonSet { blocks }))
 }
 
 pub fn disable_routing(storage: &mut dyn Storage) -> Result<Response, Error> {
@@ -183,6 +429,7 @@ fn validate_msgs(
     // HexTxHashAndEventIndex message ID format.
     if sender == config.axelarnet_gateway {
         verify_msg_ids(&msgs, &MessageIdFormat::HexTxHashAndEventIndex)?;
+        verify_route_policies(storage, &msgs)?;
         return Ok(msgs);
     }
 
@@ -201,13 +448,106 @@ fn validate_msgs(
     }
 
     verify_msg_ids(&msgs, &source_chain.msg_id_format)?;
+    verify_route_policies(storage, &msgs)?;
 
     Ok(msgs)
 }
 
-pub fn route_messages(
+/// Checks that the route policy of each message's source chain allows its destination chain
+fn verify_route_policies(
     storage: &dyn Storage,
+    msgs: &[Message],
+) -> error_stack::Result<(), Error> {
+    msgs.iter()
+        .map(|msg| (msg.cc_id.source_chain.normalize(), &msg.destination_chain))
+        .unique()
+        .try_for_each(|(source_chain, destination_chain)| {
+            ensure!(
+                is_route_allowed(storage, &source_chain, destination_chain)?,
+                Error::RouteNotAllowed {
+                    source_chain,
+                    destination_chain: destination_chain.clone(),
+                }
+            );
+
+            Ok(())
+        })
+}
+
+fn is_route_allowed(
+    storage: &dyn Storage,
+    source_chain: &ChainName,
+    destination_chain: &ChainName,
+) -> error_stack::Result<bool, Error> {
+    Ok(state::load_route_policy(storage, source_chain)?
+        .map_or(true, |policy| policy.allows(destination_chain)))
+}
+
+/// Records the messages with the given status if they are neither queued nor have been routed within
+/// the routed message horizon yet, and splits the messages into these new ones and the already routed ones.
+fn record_routed_msgs(
+    storage: &mut dyn Storage,
+    msgs: Vec<Message>,
+    status: RoutingStatus,
+    block_height: u64,
+    horizon_start: u64,
+) -> error_stack::Result<(Vec<Message>, Vec<Message>), Error> {
+    let mut new_msgs = vec![];
+    let mut already_routed_msgs = vec![];
+    for msg in msgs {
+        if state::record_routed_message(storage, &msg, status, block_height, horizon_start)? {
+            new_msgs.push(msg);
+        } else {
+            already_routed_msgs.push(msg);
+        }
+    }
+
+    Ok((new_msgs, already_routed_msgs))
+}
+
+/// Counts the messages against the incoming rate limits of their source chains
+/// and/or the outgoing rate limits of their destination chains, depending on `direction`
+fn enforce_rate_limits(
+    storage: &mut dyn Storage,
+    msgs: &[Message],
+    direction: GatewayDirection,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    let directions = FlagSet::from(direction);
+
+    let incoming = msgs
+        .iter()
+        .filter(|_| directions.contains(GatewayDirection::Incoming))
+        .map(|msg| {
+            (
+                msg.cc_id.source_chain.normalize(),
+                GatewayDirection::Incoming,
+            )
+        });
+    let outgoing = msgs
+        .iter()
+        .filter(|_| directions.contains(GatewayDirection::Outgoing))
+        .map(|msg| (msg.destination_chain.clone(), GatewayDirection::Outgoing));
+
+    incoming
+        .chain(outgoing)
+        .counts()
+        .into_iter()
+        .try_for_each(|((chain, direction), count)| {
+            state::record_routed_messages(
+                storage,
+                &chain,
+                direction,
+                count.try_into().unwrap_or(u32::MAX),
+                block_height,
+            )
+        })
+}
+
+pub fn route_messages(
+    storage: &mut dyn Storage,
     querier: QuerierWrapper,
+    block_height: u64,
     sender: Addr,
     msgs: Vec<Message>,
 ) -> error_stack::Result<Response, Error> {
@@ -221,44 +561,90 @@ pub fn route_messages(
 
     let msgs = validate_msgs(storage, config.clone(), &sender, msgs)?;
 
-    let wasm_msgs = msgs
-        .iter()
-        .group_by(|msg| msg.destination_chain.to_owned())
+    let horizon_start = block_height.saturating_sub(state::load_routed_message_horizon(storage)?);
+    state::prune_routed_messages(storage, horizon_start, MAX_PRUNED_ROUTED_MESSAGES)?;
+
+    let mut wasm_msgs = vec![];
+    let mut routed_msgs = vec![];
+    let mut queued_msgs = vec![];
+    let mut already_routed_msgs = vec![];
+
+    for (destination_chain, msgs) in &msgs
         .into_iter()
-        .map(|(destination_chain, msgs)| {
-            let gateway = match state::load_chain_by_chain_name(storage, &destination_chain)? {
-                Some(destination_chain) if destination_chain.outgoing_frozen() => {
-                    return Err(report!(Error::ChainFrozen {
-                        chain: destination_chain.name,
-                    }));
-                }
-                Some(destination_chain) => destination_chain.gateway.address,
-                // messages with unknown destination chains are routed to
-                // the axelarnet gateway if the sender is not the nexus gateway
-                // itself
-                None if client
-                    .is_chain_registered(&destination_chain)
-                    .change_context(Error::Nexus)? =>
-                {
-                    config.axelarnet_gateway.clone()
-                }
-                _ => return Err(report!(Error::ChainNotFound)),
-            };
-
-            Ok(WasmMsg::Execute {
-                contract_addr: gateway.to_string(),
-                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
-                    msgs.cloned().collect(),
-                ))
-                .expect("must serialize message"),
-                funds: vec![],
-            })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+        .group_by(|msg| msg.destination_chain.to_owned())
+    {
+        let msgs: Vec<_> = msgs.collect();
+
+        let gateway = match state::load_chain_by_chain_name(storage, &destination_chain)? {
+            // messages to frozen chains are kept until the chain is unfrozen
+            Some(destination_chain) if destination_chain.outgoing_frozen() => {
+                let (msgs, already_routed) = record_routed_msgs(
+                    storage,
+                    msgs,
+                    RoutingStatus::Queued,
+                    block_height,
+                    horizon_start,
+                )?;
+                queued_msgs.extend(msgs);
+                already_routed_msgs.extend(already_routed);
+                continue;
+            }
+            Some(destination_chain) => destination_chain.gateway.address,
+            // messages with unknown destination chains are routed to
+            // the axelarnet gateway if the sender is not the nexus gateway
+            // itself
+            None if client
+                .is_chain_registered(&destination_chain)
+                .change_context(Error::Nexus)? =>
+            {
+                config.axelarnet_gateway.clone()
+            }
+            _ => return Err(report!(Error::ChainNotFound)),
+        };
+
+        // already routed messages are skipped, so gateways can safely retry routing
+        let (msgs, already_routed) = record_routed_msgs(
+            storage,
+            msgs,
+            RoutingStatus::Routed,
+            block_height,
+            horizon_start,
+        )?;
+        already_routed_msgs.extend(already_routed);
+        if msgs.is_empty() {
+            continue;
+        }
+
+        wasm_msgs.push(route_to_gateway(&gateway, msgs.clone()));
+        routed_msgs.extend(msgs);
+    }
+
+    enforce_rate_limits(
+        storage,
+        &[routed_msgs.as_slice(), queued_msgs.as_slice()].concat(),
+        GatewayDirection::Incoming,
+        block_height,
+    )?;
+    // queued messages count against the outgoing rate limit when they are released
+    enforce_rate_limits(
+        storage,
+        &routed_msgs,
+        GatewayDirection::Outgoing,
+        block_height,
+    )?;
+    for msg in queued_msgs.iter() {
+        state::enqueue_message(storage, msg)?;
+    }
 
     Ok(Response::new()
         .add_messages(wasm_msgs)
-        .add_events(msgs.into_iter().map(|msg| MessageRouted { msg })))
+        .add_events(routed_msgs.into_iter().map(|msg| MessageRouted { msg }))
+        .add_events(queued_msgs.into_iter().map(|msg| MessageQueued { msg }))
+        .add_events(
+            already_routed_msgs
+                .into_iter()
+                .map(|msg| MessageAlreadyRouted { msg }),
+        ))
 }
 
 #[cfg(test)]
@@ -273,13 +659,16 @@ mod test {
     use cosmwasm_std::{QuerierWrapper, Storage};
     use rand::{random, RngCore};
     use router_api::error::Error;
-    use router_api::{ChainEndpoint, ChainName, CrossChainId, Gateway, GatewayDirection, Message};
+    use router_api::{
+        ChainEndpoint, ChainName, CrossChainId, Gateway, GatewayDirection, Message, QueuedMessage,
+    };
 
     use super::{freeze_chains, register_chain, unfreeze_chains};
     use crate::contract::execute::route_messages;
     use crate::contract::instantiate;
     use crate::events::{ChainFrozen, ChainUnfrozen};
     use crate::msg::InstantiateMsg;
+    use crate::state;
     use crate::state::chain_endpoints;
 
     const AXELARNET_GATEWAY: &str = "axelarnet_gateway";
@@ -335,8 +724,9 @@ mod test {
         .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain, destination_chain)]
         )
@@ -376,8 +766,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain.clone(), destination_chain)]
         )
@@ -419,8 +810,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message("polygon".parse().unwrap(), destination_chain)]
         )
@@ -477,10 +869,21 @@ mod test {
             )
             .unwrap();
 
-        assert!(route_messages(&deps.storage, QuerierWrapper::new(&deps.querier), sender, vec![rand_message(source_chain, destination_chain.clone())])
-            .is_err_and(move |err| {
-                matches!(err.current_context(), Error::ChainFrozen { chain } if *chain == destination_chain)
-            }));
+        let msg = rand_message(source_chain, destination_chain.clone());
+        let res = route_messages(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
+            sender,
+            vec![msg.clone()],
+        )
+        .unwrap();
+
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            state::load_queued_messages(&deps.storage, &destination_chain, None, u32::MAX).unwrap(),
+            vec![QueuedMessage { id: 0, msg }]
+        );
     }
 
     #[test]
@@ -523,8 +926,9 @@ mod test {
         msg.cc_id = CrossChainId::new(source_chain, "foobar").unwrap();
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -557,8 +961,9 @@ mod test {
         msg.cc_id = CrossChainId::new(source_chain, "foobar").unwrap();
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -617,8 +1022,9 @@ mod test {
 
         assert_err_contains!(
             route_messages(
-                &deps.storage,
+                &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -695,8 +1101,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -761,8 +1168,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -799,8 +1207,9 @@ mod test {
         .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -850,8 +1259,9 @@ mod test {
             .unwrap();
 
         assert!(route_messages(
-            &deps.storage,
+            &mut deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -919,11 +1329,13 @@ mod test {
         // unfreezing twice produces same result
         unfreeze_chains(
             deps.as_mut().storage,
+            mock_env().block.height,
             HashMap::from([(chain.clone(), GatewayDirection::Outgoing)]),
         )
         .unwrap();
         unfreeze_chains(
             deps.as_mut().storage,
+            mock_env().block.height,
             HashMap::from([(chain.clone(), GatewayDirection::Outgoing)]),
         )
         .unwrap();
@@ -936,11 +1348,13 @@ mod test {
 
         unfreeze_chains(
             deps.as_mut().storage,
+            mock_env().block.height,
             HashMap::from([(chain.clone(), GatewayDirection::Bidirectional)]),
         )
         .unwrap();
         unfreeze_chains(
             deps.as_mut().storage,
+            mock_env().block.height,
             HashMap::from([(chain.clone(), GatewayDirection::Bidirectional)]),
         )
         .unwrap();
@@ -989,6 +1403,7 @@ mod test {
 
         let res = unfreeze_chains(
             deps.as_mut().storage,
+            mock_env().block.height,
             HashMap::from([(chain.clone(), GatewayDirection::Incoming)]),
         )
         .unwrap();
@@ -1014,6 +1429,7 @@ mod test {
             register_chain(
                 &mut deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 "ethereum".parse().unwrap(),
                 MockApi::default().addr_make("gateway"),
                 MessageIdFormat::HexTxHashAndEventIndex
@@ -1023,6 +1439,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn enqueue_message_fails_if_queue_is_full() {
+        let mut deps = mock_dependencies();
+        let source_chain: ChainName = "ethereum".parse().unwrap();
+        let destination_chain: ChainName = "polygon".parse().unwrap();
+
+        for _ in 0..state::MAX_QUEUED_MESSAGES_PER_CHAIN {
+            state::enqueue_message(
+                &mut deps.storage,
+                &rand_message(source_chain.clone(), destination_chain.clone()),
+            )
+            .unwrap();
+        }
+
+        let msg = rand_message(source_chain, destination_chain.clone());
+        assert_err_contains!(
+            state::enqueue_message(&mut deps.storage, &msg),
+            Error,
+            Error::MessageQueueFull { .. }
+        );
+
+        state::remove_queued_message(&mut deps.storage, &destination_chain, 0);
+        assert!(state::enqueue_message(&mut deps.storage, &msg).is_ok());
+    }
+
     fn assert_chain_endpoint_frozen_status(
         storage: &dyn Storage,
         chain: ChainName,
@@ -1,12 +1,14 @@
 use axelar_wasm_std::flagset::FlagSet;
 use axelar_wasm_std::msg_id::MessageIdFormat;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Storage};
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
 use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
 use error_stack::{Result, ResultExt};
 use router_api::error::Error;
 use router_api::{Gateway, GatewayDirection};
 
+use crate::state;
+
 // the below types and functions are duplicated from the state module, except
 // chain names are just stored as String instead of ChainName. This is so we
 // can access chains with names that are no longer valid, and were stored
@@ -64,6 +66,30 @@ pub fn migrate(storage: &mut dyn Storage, chains_to_remove: Vec<String>) -> Resu
     }
     Ok(())
 }
+
+/// Chains registered before gateway changes were recorded have no gateway history.
+/// Their current gateway is recorded as active since the migration.
+pub fn seed_gateway_history(storage: &mut dyn Storage, block_height: u64) -> Result<(), Error> {
+    let chains: Vec<_> = state::chain_endpoints()
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, endpoint)| endpoint))
+        .collect::<StdResult<_>>()
+        .change_context(Error::StoreFailure)?;
+
+    for endpoint in chains {
+        if state::load_gateway_history(storage, &endpoint.name)?.is_empty() {
+            state::record_gateway_change(
+                storage,
+                &endpoint.name,
+                Some(endpoint.gateway.address),
+                block_height,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     #![allow(deprecated)]
@@ -72,9 +98,9 @@ mod test {
     use axelar_wasm_std::msg_id::MessageIdFormat;
     use cosmwasm_std::testing::{mock_dependencies, MockApi};
     use itertools::Itertools;
-    use router_api::{ChainName, Gateway, GatewayDirection};
+    use router_api::{ChainName, Gateway, GatewayDirection, GatewayHistoryEntry};
 
-    use super::{chain_endpoints_old, migrate, ChainEndpoint};
+    use super::{chain_endpoints_old, migrate, seed_gateway_history, ChainEndpoint};
     use crate::state::{self, chain_endpoints};
 
     #[test]
@@ -134,4 +160,57 @@ mod test {
 
         assert_eq!(chains, vec![good_chain_names[1].clone()]);
     }
+
+    #[test]
+    fn seed_gateway_history_records_current_gateways_of_chains_without_history() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+
+        for name in ["ethereum", "avalanche"] {
+            let chain_name = ChainName::try_from(name).unwrap();
+            state::chain_endpoints()
+                .save(
+                    deps.as_mut().storage,
+                    chain_name.clone(),
+                    &router_api::ChainEndpoint {
+                        name: chain_name,
+                        gateway: Gateway {
+                            address: api.addr_make(name),
+                        },
+                        frozen_status: GatewayDirection::None.into(),
+                        msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
+                    },
+                )
+                .unwrap();
+        }
+
+        let ethereum = ChainName::try_from("ethereum").unwrap();
+        let avalanche = ChainName::try_from("avalanche").unwrap();
+        state::record_gateway_change(
+            deps.as_mut().storage,
+            &ethereum,
+            Some(api.addr_make("ethereum")),
+            10,
+        )
+        .unwrap();
+
+        assert_ok!(seed_gateway_history(deps.as_mut().storage, 100));
+
+        assert_eq!(
+            state::load_gateway_history(&deps.storage, &ethereum).unwrap(),
+            vec![GatewayHistoryEntry {
+                address: api.addr_make("ethereum"),
+                active_from: 10,
+                active_until: None,
+            }]
+        );
+        assert_eq!(
+            state::load_gateway_history(&deps.storage, &avalanche).unwrap(),
+            vec![GatewayHistoryEntry {
+                address: api.addr_make("avalanche"),
+                active_from: 100,
+                active_until: None,
+            }]
+        );
+    }
 }
@@ -2,12 +2,18 @@ use cosmwasm_std::{Order, Storage};
 use cw_storage_plus::Bound;
 use error_stack::{Result, ResultExt};
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName};
+use router_api::{
+    ChainEndpoint, ChainName, ChainRateLimits, ChainRoutePolicy, CrossChainId, GatewayDirection,
+    GatewayHistoryEntry, QueuedMessage, RoutePolicy, RoutedMessage,
+};
 
+use crate::state;
 use crate::state::chain_endpoints;
 
 // Pagination limits
 const DEFAULT_LIMIT: u32 = u32::MAX;
+const DEFAULT_PAGE_LIMIT: u32 = 100;
+const MAX_PAGE_LIMIT: u32 = 1000;
 
 pub fn chain_info(storage: &dyn Storage, chain: ChainName) -> Result<ChainEndpoint, Error> {
     chain_endpoints()
@@ -34,6 +40,70 @@ pub fn chains(
         .collect()
 }
 
+pub fn gateway_history(
+    storage: &dyn Storage,
+    chain: ChainName,
+) -> Result<Vec<GatewayHistoryEntry>, Error> {
+    state::load_gateway_history(storage, &chain)
+}
+
+pub fn rate_limits(
+    storage: &dyn Storage,
+    chain: ChainName,
+    block_height: u64,
+) -> Result<ChainRateLimits, Error> {
+    Ok(ChainRateLimits {
+        incoming: state::load_rate_limit(
+            storage,
+            &chain,
+            GatewayDirection::Incoming,
+            block_height,
+        )?,
+        outgoing: state::load_rate_limit(
+            storage,
+            &chain,
+            GatewayDirection::Outgoing,
+            block_height,
+        )?,
+    })
+}
+
+pub fn route_policy(
+    storage: &dyn Storage,
+    source_chain: ChainName,
+) -> Result<Option<RoutePolicy>, Error> {
+    state::load_route_policy(storage, &source_chain)
+}
+
+pub fn route_policies(
+    storage: &dyn Storage,
+    start_after: Option<ChainName>,
+    limit: Option<u32>,
+) -> Result<Vec<ChainRoutePolicy>, Error> {
+    state::load_route_policies(storage, start_after, page_limit(limit))
+}
+
+pub fn routed_message(
+    storage: &dyn Storage,
+    cc_id: CrossChainId,
+) -> Result<Option<RoutedMessage>, Error> {
+    state::may_load_routed_message(storage, &cc_id)
+}
+
+pub fn queued_messages(
+    storage: &dyn Storage,
+    chain: ChainName,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<QueuedMessage>, Error> {
+    state::load_queued_messages(storage, &chain, start_after, page_limit(limit))
+}
+
+/// Limits the page size of queries over collections that can grow large
+fn page_limit(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT)
+}
+
 #[cfg(test)]
 mod test {
     use axelar_wasm_std::flagset::FlagSet;
@@ -44,6 +114,13 @@ mod test {
     use super::chain_info;
     use crate::state::chain_endpoints;
 
+    #[test]
+    fn page_limit_has_default_and_maximum() {
+        assert_eq!(super::page_limit(None), super::DEFAULT_PAGE_LIMIT);
+        assert_eq!(super::page_limit(Some(5)), 5);
+        assert_eq!(super::page_limit(Some(u32::MAX)), super::MAX_PAGE_LIMIT);
+    }
+
     #[test]
     fn should_get_chain_info() {
         let mut deps = mock_dependencies();
@@ -1,5 +1,5 @@
 use cosmwasm_std::{Addr, Attribute, Event};
-use router_api::{ChainName, GatewayDirection, Message};
+use router_api::{ChainName, GatewayDirection, Message, RateLimit, RoutePolicy};
 
 pub struct RouterInstantiated {
     pub admin: Addr,
@@ -21,6 +21,11 @@ pub struct GatewayUpgraded {
     pub gateway: GatewayInfo,
 }
 
+pub struct ChainDeregistered {
+    pub name: ChainName,
+    pub gateway: Addr,
+}
+
 pub struct ChainFrozen {
     pub name: ChainName,
     pub direction: GatewayDirection,
@@ -31,10 +36,38 @@ pub struct ChainUnfrozen {
     pub direction: GatewayDirection,
 }
 
+pub struct RateLimitSet {
+    pub chain: ChainName,
+    pub direction: GatewayDirection,
+    pub limit: Option<RateLimit>,
+}
+
+pub struct RoutePolicySet {
+    pub source_chain: ChainName,
+    pub policy: Option<RoutePolicy>,
+}
+
+pub struct RoutedMessageHorizonSet {
+    pub blocks: u64,
+}
+
+pub struct MessageQueued {
+    pub msg: Message,
+}
+
 pub struct MessageRouted {
     pub msg: Message,
 }
 
+pub struct MessageDropped {
+    pub msg: Message,
+    pub reason: String,
+}
+
+pub struct MessageAlreadyRouted {
+    pub msg: Message,
+}
+
 pub struct RoutingDisabled;
 pub struct RoutingEnabled;
 
@@ -82,6 +115,14 @@ impl From<RoutingEnabled> for Event {
     }
 }
 
+impl From<ChainDeregistered> for Event {
+    fn from(other: ChainDeregistered) -> Self {
+        Event::new("chain_deregistered")
+            .add_attribute("name", other.name)
+            .add_attribute("gateway", other.gateway)
+    }
+}
+
 impl From<ChainFrozen> for Event {
     fn from(other: ChainFrozen) -> Self {
         Event::new("chain_frozen")
@@ -111,3 +152,61 @@ impl From<MessageRouted> for Event {
         Event::new("message_routed").add_attributes(attrs)
     }
 }
+
+impl From<RateLimitSet> for Event {
+    fn from(other: RateLimitSet) -> Self {
+        Event::new("rate_limit_set")
+            .add_attribute("chain", other.chain)
+            .add_attribute(
+                "direction",
+                serde_json::to_string(&other.direction).expect("failed to serialize direction"),
+            )
+            .add_attribute(
+                "limit",
+                serde_json::to_string(&other.limit).expect("failed to serialize rate limit"),
+            )
+    }
+}
+
+impl From<RoutePolicySet> for Event {
+    fn from(other: RoutePolicySet) -> Self {
+        Event::new("route_policy_set")
+            .add_attribute("source_chain", other.source_chain)
+            .add_attribute(
+                "policy",
+                serde_json::to_string(&other.policy).expect("failed to serialize route policy"),
+            )
+    }
+}
+
+impl From<RoutedMessageHorizonSet> for Event {
+    fn from(other: RoutedMessageHorizonSet) -> Self {
+        Event::new("routed_message_horizon_set").add_attribute("blocks", other.blocks.to_string())
+    }
+}
+
+impl From<MessageDropped> for Event {
+    fn from(other: MessageDropped) -> Self {
+        let attrs: Vec<Attribute> = other.msg.into();
+
+        Event::new("message_dropped")
+            .add_attributes(attrs)
+            .add_attribute("reason", other.reason)
+    }
+}
+
+impl From<MessageAlreadyRouted> for Event {
+    fn from(other: MessageAlreadyRouted) -> Self {
+        let attrs: Vec<Attribute> = other.msg.into();
+
+        Event::new("message_already_routed").add_attributes(attrs)
+    }
+}
+
+impl From<MessageQueued> for Event {
+    fn from(other: MessageQueued) -> Self {
+        let attrs: Vec<Attribute> = other.msg.into();
+
+        Event::new("message_queued").add_attributes(attrs)
+    }
+}
@@ -1,9 +1,13 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Order, StdResult, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
-use error_stack::{report, ResultExt};
+use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use error_stack::{ensure, report, ResultExt};
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName};
+use router_api::{
+    ChainEndpoint, ChainName, ChainRoutePolicy, CrossChainId, GatewayDirection,
+    GatewayHistoryEntry, Message, QueuedMessage, RateLimit, RateLimitStatus, RoutePolicy,
+    RoutedMessage, RoutingStatus,
+};
 
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> error_stack::Result<(), Error> {
     CONFIG
@@ -35,6 +39,376 @@ pub fn load_chain_by_gateway(
         .ok_or(report!(Error::GatewayNotRegistered))
 }
 
+pub fn save_rate_limit(
+    storage: &mut dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+    limit: RateLimit,
+) -> error_stack::Result<(), Error> {
+    RATE_LIMITS
+        .update(storage, (chain.clone(), direction as u8), |status| {
+            Ok::<_, StdError>(match status {
+                // keep the usage of the current window, so changing the limit doesn't reset it
+                Some(status) => RateLimitStatus { limit, ..status },
+                None => RateLimitStatus {
+                    limit,
+                    window_start: 0,
+                    routed_messages: 0,
+                },
+            })
+        })
+        .change_context(Error::StoreFailure)?;
+
+    Ok(())
+}
+
+pub fn remove_rate_limit(
+    storage: &mut dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+) {
+    RATE_LIMITS.remove(storage, (chain.clone(), direction as u8))
+}
+
+/// Loads the rate limit of the chain in the given direction, with the usage of the window the block height belongs to
+pub fn load_rate_limit(
+    storage: &dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+    block_height: u64,
+) -> error_stack::Result<Option<RateLimitStatus>, Error> {
+    RATE_LIMITS
+        .may_load(storage, (chain.clone(), direction as u8))
+        .change_context(Error::StoreFailure)
+        .map(|status| status.map(|status| in_window_of(status, block_height)))
+}
+
+/// Counts the routed messages against the rate limit of the chain in the given direction, if there is one.
+/// Fails if the limit of the current window is exceeded.
+pub fn record_routed_messages(
+    storage: &mut dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+    count: u32,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    let Some(mut status) = load_rate_limit(storage, chain, direction, block_height)? else {
+        return Ok(());
+    };
+
+    status.routed_messages = status
+        .routed_messages
+        .checked_add(count)
+        .filter(|routed_messages| *routed_messages <= status.limit.max_messages)
+        .ok_or(report!(Error::RateLimitExceeded {
+            chain: chain.clone()
+        }))?;
+
+    RATE_LIMITS
+        .save(storage, (chain.clone(), direction as u8), &status)
+        .change_context(Error::StoreFailure)
+}
+
+/// Returns how many more messages can be counted against the rate limit of the chain in the given direction
+/// in the window the block height belongs to, or None if there is no rate limit
+pub fn remaining_rate_limit(
+    storage: &dyn Storage,
+    chain: &ChainName,
+    direction: GatewayDirection,
+    block_height: u64,
+) -> error_stack::Result<Option<u32>, Error> {
+    Ok(
+        load_rate_limit(storage, chain, direction, block_height)?.map(|status| {
+            status
+                .limit
+                .max_messages
+                .saturating_sub(status.routed_messages)
+        }),
+    )
+}
+
+/// Resets the usage if the block height belongs to a later window than the recorded one
+fn in_window_of(status: RateLimitStatus, block_height: u64) -> RateLimitStatus {
+    let window = u64::from(status.limit.window);
+    let window_start = block_height.saturating_sub(block_height.checked_rem(window).unwrap_or(0));
+
+    if window_start == status.window_start {
+        status
+    } else {
+        RateLimitStatus {
+            window_start,
+            routed_messages: 0,
+            ..status
+        }
+    }
+}
+
+/// Appends the message to the queue of its destination chain, unless the queue already holds
+/// [MAX_QUEUED_MESSAGES_PER_CHAIN] messages
+pub fn enqueue_message(storage: &mut dyn Storage, msg: &Message) -> error_stack::Result<(), Error> {
+    let chain = msg.destination_chain.clone();
+    let id = QUEUE_NEXT_ID
+        .may_load(storage, chain.clone())
+        .change_context(Error::StoreFailure)?
+        .unwrap_or_default();
+
+    // queue ids are consecutive and messages are only removed from the front of the queue
+    let queue_len = match load_queued_messages(storage, &chain, None, 1)?.first() {
+        Some(QueuedMessage { id: first_id, .. }) => id.saturating_sub(*first_id),
+        None => 0,
+    };
+    ensure!(
+        queue_len < MAX_QUEUED_MESSAGES_PER_CHAIN,
+        Error::MessageQueueFull { chain }
+    );
+
+    QUEUED_MESSAGES
+        .save(storage, (chain.clone(), id), msg)
+        .change_context(Error::StoreFailure)?;
+    QUEUE_NEXT_ID
+        .save(
+            storage,
+            chain,
+            &id.checked_add(1).expect("queue id must not overflow"),
+        )
+        .change_context(Error::StoreFailure)
+}
+
+/// Removes the message from the chain's queue. Only messages at the front of the queue may be removed,
+/// so the queue length can be derived from the ids.
+pub fn remove_queued_message(storage: &mut dyn Storage, chain: &ChainName, id: u64) {
+    QUEUED_MESSAGES.remove(storage, (chain.clone(), id))
+}
+
+pub fn load_queued_messages(
+    storage: &dyn Storage,
+    chain: &ChainName,
+    start_after: Option<u64>,
+    limit: u32,
+) -> error_stack::Result<Vec<QueuedMessage>, Error> {
+    QUEUED_MESSAGES
+        .prefix(chain.clone())
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|item| {
+            item.map(|(id, msg)| QueuedMessage { id, msg })
+                .change_context(Error::StoreFailure)
+        })
+        .collect()
+}
+
+pub fn save_routed_message_horizon(
+    storage: &mut dyn Storage,
+    blocks: u64,
+) -> error_stack::Result<(), Error> {
+    ROUTED_MESSAGE_HORIZON
+        .save(storage, &blocks)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn load_routed_message_horizon(storage: &dyn Storage) -> error_stack::Result<u64, Error> {
+    ROUTED_MESSAGE_HORIZON
+        .may_load(storage)
+        .change_context(Error::StoreFailure)
+        .map(|horizon| horizon.unwrap_or(DEFAULT_ROUTED_MESSAGE_HORIZON))
+}
+
+/// Records that the message has been queued or routed. Returns false without recording anything if the message
+/// is still queued or has already been routed at or after the start of the horizon.
+pub fn record_routed_message(
+    storage: &mut dyn Storage,
+    msg: &Message,
+    status: RoutingStatus,
+    block_height: u64,
+    horizon_start: u64,
+) -> error_stack::Result<bool, Error> {
+    match may_load_routed_message(storage, &msg.cc_id)? {
+        Some(RoutedMessage {
+            status: RoutingStatus::Queued,
+            ..
+        }) => return Ok(false),
+        Some(routed_msg) if routed_msg.block_height >= horizon_start => return Ok(false),
+        // expired entries might not have been pruned yet
+        Some(routed_msg) => {
+            ROUTED_MESSAGES_BY_HEIGHT.remove(storage, (routed_msg.block_height, msg.cc_id.clone()))
+        }
+        None => (),
+    }
+
+    save_routed_message(storage, msg, status, block_height)?;
+
+    Ok(true)
+}
+
+/// Records that a queued message has been released from the queue and routed
+pub fn record_released_message(
+    storage: &mut dyn Storage,
+    msg: &Message,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    save_routed_message(storage, msg, RoutingStatus::Routed, block_height)
+}
+
+/// Forgets a queued message that was dropped instead of routed
+pub fn remove_queued_message_record(storage: &mut dyn Storage, cc_id: &CrossChainId) {
+    ROUTED_MESSAGES.remove(storage, cc_id)
+}
+
+/// Queued messages are only indexed by block height once they are routed, so they don't expire while queued
+fn save_routed_message(
+    storage: &mut dyn Storage,
+    msg: &Message,
+    status: RoutingStatus,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    ROUTED_MESSAGES
+        .save(
+            storage,
+            &msg.cc_id,
+            &RoutedMessage {
+                destination_chain: msg.destination_chain.clone(),
+                block_height,
+                status,
+            },
+        )
+        .change_context(Error::StoreFailure)?;
+
+    if status == RoutingStatus::Routed {
+        ROUTED_MESSAGES_BY_HEIGHT
+            .save(storage, (block_height, msg.cc_id.clone()), &())
+            .change_context(Error::StoreFailure)?;
+    }
+
+    Ok(())
+}
+
+pub fn may_load_routed_message(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<Option<RoutedMessage>, Error> {
+    ROUTED_MESSAGES
+        .may_load(storage, cc_id)
+        .change_context(Error::StoreFailure)
+}
+
+/// Removes up to `limit` of the oldest routed messages that were routed before the start of the horizon
+pub fn prune_routed_messages(
+    storage: &mut dyn Storage,
+    horizon_start: u64,
+    limit: usize,
+) -> error_stack::Result<(), Error> {
+    let expired: Vec<_> = ROUTED_MESSAGES_BY_HEIGHT
+        .keys(storage, None, None, Order::Ascending)
+        .take(limit)
+        .take_while(|key| {
+            key.as_ref()
+                .map_or(true, |(block_height, _)| *block_height < horizon_start)
+        })
+        .collect::<StdResult<_>>()
+        .change_context(Error::StoreFailure)?;
+
+    for (block_height, cc_id) in expired {
+        ROUTED_MESSAGES.remove(storage, &cc_id);
+        ROUTED_MESSAGES_BY_HEIGHT.remove(storage, (block_height, cc_id));
+    }
+
+    Ok(())
+}
+
+/// Marks the currently active gateway of the chain as inactive from the given block height on,
+/// and records the new gateway if there is one
+pub fn record_gateway_change(
+    storage: &mut dyn Storage,
+    chain: &ChainName,
+    new_gateway: Option<Addr>,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    let mut history = load_gateway_history(storage, chain)?;
+
+    if let Some(active) = history
+        .last_mut()
+        .filter(|entry| entry.active_until.is_none())
+    {
+        active.active_until = Some(block_height);
+    }
+
+    history.extend(new_gateway.map(|address| GatewayHistoryEntry {
+        address,
+        active_from: block_height,
+        active_until: None,
+    }));
+
+    GATEWAY_HISTORY
+        .save(storage, chain.clone(), &history)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn load_gateway_history(
+    storage: &dyn Storage,
+    chain: &ChainName,
+) -> error_stack::Result<Vec<GatewayHistoryEntry>, Error> {
+    GATEWAY_HISTORY
+        .may_load(storage, chain.clone())
+        .change_context(Error::StoreFailure)
+        .map(Option::unwrap_or_default)
+}
+
+/// Removes the queue bookkeeping of the chain. Must only be called once the queue is empty.
+pub fn remove_message_queue(storage: &mut dyn Storage, chain: &ChainName) {
+    QUEUE_NEXT_ID.remove(storage, chain.clone())
+}
+
+pub fn save_route_policy(
+    storage: &mut dyn Storage,
+    source_chain: &ChainName,
+    policy: &RoutePolicy,
+) -> error_stack::Result<(), Error> {
+    ROUTE_POLICIES
+        .save(storage, source_chain.clone(), policy)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn remove_route_policy(storage: &mut dyn Storage, source_chain: &ChainName) {
+    ROUTE_POLICIES.remove(storage, source_chain.clone())
+}
+
+pub fn load_route_policy(
+    storage: &dyn Storage,
+    source_chain: &ChainName,
+) -> error_stack::Result<Option<RoutePolicy>, Error> {
+    ROUTE_POLICIES
+        .may_load(storage, source_chain.clone())
+        .change_context(Error::StoreFailure)
+}
+
+pub fn load_route_policies(
+    storage: &dyn Storage,
+    start_after: Option<ChainName>,
+    limit: u32,
+) -> error_stack::Result<Vec<ChainRoutePolicy>, Error> {
+    ROUTE_POLICIES
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|item| {
+            item.map(|(source_chain, policy)| ChainRoutePolicy {
+                source_chain,
+                policy,
+            })
+            .change_context(Error::StoreFailure)
+        })
+        .collect()
+}
+
 #[cw_serde]
 pub struct Config {
     pub axelarnet_gateway: Addr,
@@ -42,6 +416,31 @@ pub struct Config {
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Rate limits by chain and direction (either incoming or outgoing)
+const RATE_LIMITS: Map<(ChainName, u8), RateLimitStatus> = Map::new("rate_limits");
+
+/// Number of blocks for which routed messages are remembered, if not set by governance (about a week)
+const DEFAULT_ROUTED_MESSAGE_HORIZON: u64 = 100_800;
+const ROUTED_MESSAGE_HORIZON: Item<u64> = Item::new("routed_message_horizon");
+
+/// Messages that have been routed within the horizon, and the same keys ordered by block height for pruning
+const ROUTED_MESSAGES: Map<&CrossChainId, RoutedMessage> = Map::new("routed_messages");
+const ROUTED_MESSAGES_BY_HEIGHT: Map<(u64, CrossChainId), ()> =
+    Map::new("routed_messages_by_height");
+
+/// Gateways that have been registered for a chain, in registration order
+const GATEWAY_HISTORY: Map<ChainName, Vec<GatewayHistoryEntry>> = Map::new("gateway_history");
+
+/// Allowed or denied destination chains by source chain
+const ROUTE_POLICIES: Map<ChainName, RoutePolicy> = Map::new("route_policies");
+
+/// Maximum number of messages that can be queued for a single frozen chain
+pub const MAX_QUEUED_MESSAGES_PER_CHAIN: u64 = 10_000;
+
+/// Messages to frozen chains by destination chain and queue position
+const QUEUED_MESSAGES: Map<(ChainName, u64), Message> = Map::new("queued_messages");
+const QUEUE_NEXT_ID: Map<ChainName, u64> = Map::new("queue_next_id");
+
 pub struct ChainEndpointIndexes<'a> {
     pub gateway: GatewayIndex<'a>,
 }
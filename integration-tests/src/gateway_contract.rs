@@ -16,6 +16,7 @@ impl GatewayContract {
         app: &mut AxelarApp,
         router_address: Addr,
         verifier_address: Addr,
+        governance_address: Addr,
     ) -> Self {
         let code = ContractWrapper::new_with_empty(execute, instantiate, query);
         let code_id = app.store_code(Box::new(code));
@@ -27,6 +28,9 @@ impl GatewayContract {
                 &gateway::msg::InstantiateMsg {
                     router_address: router_address.to_string(),
                     verifier_address: verifier_address.to_string(),
+                    governance_address: governance_address.to_string(),
+                    message_expiry: None,
+                    axelar_chain_name: None,
                 },
                 &[],
                 "gateway",
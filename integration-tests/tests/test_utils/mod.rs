@@ -701,6 +701,7 @@ pub fn setup_chain(protocol: &mut Protocol, chain_name: ChainName) -> Chain {
         &mut protocol.app,
         protocol.router.contract_address().clone(),
         voting_verifier.contract_addr.clone(),
+        protocol.governance_address.clone(),
     );
 
     let multisig_prover_admin =
@@ -1,9 +1,9 @@
 use axelar_wasm_std::vec::VecExt;
 use cosmwasm_std::CosmosMsg;
 use error_stack::ResultExt;
-use router_api::{CrossChainId, Message};
+use router_api::{Address, CrossChainId, Message};
 
-use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, IncomingMessageStatus, OutgoingMessageStatus, QueryMsg};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -11,12 +11,45 @@ type Result<T> = error_stack::Result<T, Error>;
 pub enum Error {
     #[error("failed to query gateway for outgoing messages. message ids: {0:?}")]
     OutgoingMessages(Vec<CrossChainId>),
+    #[error("failed to query gateway for outgoing message statuses. message ids: {0:?}")]
+    OutgoingMessageStatuses(Vec<CrossChainId>),
+    #[error("failed to query gateway for outgoing messages to {destination_address}. start after: {start_after:?}, limit: {limit:?}")]
+    OutgoingMessagesByDestination {
+        destination_address: Address,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for all outgoing messages. start after: {start_after:?}, limit: {limit:?}")]
+    AllOutgoingMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for incoming message statuses. message ids: {0:?}")]
+    IncomingMessageStatuses(Vec<CrossChainId>),
 }
 
 impl From<QueryMsg> for Error {
     fn from(value: QueryMsg) -> Self {
         match value {
             QueryMsg::OutgoingMessages(message_ids) => Error::OutgoingMessages(message_ids),
+            QueryMsg::OutgoingMessageStatuses(message_ids) => {
+                Error::OutgoingMessageStatuses(message_ids)
+            }
+            QueryMsg::OutgoingMessagesByDestination {
+                destination_address,
+                start_after,
+                limit,
+            } => Error::OutgoingMessagesByDestination {
+                destination_address,
+                start_after,
+                limit,
+            },
+            QueryMsg::AllOutgoingMessages { start_after, limit } => {
+                Error::AllOutgoingMessages { start_after, limit }
+            }
+            QueryMsg::IncomingMessageStatuses(messages) => {
+                Error::IncomingMessageStatuses(messages.into_iter().map(|msg| msg.cc_id).collect())
+            }
         }
     }
 }
@@ -37,6 +70,45 @@ impl<'a> Client<'a> {
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
 
+    pub fn outgoing_message_statuses(
+        &self,
+        message_ids: Vec<CrossChainId>,
+    ) -> Result<Vec<OutgoingMessageStatus>> {
+        let msg = QueryMsg::OutgoingMessageStatuses(message_ids);
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn outgoing_messages_by_destination(
+        &self,
+        destination_address: Address,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Message>> {
+        let msg = QueryMsg::OutgoingMessagesByDestination {
+            destination_address,
+            start_after,
+            limit,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn all_outgoing_messages(
+        &self,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Message>> {
+        let msg = QueryMsg::AllOutgoingMessages { start_after, limit };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn incoming_message_statuses(
+        &self,
+        messages: Vec<Message>,
+    ) -> Result<Vec<IncomingMessageStatus>> {
+        let msg = QueryMsg::IncomingMessageStatuses(messages);
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
     pub fn verify_messages(&self, messages: Vec<Message>) -> Option<CosmosMsg> {
         messages
             .to_none_if_empty()
@@ -57,7 +129,7 @@ mod tests {
     use router_api::{CrossChainId, Message};
 
     use crate::client::Client;
-    use crate::msg::QueryMsg;
+    use crate::msg::{OutgoingMessageStatus, QueryMsg};
 
     #[test]
     fn query_outgoing_messages_should_return_error_when_query_errors() {
@@ -93,6 +165,25 @@ mod tests {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_outgoing_message_statuses_should_return_statuses() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let cc_id = CrossChainId {
+            source_chain: "ethereum".parse().unwrap(),
+            message_id: "0x13548ac28fe95805ad2b8b824472d08e3b45cbc023a5a45a912f11ea98f81e97-0"
+                .parse()
+                .unwrap(),
+        };
+        let res = client.outgoing_message_statuses(vec![cc_id]).unwrap();
+        assert_eq!(
+            res,
+            vec![OutgoingMessageStatus::Pending { expires_at: None }]
+        );
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let addr = "gateway";
 
@@ -134,6 +225,15 @@ mod tests {
                     )
                     .into())
                     .into(),
+                    QueryMsg::OutgoingMessageStatuses(cc_ids) => Ok(to_json_binary(
+                        &cc_ids
+                            .into_iter()
+                            .map(|_| OutgoingMessageStatus::Pending { expires_at: None })
+                            .collect::<Vec<_>>(),
+                    )
+                    .into())
+                    .into(),
+                    msg => panic!("unexpected query: {:?}", msg),
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
@@ -1,6 +1,7 @@
+use axelar_wasm_std::{nonempty, VerificationStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::EnsurePermissions;
-use router_api::{CrossChainId, Message};
+use router_api::{Address, CrossChainId, Message};
 
 #[cw_serde]
 #[derive(EnsurePermissions)]
@@ -14,6 +15,16 @@ pub enum ExecuteMsg {
     /// they have to be verified first.
     #[permission(Any)]
     RouteMessages(Vec<Message>),
+
+    /// Cancels outgoing messages that have not been delivered, so no more proofs are constructed for them.
+    /// Can be called by governance, or by the source address of the messages if it is an address on Axelar.
+    #[permission(Any)]
+    CancelMessages(Vec<CrossChainId>),
+
+    /// Sets the number of blocks after which newly routed outgoing messages expire.
+    /// None disables expiry for messages routed from now on.
+    #[permission(Governance)]
+    SetMessageExpiry { blocks: Option<nonempty::Uint64> },
 }
 
 #[cw_serde]
@@ -22,4 +33,47 @@ pub enum QueryMsg {
     // messages that can be relayed to the chain corresponding to this gateway
     #[returns(Vec<Message>)]
     OutgoingMessages(Vec<CrossChainId>),
+
+    // whether the outgoing messages can still be relayed, in the same order as the given IDs
+    #[returns(Vec<OutgoingMessageStatus>)]
+    OutgoingMessageStatuses(Vec<CrossChainId>),
+
+    // outgoing messages to the given destination address, in the order they were routed to this gateway.
+    // The list is paginated by the ID of the last message of the previous page, the default limit is 100 and the maximum 1000.
+    #[returns(Vec<Message>)]
+    OutgoingMessagesByDestination {
+        destination_address: Address,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
+    // all outgoing messages, in the order they were routed to this gateway.
+    // The list is paginated by the ID of the last message of the previous page, the default limit is 100 and the maximum 1000.
+    #[returns(Vec<Message>)]
+    AllOutgoingMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
+    // verification status of the given incoming messages and whether this gateway has routed them to the router
+    #[returns(Vec<IncomingMessageStatus>)]
+    IncomingMessageStatuses(Vec<Message>),
+}
+
+#[cw_serde]
+pub enum OutgoingMessageStatus {
+    /// the message can be relayed, until the block height it expires at (if any)
+    Pending {
+        expires_at: Option<u64>,
+    },
+    Expired,
+    Cancelled,
+}
+
+#[cw_serde]
+pub struct IncomingMessageStatus {
+    pub message: Message,
+    pub verification_status: VerificationStatus,
+    /// None if the message might have been routed before the gateway started to keep track of routed messages
+    pub routed: Option<bool>,
 }
@@ -38,6 +38,24 @@ pub enum Error {
     #[error("chain is frozen")]
     ChainFrozen { chain: ChainName },
 
+    #[error("chain {chain} must be frozen in both directions")]
+    ChainNotFrozen { chain: ChainName },
+
+    #[error("chain {chain} has queued messages")]
+    ChainHasQueuedMessages { chain: ChainName },
+
+    #[error("message queue of chain {chain} is full")]
+    MessageQueueFull { chain: ChainName },
+
+    #[error("rate limit of chain {chain} exceeded")]
+    RateLimitExceeded { chain: ChainName },
+
+    #[error("routing messages from {source_chain} to {destination_chain} is not allowed")]
+    RouteNotAllowed {
+        source_chain: ChainName,
+        destination_chain: ChainName,
+    },
+
     #[error("address is invalid")]
     InvalidAddress,
 
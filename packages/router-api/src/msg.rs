@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use axelar_wasm_std::msg_id::MessageIdFormat;
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::EnsurePermissions;
 
@@ -22,17 +23,54 @@ pub enum ExecuteMsg {
         chain: ChainName,
         contract_address: Address,
     },
+    /// Removes a chain from the router. The chain must be frozen in both directions
+    /// and must not have any queued messages left. Its gateway history is kept.
+    #[permission(Governance)]
+    DeregisterChain { chain: ChainName },
     /// Freezes the specified chains in the specified directions.
     #[permission(Elevated)]
     FreezeChains {
         chains: HashMap<ChainName, GatewayDirection>,
     },
     /// Unfreezes the specified chains in the specified directions.
+    /// Chains that can receive messages again release a batch of their queued messages, like `FlushQueuedMessages`.
     #[permission(Elevated)]
     UnfreezeChains {
         chains: HashMap<ChainName, GatewayDirection>,
     },
 
+    /// Limits the number of messages that can be routed from (incoming) or to (outgoing) a chain per window of blocks.
+    /// Setting the limit to None removes the rate limit in the specified directions.
+    #[permission(Governance)]
+    SetRateLimit {
+        chain: ChainName,
+        direction: GatewayDirection,
+        limit: Option<RateLimit>,
+    },
+
+    /// Restricts the destination chains that messages from the source chain can be routed to.
+    /// Replaces any existing policy of the source chain.
+    #[permission(Governance)]
+    SetRoutePolicy {
+        source_chain: ChainName,
+        policy: RoutePolicy,
+    },
+    /// Removes the route policy of the source chain, so its messages can be routed to any chain again
+    #[permission(Governance)]
+    RemoveRoutePolicy { source_chain: ChainName },
+
+    /// Sets for how many blocks routed messages are remembered to reject duplicates.
+    /// Older entries are pruned while routing new messages.
+    #[permission(Governance)]
+    SetRoutedMessageHorizon { blocks: nonempty::Uint64 },
+
+    /// Routes up to `limit` of the messages that were queued while the chain was frozen, as long as the
+    /// outgoing rate limit of the chain allows. Queued messages whose route is no longer allowed by
+    /// the route policy of their source chain are dropped.
+    /// Fails if the chain is still frozen in the outgoing direction.
+    #[permission(Any)]
+    FlushQueuedMessages { chain: ChainName, limit: u32 },
+
     /// Emergency command to stop all amplifier routing.
     #[permission(Elevated)]
     DisableRouting,
@@ -42,7 +80,7 @@ pub enum ExecuteMsg {
     EnableRouting,
 
     /// Routes a message to all outgoing gateways registered to the destination domain.
-    /// Called by an incoming gateway
+    /// Messages that have already been routed are skipped. Called by an incoming gateway
     #[permission(Specific(gateway))]
     RouteMessages(Vec<Message>),
 }
@@ -64,4 +102,39 @@ pub enum QueryMsg {
     },
     #[returns(bool)]
     IsEnabled,
+
+    /// Returns the gateways of a chain in the order they were registered, including
+    /// the gateways of a deregistered chain
+    #[returns(Vec<GatewayHistoryEntry>)]
+    GatewayHistory { chain: ChainName },
+
+    /// Returns the rate limits of a chain and how much of them is used in the current window
+    #[returns(ChainRateLimits)]
+    RateLimits { chain: ChainName },
+
+    /// Returns the route policy of a source chain, or None if messages can be routed to any chain
+    #[returns(Option<RoutePolicy>)]
+    RoutePolicy { source_chain: ChainName },
+
+    /// Returns the route policies of all source chains that have one.
+    /// The list is paginated by the source chain name, returns 100 policies by default and at most 1000.
+    #[returns(Vec<ChainRoutePolicy>)]
+    RoutePolicies {
+        start_after: Option<ChainName>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the destination chain, block height and routing status of a queued or routed message,
+    /// or None if the message is not queued and was not routed within the routed message horizon
+    #[returns(Option<RoutedMessage>)]
+    RoutedMessage { cc_id: CrossChainId },
+
+    /// Returns the messages that are queued for a frozen chain, in the order they will be routed.
+    /// Returns 100 messages by default and at most 1000.
+    #[returns(Vec<QueuedMessage>)]
+    QueuedMessages {
+        chain: ChainName,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
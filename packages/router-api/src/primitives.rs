@@ -1,4 +1,5 @@
 use std::any::type_name;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Display;
 use std::ops::Deref;
@@ -188,7 +189,7 @@ impl Display for CrossChainId {
 /// - The string is lowercased
 #[cw_serde]
 #[serde(try_from = "String")]
-#[derive(Eq, Hash, Valuable)]
+#[derive(Eq, Hash, PartialOrd, Ord, Valuable)]
 pub struct ChainName(String);
 
 impl FromStr for ChainName {
@@ -474,6 +475,92 @@ impl ChainEndpoint {
     }
 }
 
+/// Destination and block height of a message that was accepted for routing.
+/// Messages to frozen chains are queued, they are routed once they are released from the queue.
+#[cw_serde]
+#[derive(Eq)]
+pub struct RoutedMessage {
+    pub destination_chain: ChainName,
+    /// block height at which the message was queued or routed
+    pub block_height: u64,
+    pub status: RoutingStatus,
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum RoutingStatus {
+    Queued,
+    Routed,
+}
+
+/// A gateway that was registered for a chain and the block heights during which it routed messages
+#[cw_serde]
+pub struct GatewayHistoryEntry {
+    pub address: Addr,
+    pub active_from: u64,
+    /// None while the gateway is still registered for the chain
+    pub active_until: Option<u64>,
+}
+
+/// Maximum number of messages that can be routed from or to a chain within a window of blocks.
+/// Windows are aligned to multiples of the window length.
+#[cw_serde]
+#[derive(Copy)]
+pub struct RateLimit {
+    pub max_messages: u32,
+    /// length of the window in blocks
+    pub window: nonempty::Uint64,
+}
+
+/// Usage of a rate limit in the current window
+#[cw_serde]
+pub struct RateLimitStatus {
+    pub limit: RateLimit,
+    /// block height at which the current window started
+    pub window_start: u64,
+    pub routed_messages: u32,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ChainRateLimits {
+    pub incoming: Option<RateLimitStatus>,
+    pub outgoing: Option<RateLimitStatus>,
+}
+
+/// A message that waits for its frozen destination chain to be unfrozen
+#[cw_serde]
+#[derive(Eq)]
+pub struct QueuedMessage {
+    /// position in the queue of the destination chain
+    pub id: u64,
+    pub msg: Message,
+}
+
+/// Restricts the destination chains that messages from a source chain can be routed to
+#[cw_serde]
+pub enum RoutePolicy {
+    /// messages can only be routed to the listed chains
+    Allowlist(BTreeSet<ChainName>),
+    /// messages can be routed to any chain except the listed ones
+    Denylist(BTreeSet<ChainName>),
+}
+
+impl RoutePolicy {
+    pub fn allows(&self, destination_chain: &ChainName) -> bool {
+        match self {
+            RoutePolicy::Allowlist(chains) => chains.contains(destination_chain),
+            RoutePolicy::Denylist(chains) => !chains.contains(destination_chain),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ChainRoutePolicy {
+    pub source_chain: ChainName,
+    pub policy: RoutePolicy,
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::to_json_vec;